@@ -0,0 +1,404 @@
+//! Defines coders based on asymmetric numeral systems.
+//! 
+//! Both coders run backwards: the encoder takes symbols in reverse order and the decoder
+//! returns them forwards, reading the bitstream from its end with a `ReadBackward`.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitWrite, ReadBackward, WriteBackward,};
+use alloc::{vec, vec::Vec,};
+
+mod tests;
+
+/// The largest table log of a `RansTable`.
+pub const MAX_RANS_LOG: u8 = 16;
+/// The smallest table log of an `FseTable`.
+pub const MIN_FSE_LOG: u8 = 5;
+/// The largest table log of an `FseTable`.
+pub const MAX_FSE_LOG: u8 = 15;
+/// The lower bound of the rANS state.
+const RANS_LOW: u32 = 1 << 16;
+
+/// An error from an ANS coder.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum AnsError {
+  /// More bits were read than the stream holds.
+  Underflow,
+  /// The symbol counts do not sum to a supported table size.
+  Counts,
+  /// The stream did not decode back to the state the encoder started in.
+  Invalid,
+}
+
+/// Scales symbol frequencies to counts which sum to `1 << table_log`.
+/// 
+/// Every symbol with a non zero frequency gets a count of at least `1`.
+/// 
+/// # Params
+/// 
+/// freqs --- The frequency of each symbol, at least one must be non zero.  
+/// table_log --- The log of the sum of the counts, at most `16`.  
+pub fn normalize_counts(freqs: &[u64], table_log: u8,) -> Vec<u32> {
+  assert!(table_log <= MAX_RANS_LOG, "The table log must be at most 16",);
+
+  let size = 1u64 << table_log;
+  let total = freqs.iter().sum::<u64>();
+  let used = freqs.iter().filter(|&&freq,| freq > 0,).count() as u64;
+  assert!(total > 0, "At least one symbol must be used",);
+  assert!(used <= size, "There are more symbols than the table can hold",);
+
+  let mut counts = freqs.iter().map(|&freq,| match freq {
+    0 => 0,
+    freq => ((freq as u128 * size as u128 / total as u128) as u32).max(1,),
+  },).collect::<Vec<_>>();
+  let mut sum = counts.iter().map(|&count,| count as u64,).sum::<u64>();
+  //Take any excess from the largest counts, which loses the least.
+  while sum > size {
+    let (index, _,) = counts.iter().enumerate().max_by_key(|&(_, &count,),| count,).expect("At least one symbol is used",);
+
+    counts[index] -= 1;
+    sum -= 1;
+  }
+  //Give any shortfall to the most frequent symbol.
+  let (index, _,) = freqs.iter().enumerate().max_by_key(|&(_, &freq,),| freq,).expect("At least one symbol is used",);
+  counts[index] += (size - sum) as u32;
+
+  counts
+}
+
+/// Returns the log of the sum of `counts` if it is a power of two in range.
+fn table_log(counts: &[u32], min: u8, max: u8,) -> Result<u8, AnsError> {
+  let total = counts.iter().try_fold(0u32, |total, &count,| total.checked_add(count,),).ok_or(AnsError::Counts,)?;
+  if !total.is_power_of_two() { return Err(AnsError::Counts) }
+
+  let log = total.trailing_zeros() as u8;
+  if log < min || log > max { Err(AnsError::Counts) } else { Ok(log) }
+}
+
+/// The symbol counts used by the rANS coder.
+#[derive(PartialEq, Eq, Clone, Debug, Hash,)]
+pub struct RansTable {
+  /// The log of the sum of the counts.
+  log: u8,
+  /// The count of each symbol.
+  counts: Vec<u32>,
+  /// The sum of the counts before each symbol.
+  starts: Vec<u32>,
+  /// The symbol of each slot.
+  slots: Vec<u16>,
+}
+
+impl RansTable {
+  /// Constructs a new `RansTable` from symbol counts.
+  /// 
+  /// Returns `Counts` if the counts do not sum to a power of two of at most `1 << 16`.
+  /// 
+  /// # Params
+  /// 
+  /// counts --- The count of each symbol.  
+  pub fn new(counts: &[u32],) -> Result<Self, AnsError> {
+    let log = table_log(counts, 0, MAX_RANS_LOG,)?;
+    let mut starts = Vec::with_capacity(counts.len(),);
+    let mut slots = Vec::with_capacity(1 << log,);
+    for (symbol, &count,) in counts.iter().enumerate() {
+      starts.push(slots.len() as u32,);
+      slots.extend((0..count).map(|_,| symbol as u16,),);
+    }
+
+    Ok(Self { log, counts: counts.to_vec(), starts, slots, })
+  }
+  /// Constructs a new `RansTable` from symbol frequencies.
+  /// 
+  /// # Params
+  /// 
+  /// freqs --- The frequency of each symbol, at least one must be non zero.  
+  /// table_log --- The log of the table size, at most `16`.  
+  pub fn from_frequencies(freqs: &[u64], table_log: u8,) -> Self {
+    Self::new(&normalize_counts(freqs, table_log,),).expect("The counts are normalized",)
+  }
+  /// Returns the log of the table size.
+  #[inline]
+  pub const fn table_log(&self,) -> u8 { self.log }
+  /// Returns the symbol counts.
+  #[inline]
+  pub fn counts(&self,) -> &[u32] { &self.counts }
+}
+
+/// Encodes symbols with rANS.
+/// 
+/// Symbols must be encoded in the reverse of the order they will be decoded in. Each
+/// symbol may use a different table as long as the decoder uses the same one.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct RansEncoder {
+  /// The writer to encode to.
+  writer: WriteBackward,
+  /// The coder state.
+  state: u32,
+}
+
+impl RansEncoder {
+  /// Constructs a new `RansEncoder` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  pub const fn new(writer: WriteBackward,) -> Self { Self { writer, state: RANS_LOW, } }
+  /// Encodes a symbol.
+  /// 
+  /// # Params
+  /// 
+  /// table --- The table of the symbol.  
+  /// symbol --- The symbol to encode, its count must not be `0`.  
+  pub fn encode(&mut self, table: &RansTable, symbol: u16,) -> &mut Self {
+    let (count, start,) = (table.counts[symbol as usize], table.starts[symbol as usize],);
+    assert!(count > 0, "Cannot encode a symbol with a count of zero",);
+
+    //Shift out bits until encoding keeps the state below `1 << 32`.
+    let max = ((RANS_LOW as u64 >> table.log) << 16) * count as u64;
+    while self.state as u64 >= max {
+      self.writer.write_word(16, self.state as u64,).ok();
+      self.state >>= 16;
+    }
+
+    self.state = ((self.state / count) << table.log) + self.state % count + start;
+
+    self
+  }
+  /// Writes the final state and returns the writer.
+  pub fn finish(mut self,) -> WriteBackward {
+    self.writer.write_word(32, self.state as u64,).ok();
+
+    self.writer
+  }
+}
+
+/// Decodes symbols encoded by a `RansEncoder`.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct RansDecoder<'a,> {
+  /// The reader to decode from.
+  reader: ReadBackward<'a,>,
+  /// The coder state.
+  state: u32,
+}
+
+impl<'a,> RansDecoder<'a,> {
+  /// Constructs a new `RansDecoder` reading the final state of the encoder.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn new(mut reader: ReadBackward<'a,>,) -> Result<Self, AnsError> {
+    let state = reader.read_word(32,).map_err(|_,| AnsError::Underflow,)? as u32;
+
+    Ok(Self { reader, state, })
+  }
+  /// Decodes a symbol.
+  /// 
+  /// # Params
+  /// 
+  /// table --- The table of the symbol.  
+  pub fn decode(&mut self, table: &RansTable,) -> Result<u16, AnsError> {
+    let slot = self.state & ((1 << table.log) - 1);
+    let symbol = table.slots[slot as usize];
+    let (count, start,) = (table.counts[symbol as usize], table.starts[symbol as usize],);
+
+    self.state = count * (self.state >> table.log) + slot - start;
+    while self.state < RANS_LOW {
+      self.state = self.state << 16 | self.reader.read_word(16,).map_err(|_,| AnsError::Underflow,)? as u32;
+    }
+
+    Ok(symbol)
+  }
+  /// Checks that every symbol has been decoded and returns the reader.
+  /// 
+  /// Returns `Invalid` if the state is not the state the encoder started in.
+  pub fn finish(self,) -> Result<ReadBackward<'a,>, AnsError> {
+    if self.state == RANS_LOW { Ok(self.reader) } else { Err(AnsError::Invalid) }
+  }
+}
+
+/// A decoding table entry of an `FseTable`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+struct FseEntry {
+  /// The symbol of the state.
+  symbol: u16,
+  /// The number of bits read to find the next state.
+  bits: u8,
+  /// The next state before the bits are added.
+  base: u16,
+}
+
+/// The tables used by the tANS coder, built the same way as zstd's FSE tables.
+#[derive(PartialEq, Eq, Clone, Debug, Hash,)]
+pub struct FseTable {
+  /// The log of the number of states.
+  log: u8,
+  /// The decoding entry of each state.
+  entries: Vec<FseEntry>,
+  /// The encoder states of each symbol in order, offset by the table size.
+  states: Vec<u16>,
+  /// The values used to find the number of bits and the next state when encoding
+  /// each symbol.
+  transforms: Vec<Option<(u32, i32,)>>,
+}
+
+impl FseTable {
+  /// Constructs a new `FseTable` from symbol counts.
+  /// 
+  /// Returns `Counts` if the counts do not sum to a power of two from `1 << 5` to
+  /// `1 << 15`.
+  /// 
+  /// # Params
+  /// 
+  /// counts --- The count of each symbol.  
+  pub fn new(counts: &[u32],) -> Result<Self, AnsError> {
+    let log = table_log(counts, MIN_FSE_LOG, MAX_FSE_LOG,)?;
+    let size = 1usize << log;
+
+    //Spread the symbols over the states, the step is odd so every state is visited.
+    let step = (size >> 1) + (size >> 3) + 3;
+    let mut spread = vec![0u16; size];
+    let mut pos = 0;
+    for (symbol, &count,) in counts.iter().enumerate() {
+      for _ in 0..count {
+        spread[pos] = symbol as u16;
+        pos = (pos + step) & (size - 1);
+      }
+    }
+
+    let mut next = counts.to_vec();
+    let entries = spread.iter().map(|&symbol,| {
+      let index = next[symbol as usize];
+      next[symbol as usize] += 1;
+
+      let bits = log - (31 - index.leading_zeros()) as u8;
+      FseEntry { symbol, bits, base: ((index << bits) - size as u32) as u16, }
+    },).collect();
+
+    let mut starts = Vec::with_capacity(counts.len(),);
+    let mut total = 0;
+    for &count in counts {
+      starts.push(total,);
+      total += count;
+    }
+
+    let mut cursor = starts.clone();
+    let mut states = vec![0u16; size];
+    for (state, &symbol,) in spread.iter().enumerate() {
+      states[cursor[symbol as usize] as usize] = (size + state) as u16;
+      cursor[symbol as usize] += 1;
+    }
+
+    let transforms = counts.iter().zip(&starts,).map(|(&count, &start,),| {
+      if count == 0 { return None }
+
+      let high = if count > 1 { 31 - (count - 1).leading_zeros() } else { 0 };
+      let max_bits = log as u32 - high;
+      let min_state = count << max_bits;
+
+      Some(((max_bits << 16).wrapping_sub(min_state,), start as i32 - count as i32,),)
+    },).collect();
+
+    Ok(Self { log, entries, states, transforms, })
+  }
+  /// Constructs a new `FseTable` from symbol frequencies.
+  /// 
+  /// # Params
+  /// 
+  /// freqs --- The frequency of each symbol, at least one must be non zero.  
+  /// table_log --- The log of the table size, from `5` to `15`.  
+  pub fn from_frequencies(freqs: &[u64], table_log: u8,) -> Self {
+    assert!((MIN_FSE_LOG..=MAX_FSE_LOG).contains(&table_log), "The table log must be between 5 and 15",);
+
+    Self::new(&normalize_counts(freqs, table_log,),).expect("The counts are normalized",)
+  }
+  /// Returns the log of the number of states.
+  #[inline]
+  pub const fn table_log(&self,) -> u8 { self.log }
+}
+
+/// Encodes symbols with tANS.
+/// 
+/// Symbols must be encoded in the reverse of the order they will be decoded in.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct FseEncoder<'a,> {
+  /// The table to encode with.
+  table: &'a FseTable,
+  /// The writer to encode to.
+  writer: WriteBackward,
+  /// The coder state offset by the table size.
+  state: u32,
+}
+
+impl<'a,> FseEncoder<'a,> {
+  /// Constructs a new `FseEncoder` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// table --- The table to encode with.  
+  /// writer --- The writer to encode to.  
+  pub const fn new(table: &'a FseTable, writer: WriteBackward,) -> Self {
+    Self { table, writer, state: 1 << table.log, }
+  }
+  /// Encodes a symbol.
+  /// 
+  /// # Params
+  /// 
+  /// symbol --- The symbol to encode, its count must not be `0`.  
+  pub fn encode(&mut self, symbol: u16,) -> &mut Self {
+    let (delta_bits, delta_state,) = self.table.transforms[symbol as usize]
+      .expect("Cannot encode a symbol with a count of zero",);
+
+    let bits = (self.state.wrapping_add(delta_bits,) >> 16) as u8;
+    self.writer.write_word(bits, self.state as u64,).ok();
+    self.state = self.table.states[((self.state >> bits) as i32 + delta_state) as usize] as u32;
+
+    self
+  }
+  /// Writes the final state and returns the writer.
+  pub fn finish(mut self,) -> WriteBackward {
+    self.writer.write_word(self.table.log, self.state as u64,).ok();
+
+    self.writer
+  }
+}
+
+/// Decodes symbols encoded by an `FseEncoder`.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct FseDecoder<'a, 'b,> {
+  /// The table to decode with.
+  table: &'a FseTable,
+  /// The reader to decode from.
+  reader: ReadBackward<'b,>,
+  /// The coder state.
+  state: u16,
+}
+
+impl<'a, 'b,> FseDecoder<'a, 'b,> {
+  /// Constructs a new `FseDecoder` reading the final state of the encoder.
+  /// 
+  /// # Params
+  /// 
+  /// table --- The table to decode with.  
+  /// reader --- The reader to decode from.  
+  pub fn new(table: &'a FseTable, mut reader: ReadBackward<'b,>,) -> Result<Self, AnsError> {
+    let state = reader.read_word(table.log,).map_err(|_,| AnsError::Underflow,)? as u16;
+
+    Ok(Self { table, reader, state, })
+  }
+  /// Decodes a symbol.
+  pub fn decode(&mut self,) -> Result<u16, AnsError> {
+    let entry = self.table.entries[self.state as usize];
+
+    self.state = entry.base + self.reader.read_word(entry.bits,).map_err(|_,| AnsError::Underflow,)? as u16;
+
+    Ok(entry.symbol)
+  }
+  /// Checks that every symbol has been decoded and returns the reader.
+  /// 
+  /// Returns `Invalid` if the state is not the state the encoder started in.
+  pub fn finish(self,) -> Result<ReadBackward<'b,>, AnsError> {
+    if self.state == 0 { Ok(self.reader) } else { Err(AnsError::Invalid) }
+  }
+}
@@ -0,0 +1,88 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::test_data::Rng;
+
+/// Returns `count` symbols below `alphabet` with a skewed distribution.
+fn symbols(count: usize, alphabet: u32,) -> Vec<u16> {
+  Rng::new(2463534242,).take(count,).map(|value,| (value % alphabet).min(value % 7,) as u16,).collect()
+}
+
+/// Returns the frequency of each symbol.
+fn frequencies(symbols: &[u16], alphabet: usize,) -> Vec<u64> {
+  let mut freqs = vec![0; alphabet];
+  for &symbol in symbols { freqs[symbol as usize] += 1; }
+
+  freqs
+}
+
+#[test]
+fn test_normalize_counts() {
+  assert_eq!(normalize_counts(&[1, 1, 2,], 2,), [1, 1, 2,],);
+  assert_eq!(normalize_counts(&[0, 3, 0, 1,], 4,), [0, 12, 0, 4,],);
+  //Rare symbols keep a count.
+  let counts = normalize_counts(&[1_000_000, 1, 1, 1,], 5,);
+  assert_eq!(counts, [29, 1, 1, 1,],);
+  let counts = normalize_counts(&[5, 1, 1, 1, 1, 1, 1, 1,], 3,);
+  assert_eq!(counts, [1; 8],);
+
+  assert_eq!(RansTable::new(&[1, 2,],), Err(AnsError::Counts),);
+  assert_eq!(FseTable::new(&[8, 8,],), Err(AnsError::Counts),);
+  assert!(FseTable::new(&[16, 16,],).is_ok(),);
+}
+
+#[test]
+fn test_rans() {
+  let symbols = symbols(20_000, 64,);
+  let table = RansTable::from_frequencies(&frequencies(&symbols, 64,), 12,);
+  //A second table used for the even positions.
+  let other = RansTable::new(&[1 << 10; 64],).expect("Error building table",);
+  let table_of = |index: usize,| if index.is_multiple_of(2,) { &other } else { &table };
+
+  let mut encoder = RansEncoder::new(WriteBackward::new(),);
+  for (index, &symbol,) in symbols.iter().enumerate().rev() { encoder.encode(table_of(index,), symbol,); }
+  let bytes = encoder.finish().finish();
+
+  let mut decoder = RansDecoder::new(ReadBackward::new(&bytes,).expect("Error decoding",),).expect("Error decoding",);
+  for (index, &symbol,) in symbols.iter().enumerate() { assert_eq!(decoder.decode(table_of(index,),), Ok(symbol),); }
+  assert!(decoder.finish().expect("Error decoding",).is_empty(),);
+
+  //Decoding too many symbols is detected.
+  let mut decoder = RansDecoder::new(ReadBackward::new(&bytes,).expect("Error decoding",),).expect("Error decoding",);
+  for (index, _,) in symbols.iter().enumerate() { decoder.decode(table_of(index,),).ok(); }
+  decoder.decode(&table,).ok();
+  assert_eq!(decoder.finish(), Err(AnsError::Invalid),);
+}
+
+#[test]
+fn test_fse() {
+  let symbols = symbols(20_000, 64,);
+  let freqs = frequencies(&symbols, 64,);
+  let table = FseTable::from_frequencies(&freqs, 11,);
+
+  let mut encoder = FseEncoder::new(&table, WriteBackward::new(),);
+  for &symbol in symbols.iter().rev() { encoder.encode(symbol,); }
+  let bytes = encoder.finish().finish();
+
+  //Within a percent of the entropy.
+  let entropy = freqs.iter().filter(|&&freq,| freq > 0,)
+    .map(|&freq,| freq as f64 * (symbols.len() as f64 / freq as f64).log2(),).sum::<f64>() / 8.0;
+  assert!((bytes.len() as f64) < entropy * 1.01,);
+
+  let mut decoder = FseDecoder::new(&table, ReadBackward::new(&bytes,).expect("Error decoding",),).expect("Error decoding",);
+  for &symbol in &symbols { assert_eq!(decoder.decode(), Ok(symbol),); }
+  assert!(decoder.finish().expect("Error decoding",).is_empty(),);
+
+  //A single symbol costs no bits.
+  let table = FseTable::new(&[0, 32,],).expect("Error building table",);
+  let mut encoder = FseEncoder::new(&table, WriteBackward::new(),);
+  for _ in 0..100 { encoder.encode(1,); }
+  let bytes = encoder.finish().finish();
+  assert_eq!(bytes.len(), 1,);
+  let mut decoder = FseDecoder::new(&table, ReadBackward::new(&bytes,).expect("Error decoding",),).expect("Error decoding",);
+  for _ in 0..100 { assert_eq!(decoder.decode(), Ok(1),); }
+  assert!(decoder.finish().is_ok(),);
+}
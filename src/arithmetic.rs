@@ -0,0 +1,455 @@
+//! Defines a range coder and the adaptive models which drive it.
+//! 
+//! The coder works on whole bytes of the underlying reader/writer and codes each symbol
+//! with the probability given by a model, the models are traits so that any predictor
+//! can be plugged in.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitWrite, CodeError,};
+use alloc::{vec, vec::Vec,};
+
+mod tests;
+
+/// The precision of the probabilities of a `BinaryModel`.
+pub const PROB_BITS: u8 = 12;
+/// The largest total frequency of a `FrequencyModel`.
+pub const MAX_TOTAL: u32 = 1 << 16;
+/// The range is renormalised once it drops below this.
+const TOP: u32 = 1 << 24;
+
+/// A model of a single bit.
+pub trait BinaryModel {
+  /// Returns the probability that the next bit is `true` out of `1 << PROB_BITS`.
+  /// 
+  /// Probabilities of `0` and `1 << PROB_BITS` are clamped so that both bits can be
+  /// coded.
+  fn probability(&self,) -> u16;
+  /// Updates the model with the bit which was coded.
+  /// 
+  /// # Params
+  /// 
+  /// bit --- The bit which was coded.  
+  fn update(&mut self, bit: bool,);
+}
+
+impl<M,> BinaryModel for &mut M
+  where M: BinaryModel + ?Sized, {
+  #[inline]
+  fn probability(&self,) -> u16 { M::probability(*self,) }
+  #[inline]
+  fn update(&mut self, bit: bool,) { M::update(*self, bit,) }
+}
+
+/// A model of the symbols of an alphabet as frequencies.
+pub trait FrequencyModel {
+  /// Returns the sum of the frequencies of all symbols, at most `MAX_TOTAL`.
+  fn total(&self,) -> u32;
+  /// Returns the sum of the frequencies of the symbols before `symbol` and the
+  /// frequency of `symbol`, which must not be `0`.
+  /// 
+  /// # Params
+  /// 
+  /// symbol --- The symbol to find.  
+  fn range(&self, symbol: u16,) -> (u32, u32,);
+  /// Returns the symbol whose range contains `target` with its range.
+  /// 
+  /// # Params
+  /// 
+  /// target --- A value less than `total`.  
+  fn find(&self, target: u32,) -> (u16, u32, u32,);
+  /// Updates the model with the symbol which was coded.
+  /// 
+  /// # Params
+  /// 
+  /// symbol --- The symbol which was coded.  
+  fn update(&mut self, symbol: u16,);
+}
+
+impl<M,> FrequencyModel for &mut M
+  where M: FrequencyModel + ?Sized, {
+  #[inline]
+  fn total(&self,) -> u32 { M::total(*self,) }
+  #[inline]
+  fn range(&self, symbol: u16,) -> (u32, u32,) { M::range(*self, symbol,) }
+  #[inline]
+  fn find(&self, target: u32,) -> (u16, u32, u32,) { M::find(*self, target,) }
+  #[inline]
+  fn update(&mut self, symbol: u16,) { M::update(*self, symbol,) }
+}
+
+/// A bit probability which moves towards each coded bit by a fixed fraction.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct AdaptiveBit {
+  /// The probability of a `true` bit.
+  prob: u16,
+  /// The speed of adaption, the probability moves `1 / 2^shift` of the way.
+  shift: u8,
+}
+
+impl AdaptiveBit {
+  /// Constructs a new `AdaptiveBit` with equal probabilities and a shift of `4`.
+  pub const fn new() -> Self { Self::with_shift(4,) }
+  /// Constructs a new `AdaptiveBit` with equal probabilities.
+  /// 
+  /// # Params
+  /// 
+  /// shift --- The speed of adaption from `1`, the fastest, to `PROB_BITS - 1`.  
+  pub const fn with_shift(shift: u8,) -> Self {
+    assert!(shift >= 1 && shift < PROB_BITS, "The shift must be between 1 and PROB_BITS - 1",);
+
+    Self { prob: 1 << (PROB_BITS - 1), shift, }
+  }
+}
+
+impl Default for AdaptiveBit {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+impl BinaryModel for AdaptiveBit {
+  #[inline]
+  fn probability(&self,) -> u16 { self.prob }
+  #[inline]
+  fn update(&mut self, bit: bool,) {
+    if bit { self.prob += ((1 << PROB_BITS) - self.prob) >> self.shift; }
+    else { self.prob -= self.prob >> self.shift; }
+  }
+}
+
+/// Symbol frequencies which count each coded symbol.
+/// 
+/// The frequencies are halved whenever the total passes the limit so that the model
+/// follows changes in the input.
+#[derive(PartialEq, Eq, Clone, Debug, Hash,)]
+pub struct AdaptiveFrequencies {
+  /// The frequency of each symbol.
+  freqs: Vec<u32>,
+  /// The sum of `freqs`.
+  total: u32,
+  /// The amount added to a symbol when it is coded.
+  increment: u32,
+  /// The total which causes the frequencies to be halved.
+  limit: u32,
+}
+
+impl AdaptiveFrequencies {
+  /// Constructs a new `AdaptiveFrequencies` with every symbol equally likely, an
+  /// increment of `32` and a limit of `MAX_TOTAL`.
+  /// 
+  /// # Params
+  /// 
+  /// alphabet --- The number of symbols, from `1` to `MAX_TOTAL / 2`.  
+  pub fn new(alphabet: usize,) -> Self { Self::with_params(alphabet, 32, MAX_TOTAL,) }
+  /// Constructs a new `AdaptiveFrequencies` with every symbol equally likely.
+  /// 
+  /// # Params
+  /// 
+  /// alphabet --- The number of symbols, from `1` to `limit / 2`.  
+  /// increment --- The amount added to a symbol when it is coded, at least `1`.  
+  /// limit --- The total which causes the frequencies to be halved, at most `MAX_TOTAL`.  
+  pub fn with_params(alphabet: usize, increment: u32, limit: u32,) -> Self {
+    assert!(alphabet >= 1 && alphabet <= limit as usize / 2, "The alphabet must have 1 to limit / 2 symbols",);
+    assert!(limit <= MAX_TOTAL, "The limit must be at most MAX_TOTAL",);
+    assert!(increment >= 1 && increment < limit / 2, "The increment must be between 1 and limit / 2",);
+
+    Self { freqs: vec![1; alphabet], total: alphabet as u32, increment, limit, }
+  }
+  /// Returns the number of symbols.
+  #[inline]
+  pub fn alphabet(&self,) -> usize { self.freqs.len() }
+}
+
+impl FrequencyModel for AdaptiveFrequencies {
+  #[inline]
+  fn total(&self,) -> u32 { self.total }
+  fn range(&self, symbol: u16,) -> (u32, u32,) {
+    let symbol = symbol as usize;
+
+    (self.freqs[..symbol].iter().sum(), self.freqs[symbol],)
+  }
+  fn find(&self, target: u32,) -> (u16, u32, u32,) {
+    let mut low = 0;
+    for (symbol, &freq,) in self.freqs.iter().enumerate() {
+      if target < low + freq { return (symbol as u16, low, freq,) }
+      low += freq;
+    }
+
+    panic!("The target is outside of the total",)
+  }
+  fn update(&mut self, symbol: u16,) {
+    self.freqs[symbol as usize] += self.increment;
+    self.total += self.increment;
+
+    if self.total > self.limit {
+      for freq in &mut self.freqs { *freq = freq.div_ceil(2,); }
+      self.total = self.freqs.iter().sum();
+    }
+  }
+}
+
+/// Returns the probability of a model clamped so that both bits have a range.
+#[inline]
+fn clamp_probability<M,>(model: &M,) -> u32
+  where M: BinaryModel + ?Sized, {
+  (model.probability() as u32).clamp(1, (1 << PROB_BITS) - 1,)
+}
+
+/// Encodes symbols with a range coder, writing whole bytes high bits first.
+/// 
+/// Bytes which a carry may still change are held back until they are certain, so a
+/// stream is only complete once `finish` is called.  
+/// After an error the encoder should not be used.
+#[derive(Clone, Debug,)]
+pub struct RangeEncoder<W,>
+  where W: BitWrite, {
+  /// The writer to encode to.
+  writer: W,
+  /// The low end of the range with a carry in bit `32`.
+  low: u64,
+  /// The size of the range.
+  range: u32,
+  /// The held back byte which a carry would increment.
+  cache: Option<u8>,
+  /// The number of held back `0xFF` bytes following `cache`.
+  pending: u64,
+}
+
+impl<W,> RangeEncoder<W,>
+  where W: BitWrite, {
+  /// Constructs a new `RangeEncoder` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  pub const fn new(writer: W,) -> Self {
+    Self { writer, low: 0, range: u32::MAX, cache: None, pending: 0, }
+  }
+  /// Returns a reference to the writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &W { &self.writer }
+  /// Encodes a bit with a binary model and updates the model.
+  /// 
+  /// # Params
+  /// 
+  /// model --- The model of the bit.  
+  /// bit --- The bit to encode.  
+  pub fn encode_bit<M,>(&mut self, mut model: M, bit: bool,) -> Result<(), W::Error>
+    where M: BinaryModel, {
+    let bound = (self.range >> PROB_BITS) * clamp_probability(&model,);
+
+    if bit { self.range = bound; }
+    else { self.low += bound as u64; self.range -= bound; }
+    model.update(bit,);
+
+    self.normalise()
+  }
+  /// Encodes a symbol with a frequency model and updates the model.
+  /// 
+  /// Panics if the model has a total of `0` or more than `MAX_TOTAL` or gives the symbol
+  /// a frequency of `0`, which would leave the range empty.
+  /// 
+  /// # Params
+  /// 
+  /// model --- The model of the symbol.  
+  /// symbol --- The symbol to encode.  
+  pub fn encode<M,>(&mut self, mut model: M, symbol: u16,) -> Result<(), W::Error>
+    where M: FrequencyModel, {
+    let total = checked_total(&model,);
+    let (low, freq,) = model.range(symbol,);
+    check_range(low, freq, total,);
+    let scale = self.range / total;
+
+    self.low += (scale * low) as u64;
+    self.range = scale * freq;
+    model.update(symbol,);
+
+    self.normalise()
+  }
+  /// Encodes the low bits of a value with equal probabilities, high bits first.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits to encode, at most `32`.  
+  /// value --- The value to encode.  
+  pub fn encode_direct(&mut self, bits: u8, value: u32,) -> Result<(), W::Error> {
+    assert!(bits <= 32, "Cannot encode more than 32 bits at once",);
+
+    for bit in (0..bits).rev() {
+      self.range >>= 1;
+      if (value >> bit) & 1 == 1 { self.low += self.range as u64; }
+      self.normalise()?;
+    }
+
+    Ok(())
+  }
+  /// Writes the bytes needed to decode everything encoded so far and returns the writer.
+  pub fn finish(mut self,) -> Result<W, W::Error> {
+    for _ in 0..5 { self.shift_low()?; }
+
+    Ok(self.writer)
+  }
+  /// Shifts out bytes until the range is large enough.
+  #[inline]
+  fn normalise(&mut self,) -> Result<(), W::Error> {
+    while self.range < TOP {
+      self.range <<= 8;
+      self.shift_low()?;
+    }
+
+    Ok(())
+  }
+  /// Shifts the top byte out of `low`, writing the held back bytes once no carry can
+  /// reach them.
+  fn shift_low(&mut self,) -> Result<(), W::Error> {
+    if self.low < 0xFF00_0000 || self.low > u32::MAX as u64 {
+      let carry = (self.low >> 32) as u8;
+
+      if let Some(cache) = self.cache { self.writer.write_byte(cache.wrapping_add(carry,),)?; }
+      while self.pending > 0 {
+        self.writer.write_byte(0xFFu8.wrapping_add(carry,),)?;
+        self.pending -= 1;
+      }
+      self.cache = Some((self.low >> 24) as u8,);
+    } else {
+      //The byte is `0xFF` and a carry would propagate through it.
+      self.pending += 1;
+    }
+
+    self.low = (self.low & 0x00FF_FFFF) << 8;
+
+    Ok(())
+  }
+}
+
+/// Checks that a model has a usable total and returns it.
+/// 
+/// A total of `0` would divide by zero and a total above `MAX_TOTAL` could leave the
+/// coder with an empty range.
+#[inline]
+fn checked_total<M,>(model: &M,) -> u32
+  where M: FrequencyModel, {
+  let total = model.total();
+  assert!(total > 0 && total <= MAX_TOTAL, "The total of a model must be from 1 to MAX_TOTAL",);
+
+  total
+}
+
+/// Checks that the range of a symbol is not empty and fits in `total`.
+/// 
+/// An empty range would leave the coder normalising forever.
+#[inline]
+fn check_range(low: u32, freq: u32, total: u32,) {
+  assert!(freq > 0 && low + freq <= total, "A symbol must have a non-zero frequency within the total",);
+}
+
+/// Decodes symbols encoded by a `RangeEncoder`.
+/// 
+/// The models must be used in the same order and start in the same state as when
+/// encoding.
+#[derive(Clone, Debug,)]
+pub struct RangeDecoder<R,>
+  where R: BitRead, {
+  /// The reader to decode from.
+  reader: R,
+  /// The position of the encoded value within the range.
+  code: u32,
+  /// The size of the range.
+  range: u32,
+}
+
+impl<R,> RangeDecoder<R,>
+  where R: BitRead, {
+  /// Constructs a new `RangeDecoder` reading the first bytes of the stream.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn new(mut reader: R,) -> Result<Self, R::Error> {
+    let mut code = 0;
+    for _ in 0..4 { code = code << 8 | reader.read_byte()? as u32; }
+
+    Ok(Self { reader, code, range: u32::MAX, })
+  }
+  /// Returns a reference to the reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the reader.
+  /// 
+  /// The reader is positioned after the bytes written by `RangeEncoder::finish`.
+  #[inline]
+  pub fn into_inner(self,) -> R { self.reader }
+  /// Decodes a bit with a binary model and updates the model.
+  /// 
+  /// # Params
+  /// 
+  /// model --- The model of the bit.  
+  pub fn decode_bit<M,>(&mut self, mut model: M,) -> Result<bool, R::Error>
+    where M: BinaryModel, {
+    let bound = (self.range >> PROB_BITS) * clamp_probability(&model,);
+    let bit = self.code < bound;
+
+    if bit { self.range = bound; }
+    else { self.code -= bound; self.range -= bound; }
+    model.update(bit,);
+    self.normalise()?;
+
+    Ok(bit)
+  }
+  /// Decodes a symbol with a frequency model and updates the model.
+  /// 
+  /// Returns `Invalid` if the stream does not match the model.  
+  /// Panics if the model has a total of `0` or more than `MAX_TOTAL` or finds a symbol
+  /// with a frequency of `0`.
+  /// 
+  /// # Params
+  /// 
+  /// model --- The model of the symbol.  
+  pub fn decode<M,>(&mut self, mut model: M,) -> Result<u16, CodeError<R::Error>>
+    where M: FrequencyModel, {
+    let total = checked_total(&model,);
+    let scale = self.range / total;
+    let target = self.code / scale;
+    if target >= total { return Err(CodeError::Invalid) }
+
+    let (symbol, low, freq,) = model.find(target,);
+    check_range(low, freq, total,);
+    self.code -= scale * low;
+    self.range = scale * freq;
+    model.update(symbol,);
+    self.normalise()?;
+
+    Ok(symbol)
+  }
+  /// Decodes a value encoded with `encode_direct`.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits to decode, at most `32`.  
+  pub fn decode_direct(&mut self, bits: u8,) -> Result<u32, R::Error> {
+    assert!(bits <= 32, "Cannot decode more than 32 bits at once",);
+
+    let mut value = 0u32;
+    for _ in 0..bits {
+      self.range >>= 1;
+
+      let bit = self.code >= self.range;
+      if bit { self.code -= self.range; }
+      value = value.wrapping_shl(1,) | bit as u32;
+      self.normalise()?;
+    }
+
+    Ok(value)
+  }
+  /// Reads bytes until the range is large enough.
+  #[inline]
+  fn normalise(&mut self,) -> Result<(), R::Error> {
+    while self.range < TOP {
+      self.code = self.code << 8 | self.reader.read_byte()? as u32;
+      self.range <<= 8;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,153 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::{WriteVec, ReadIter, test_data::Rng,};
+
+/// Returns `count` values from a fixed sequence.
+fn values(count: usize,) -> Vec<u32> { Rng::new(2463534242,).take(count,).collect() }
+
+/// Returns the bytes written by an encoder.
+fn into_bytes(encoder: RangeEncoder<WriteVec,>,) -> Vec<u8> {
+  match encoder.finish() {
+    Ok(writer) => writer.into_vec().expect("The encoder writes whole bytes",),
+    Err(e) => match e {},
+  }
+}
+
+/// A model which predicts each bit from the previous bit, as an example of a model
+/// supplied by the user.
+struct Order1 {
+  /// The model after a `false` and after a `true` bit.
+  models: [AdaptiveBit; 2],
+  /// The previous bit.
+  prev: bool,
+}
+
+impl BinaryModel for Order1 {
+  fn probability(&self,) -> u16 { self.models[self.prev as usize].probability() }
+  fn update(&mut self, bit: bool,) {
+    self.models[self.prev as usize].update(bit,);
+    self.prev = bit;
+  }
+}
+
+#[test]
+fn test_binary() {
+  //Mostly `false` bits, about 0.29 bits of entropy each.
+  let bits = values(20_000,).into_iter().map(|value,| value % 20 == 0,).collect::<Vec<_>>();
+
+  let mut encoder = RangeEncoder::new(WriteVec::new(),);
+  let mut model = AdaptiveBit::new();
+  for &bit in &bits { encoder.encode_bit(&mut model, bit,).ok(); }
+  let bytes = into_bytes(encoder,);
+  assert!(bytes.len() < 20_000 * 34 / 100 / 8,);
+
+  let mut decoder = RangeDecoder::new(ReadIter::new(bytes.iter().copied(),),).expect("Error decoding",);
+  let mut model = AdaptiveBit::new();
+  for &bit in &bits { assert_eq!(decoder.decode_bit(&mut model,), Ok(bit),); }
+  //Every byte is used.
+  assert_eq!(decoder.into_inner().read_byte(), Err(None,),);
+
+  //Alternating bits are free with an order 1 model.
+  let mut encoder = RangeEncoder::new(WriteVec::new(),);
+  let mut model = Order1 { models: [AdaptiveBit::with_shift(2,); 2], prev: false, };
+  for index in 0..10_000 { encoder.encode_bit(&mut model, index % 2 == 0,).ok(); }
+  let bytes = into_bytes(encoder,);
+  assert!(bytes.len() < 50,);
+
+  let mut decoder = RangeDecoder::new(ReadIter::new(bytes.iter().copied(),),).expect("Error decoding",);
+  let mut model = Order1 { models: [AdaptiveBit::with_shift(2,); 2], prev: false, };
+  for index in 0..10_000 { assert_eq!(decoder.decode_bit(&mut model,), Ok(index % 2 == 0),); }
+}
+
+#[test]
+fn test_symbols() {
+  //A skewed distribution over 256 symbols.
+  let symbols = values(50_000,).into_iter().map(|value,| (value % 256).min(value % 64,) as u16,).collect::<Vec<_>>();
+  let mut encoder = RangeEncoder::new(WriteVec::new(),);
+  let mut model = AdaptiveFrequencies::new(256,);
+  let mut bit = AdaptiveBit::new();
+  for (index, &symbol,) in symbols.iter().enumerate() {
+    encoder.encode(&mut model, symbol,).ok();
+    encoder.encode_bit(&mut bit, symbol < 8,).ok();
+    encoder.encode_direct((index % 33) as u8, (index as u32).wrapping_mul(0x9E37_79B9,),).ok();
+  }
+  let bytes = into_bytes(encoder,);
+
+  let mut decoder = RangeDecoder::new(ReadIter::new(bytes.iter().copied(),),).expect("Error decoding",);
+  let mut model = AdaptiveFrequencies::new(256,);
+  let mut bit = AdaptiveBit::new();
+  for (index, &symbol,) in symbols.iter().enumerate() {
+    let bits = (index % 33) as u8;
+    let value = (index as u32).wrapping_mul(0x9E37_79B9,) as u64 & ((1 << bits) - 1);
+
+    assert_eq!(decoder.decode(&mut model,), Ok(symbol),);
+    assert_eq!(decoder.decode_bit(&mut bit,), Ok(symbol < 8),);
+    assert_eq!(decoder.decode_direct(bits,), Ok(value as u32),);
+  }
+  assert_eq!(decoder.into_inner().read_byte(), Err(None,),);
+
+  //A single symbol alphabet costs nothing.
+  let mut encoder = RangeEncoder::new(WriteVec::new(),);
+  let mut model = AdaptiveFrequencies::new(1,);
+  for _ in 0..1000 { encoder.encode(&mut model, 0,).ok(); }
+  assert_eq!(into_bytes(encoder,).len(), 4,);
+}
+
+#[test]
+fn test_carry() {
+  //Values near the top of the range produce runs of `0xFF` which carries pass through.
+  let values = values(20_000,);
+  let mut encoder = RangeEncoder::new(WriteVec::new(),);
+  let mut model = AdaptiveBit::new();
+  for &value in &values {
+    encoder.encode_bit(&mut model, value & 1 == 1,).ok();
+    encoder.encode_direct(32, value | 0xFFFF_FF00,).ok();
+  }
+  let bytes = into_bytes(encoder,);
+  assert!(bytes.windows(3,).any(|window,| window == [0xFF; 3],),);
+
+  let mut decoder = RangeDecoder::new(ReadIter::new(bytes.iter().copied(),),).expect("Error decoding",);
+  let mut model = AdaptiveBit::new();
+  for &value in &values {
+    assert_eq!(decoder.decode_bit(&mut model,), Ok(value & 1 == 1),);
+    assert_eq!(decoder.decode_direct(32,), Ok(value | 0xFFFF_FF00),);
+  }
+
+  //Truncated input fails to decode.
+  let mut decoder = RangeDecoder::new(ReadIter::new(bytes[..bytes.len() / 2].iter().copied(),),).expect("Error decoding",);
+  assert!(values.iter().any(|_,| decoder.decode_direct(32,).is_err(),),);
+}
+
+/// A model supplied by the user which breaks the rules of `FrequencyModel`.
+struct Broken {
+  /// The total of the model.
+  total: u32,
+}
+
+impl FrequencyModel for Broken {
+  fn total(&self,) -> u32 { self.total }
+  fn range(&self, symbol: u16,) -> (u32, u32,) { (symbol as u32, 0,) }
+  fn find(&self, target: u32,) -> (u16, u32, u32,) { (target as u16, target, 0,) }
+  fn update(&mut self, _: u16,) {}
+}
+
+#[test]
+#[should_panic(expected = "A symbol must have a non-zero frequency within the total",)]
+fn test_zero_frequency() {
+  let mut encoder = RangeEncoder::new(WriteVec::new(),);
+
+  encoder.encode(Broken { total: 4, }, 1,).ok();
+}
+
+#[test]
+#[should_panic(expected = "The total of a model must be from 1 to MAX_TOTAL",)]
+fn test_zero_total() {
+  let bytes = [0; 8];
+  let mut decoder = RangeDecoder::new(ReadIter::new(bytes.iter().copied(),),).expect("Error decoding",);
+
+  decoder.decode(Broken { total: 0, },).ok();
+}
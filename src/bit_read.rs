@@ -1,16 +1,23 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-31
+//! Last Moddified --- 2026-10-18
 
-use crate::{UnalignedError, Bits,};
+use crate::{UnalignedError, CodeError, BitOrder, Bits, zigzag_decode, golomb::truncated_binary, fibonacci::FIBONACCI,};
 use core::{
   convert::TryFrom,
   borrow::Borrow,
 };
+use alloc::vec::Vec;
 
 mod tests;
+mod read_lsb;
+mod read_backward;
+mod bit_unstuff;
+mod read_rbsp;
+mod read_jpeg;
 #[cfg(feature = "std",)]
 mod bit_reader;
 
+pub use self::{read_lsb::*, read_backward::*, bit_unstuff::*, read_rbsp::*, read_jpeg::*,};
 #[cfg(feature = "std",)]
 pub use self::bit_reader::*;
 
@@ -52,6 +59,316 @@ pub trait BitRead {
   /// 
   /// bits --- The number of bits to read off.  
   fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error>;
+  /// Reads up to `64` bits from the input at once.
+  /// 
+  /// The bits will occupy the low bits of the returned word and the higher bits are
+  /// zero.
+  /// 
+  /// Readers which buffer whole words should override this to avoid reading a byte at a
+  /// time.  
+  /// Attempting to read too many bits should not remove any bits from the input, if
+  /// more than a byte is available the error only needs to report a full byte.  
+  /// The default implementation reads a byte at a time and may have removed bits from
+  /// the input when an error is returned.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits to read off, at most `64`.  
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    let mut word = 0;
+    let mut bits = bits;
+    while bits > 0 {
+      let chunk = Bits::BITS[bits.min(8,) as usize - 1];
+
+      word = (word << chunk as u32) | (self.read_bits(chunk,)? & chunk.mask()) as u64;
+      bits -= chunk as u8;
+    }
+
+    Ok(word)
+  }
+  /// Reads a unary code, the number of bits before the first `terminator` bit.
+  /// 
+  /// The terminator is removed from the input.  
+  /// If an error is returned the bits of the code which were read are lost.
+  /// 
+  /// # Params
+  /// 
+  /// terminator --- The bit which ends the code.  
+  #[inline]
+  fn read_unary(&mut self, terminator: bool,) -> Result<u64, Self::Error> {
+    self.read_truncated_unary(u64::MAX, terminator,)
+  }
+  /// Reads a truncated unary code, the number of bits before the first `terminator` bit
+  /// up to `max`.
+  /// 
+  /// If the code reaches `max` there is no terminator to be read.  
+  /// Readers which buffer whole words should override this to count the bits of a word
+  /// at once.  
+  /// If an error is returned the bits of the code which were read are lost.
+  /// 
+  /// # Params
+  /// 
+  /// max --- The largest value of the code.  
+  /// terminator --- The bit which ends the code.  
+  fn read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, Self::Error> {
+    let mut count = 0;
+    while count < max && self.read_bit()? != terminator { count += 1 }
+
+    Ok(count)
+  }
+  /// Reads a k-th order Exp-Golomb code.
+  /// 
+  /// If the code has more than `32` leading zeros or its value does not fit in a `u32`
+  /// `CodeError::Overflow` is returned.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The order of the code, at most `32`.  
+  fn read_exp_golomb(&mut self, k: u8,) -> Result<u32, CodeError<Self::Error,>> {
+    assert!(k <= 32, "Exp-Golomb codes have an order of at most 32",);
+
+    let zeros = self.read_truncated_unary(33, true,)?;
+    if zeros > 32 { return Err(CodeError::Overflow) }
+
+    let suffix = self.read_word(zeros as u8 + k,)?;
+    let value = (((1u128 << zeros) - 1) << k) + suffix as u128;
+
+    u32::try_from(value,).map_err(|_,| CodeError::Overflow,)
+  }
+  /// Reads an unsigned Exp-Golomb code, `ue(v)`.
+  #[inline]
+  fn read_ue(&mut self,) -> Result<u32, CodeError<Self::Error,>> { self.read_exp_golomb(0,) }
+  /// Reads a signed Exp-Golomb code, `se(v)`.
+  /// 
+  /// The code of `i32::MIN` is `u32::MAX + 1` so codes with `32` leading zeros are read
+  /// in full before checking that the value fits in an `i32`.
+  fn read_se(&mut self,) -> Result<i32, CodeError<Self::Error,>> {
+    let zeros = self.read_truncated_unary(33, true,)?;
+    if zeros > 32 { return Err(CodeError::Overflow) }
+
+    let code = ((1i64 << zeros) - 1) + self.read_word(zeros as u8,)? as i64;
+    let value = if code % 2 == 1 { (code + 1) / 2 } else { -(code / 2) };
+
+    i32::try_from(value,).map_err(|_,| CodeError::Overflow,)
+  }
+  /// Reads an Elias gamma code.
+  /// 
+  /// If the code is for a value larger than `u64::MAX` `CodeError::Overflow` is
+  /// returned.
+  fn read_gamma(&mut self,) -> Result<u64, CodeError<Self::Error,>> {
+    let zeros = self.read_truncated_unary(64, true,)?;
+    if zeros > 63 { return Err(CodeError::Overflow) }
+
+    Ok((1 << zeros) | self.read_word(zeros as u8,)?)
+  }
+  /// Reads an Elias delta code.
+  /// 
+  /// If the code is for a value larger than `u64::MAX` `CodeError::Overflow` is
+  /// returned.
+  fn read_delta(&mut self,) -> Result<u64, CodeError<Self::Error,>> {
+    let bits = self.read_gamma()?;
+    if bits > 64 { return Err(CodeError::Overflow) }
+
+    Ok((1 << (bits - 1)) | self.read_word(bits as u8 - 1,)?)
+  }
+  /// Reads an Elias omega code.
+  /// 
+  /// If the code is for a value larger than `u64::MAX` `CodeError::Overflow` is
+  /// returned.
+  fn read_omega(&mut self,) -> Result<u64, CodeError<Self::Error,>> {
+    let mut value = 1u64;
+    while self.read_bit()? {
+      if value > 63 { return Err(CodeError::Overflow) }
+
+      //The next group has `value + 1` bits and its leading one has already been read.
+      value = (1 << value) | self.read_word(value as u8,)?;
+    }
+
+    Ok(value)
+  }
+  /// Reads a Rice code, a unary quotient of zeros ended by a one followed by a `k` bit
+  /// remainder.
+  /// 
+  /// If the value does not fit in a `u64` `CodeError::Overflow` is returned.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The Rice parameter, less than `64`.  
+  fn read_rice(&mut self, k: u8,) -> Result<u64, CodeError<Self::Error,>> {
+    assert!(k < 64, "The Rice parameter must be less than 64",);
+
+    let quotient = self.read_unary(true,)?;
+    if quotient.leading_zeros() < k as u32 { return Err(CodeError::Overflow) }
+
+    Ok((quotient << k) | self.read_word(k,)?)
+  }
+  /// Reads a Rice code for a signed value mapped by `zigzag_encode`.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The Rice parameter, less than `64`.  
+  #[inline]
+  fn read_signed_rice(&mut self, k: u8,) -> Result<i64, CodeError<Self::Error,>> {
+    self.read_rice(k,).map(zigzag_decode,)
+  }
+  /// Reads a Golomb code, a unary quotient of zeros ended by a one followed by a
+  /// truncated binary remainder.
+  /// 
+  /// If the value does not fit in a `u64` `CodeError::Overflow` is returned.
+  /// 
+  /// # Params
+  /// 
+  /// m --- The Golomb parameter, greater than `0`.  
+  fn read_golomb(&mut self, m: u64,) -> Result<u64, CodeError<Self::Error,>> {
+    let (bits, cutoff,) = truncated_binary(m,);
+    let quotient = self.read_unary(true,)?;
+    let mut remainder = match bits {
+      0 => 0,
+      bits => self.read_word(bits - 1,)?,
+    };
+    //Long remainders have an extra low bit.
+    if remainder >= cutoff && bits > 0 {
+      remainder = ((remainder << 1) | self.read_bit()? as u64) - cutoff;
+    }
+
+    quotient.checked_mul(m,).and_then(|v,| v.checked_add(remainder,),).ok_or(CodeError::Overflow,)
+  }
+  /// Reads an unsigned LEB128 code.
+  /// 
+  /// If the code has trailing zero bytes `CodeError::Overlong` is returned and if its
+  /// value does not fit in a `u64` `CodeError::Overflow` is returned.
+  /// 
+  /// Aligned readers read the code a byte at a time with `read_bytes`.
+  fn read_uleb128(&mut self,) -> Result<u64, CodeError<Self::Error,>> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+      let byte = read_code_byte(self,)?;
+      let low = (byte & 0x7F) as u64;
+
+      //Only a single bit of the tenth byte fits in a `u64`.
+      if shift == 63 && low > 1 { return Err(CodeError::Overflow) }
+
+      value |= low << shift;
+      if byte & 0x80 == 0 {
+        if byte == 0 && shift > 0 { return Err(CodeError::Overlong) }
+
+        return Ok(value)
+      }
+
+      shift += 7;
+      if shift > 63 { return Err(CodeError::Overflow) }
+    }
+  }
+  /// Reads a signed LEB128 code.
+  /// 
+  /// If the code has trailing sign extension bytes `CodeError::Overlong` is returned and
+  /// if its value does not fit in an `i64` `CodeError::Overflow` is returned.
+  /// 
+  /// Aligned readers read the code a byte at a time with `read_bytes`.
+  fn read_sleb128(&mut self,) -> Result<i64, CodeError<Self::Error,>> {
+    let mut value = 0;
+    let mut shift = 0;
+    let mut last = None;
+    loop {
+      let byte = read_code_byte(self,)?;
+      let low = (byte & 0x7F) as i64;
+
+      //The tenth byte may only hold the sign bit and its extension.
+      if shift == 63 && !(byte == 0 || byte == 0x7F) { return Err(CodeError::Overflow) }
+
+      value |= low << shift;
+      shift += 7;
+      if byte & 0x80 == 0 {
+        //The final byte only repeats the sign of the previous byte.
+        let sign = byte & 0x40 != 0;
+        if let Some(last) = last {
+          if (byte == 0 || byte == 0x7F) && (last & 0x40 != 0) == sign { return Err(CodeError::Overlong) }
+        }
+        //Sign extend the value.
+        if sign && shift < 64 { value |= !0 << shift }
+
+        return Ok(value)
+      }
+
+      last = Some(byte,);
+    }
+  }
+  /// Reads a LEB128 code for a signed value mapped by `zigzag_encode`.
+  #[inline]
+  fn read_zigzag_leb128(&mut self,) -> Result<i64, CodeError<Self::Error,>> {
+    self.read_uleb128().map(zigzag_decode,)
+  }
+  /// Reads a Fibonacci code, the Zeckendorf representation of the value from the
+  /// smallest Fibonacci number up ended by an extra one.
+  /// 
+  /// If the value does not fit in a `u64` `CodeError::Overflow` is returned, use
+  /// `resync_fibonacci` to find the start of the next code after an error.
+  fn read_fibonacci(&mut self,) -> Result<u64, CodeError<Self::Error,>> {
+    let mut value = 0u64;
+    let mut index = 0;
+    loop {
+      //Skip the zeros before the next one.
+      let zeros = self.read_truncated_unary((FIBONACCI.len() + 1 - index) as u64, true,)?;
+      //Two ones in a row end the code.
+      if zeros == 0 && index > 0 { return Ok(value) }
+
+      index += zeros as usize;
+      if index >= FIBONACCI.len() { return Err(CodeError::Overflow) }
+
+      value = value.checked_add(FIBONACCI[index],).ok_or(CodeError::Overflow,)?;
+      index += 1;
+    }
+  }
+  /// Skips bits up to and including the next pair of ones which end a Fibonacci code.
+  /// 
+  /// Returns the number of bits skipped, the next bit read starts a new code.
+  fn resync_fibonacci(&mut self,) -> Result<u64, Self::Error> {
+    let mut skipped = 0;
+    let mut after_one = false;
+    loop {
+      let zeros = self.read_unary(true,)?;
+
+      skipped += zeros + 1;
+      if zeros == 0 && after_one { return Ok(skipped) }
+      after_one = true;
+    }
+  }
+  /// Reads bytes from the input until `buf` is filled.
+  /// 
+  /// If an error is returned the number of bytes which were read into `buf` is returned
+  /// with it.
+  /// 
+  /// # Params
+  /// 
+  /// buf --- The buffer to fill.  
+  fn read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, Self::Error,)> {
+    for (read, byte,) in buf.iter_mut().enumerate() {
+      *byte = self.read_byte().map_err(move |e,| (read, e,),)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// A reader which can look at upcoming bits without reading them.
+pub trait BitPeek: BitRead {
+  /// The order bits are read from each byte.
+  const ORDER: BitOrder;
+
+  /// Returns the next `bits` bits without reading them and the number of those bits
+  /// which are available.
+  /// 
+  /// For `BitOrder::Msb` the next bit is the highest of the `bits` low bits returned,
+  /// for `BitOrder::Lsb` it is the lowest. Bits past the end of the input are zero, any
+  /// error is reported by the next read instead.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits to look at, at most `56`.  
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,);
 }
 
 impl<R,> BitRead for &'_ mut R
@@ -66,6 +383,75 @@ impl<R,> BitRead for &'_ mut R
   fn read_byte(&mut self,) -> Result<u8, Self::Error> { R::read_byte(self,) }
   #[inline]
   fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> { R::read_bits(*self, bits,) }
+  #[inline]
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> { R::read_word(*self, bits,) }
+  #[inline]
+  fn read_unary(&mut self, terminator: bool,) -> Result<u64, Self::Error> { R::read_unary(*self, terminator,) }
+  #[inline]
+  fn read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, Self::Error> {
+    R::read_truncated_unary(*self, max, terminator,)
+  }
+  #[inline]
+  fn read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, Self::Error,)> { R::read_bytes(*self, buf,) }
+}
+
+impl<R,> BitPeek for &'_ mut R
+  where R: BitPeek, {
+  const ORDER: BitOrder = R::ORDER;
+
+  #[inline]
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) { R::peek_word(*self, bits,) }
+}
+
+/// Reads a byte of a byte oriented code, with `read_bytes` if `reader` is aligned so
+/// that aligned readers can skip their bitwise path.
+#[inline]
+fn read_code_byte<R,>(reader: &mut R,) -> Result<u8, R::Error>
+  where R: BitRead + ?Sized, {
+  if !reader.is_aligned() { return reader.read_byte() }
+
+  let mut byte = [0];
+  reader.read_bytes(&mut byte,).map_err(|(_, e,),| e,)?;
+
+  Ok(byte[0])
+}
+
+/// Counts the bits at the top of `acc` before a `terminator` bit.
+/// 
+/// Returns the number of bits counted and whether the terminator was found, at most
+/// `limit` bits are counted.
+/// 
+/// # Params
+/// 
+/// acc --- The bits to count, stored in the high bits.  
+/// acc_bits --- The number of bits stored in `acc`.  
+/// limit --- The most bits to count.  
+/// terminator --- The bit which ends the count.  
+pub(crate) fn count_run(acc: u64, acc_bits: u8, limit: u64, terminator: bool,) -> (u8, bool,) {
+  let run = if terminator { acc } else { !acc }.leading_zeros() as u8;
+  let run = u64::min(run.min(acc_bits,) as u64, limit,) as u8;
+
+  (run, run < acc_bits && (run as u64) < limit,)
+}
+
+/// Takes `bits` bits from the top of `acc` followed by the high bits of `byte`.
+/// 
+/// This completes a read of more bits than the accumulator can always hold once the
+/// last byte is available, so that a failed read does not remove any bits.  
+/// Returns the bits read and the remaining low bits of `byte` as the new accumulator
+/// and its length.
+/// 
+/// # Params
+/// 
+/// acc --- The bits to read first, stored in the high bits.  
+/// acc_bits --- The number of bits stored in `acc`, at least `bits - 8`.  
+/// byte --- The byte after `acc`.  
+/// bits --- The number of bits to read, more than `acc_bits`.  
+pub(crate) fn take_past(acc: u64, acc_bits: u8, byte: u8, bits: u8,) -> (u64, u64, u8,) {
+  let need = bits - acc_bits;
+  let word = (acc.checked_shr(64 - acc_bits as u32,).unwrap_or(0,) << need) | (byte >> (8 - need)) as u64;
+
+  (word, ((byte as u64) << 56).checked_shl(need as u32,).unwrap_or(0,), 8 - need,)
 }
 
 /// Wraps a byte and reads from it high bits first.
@@ -179,14 +565,20 @@ impl<B,> From<B> for ReadByte<B,>
 }
 
 /// Wraps an iterator of bytes and reads from it bitwise, high bits first.
+/// 
+/// Bytes are only taken from the iterator when they are needed so the iterator can
+/// always be unwrapped once the reader is aligned, `into_parts` also returns any whole
+/// bytes which were taken to peek ahead.
 #[derive(Clone, Copy, Debug,)]
 pub struct ReadIter<I,>
   where I: Iterator,
     I::Item: Borrow<u8>, {
   /// The iterator of bytes to read.
   iterator: I,
-  /// The current byte being read.
-  buffer: ReadByte<u8>,
+  /// The bits waiting to be read, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
 }
 
 impl<I,> ReadIter<I,>
@@ -199,26 +591,63 @@ impl<I,> ReadIter<I,>
   /// iter --- The iterator to read from.  
   pub fn new<Iter,>(iter: Iter,) -> Self
     where Iter: IntoIterator<IntoIter = I, Item = I::Item>, {
-    Self { iterator: iter.into_iter(), buffer: ReadByte::EMPTY, }
+    Self { iterator: iter.into_iter(), acc: 0, acc_bits: 0, }
   }
-  /// Returns the number of bytes left to read before this reader is aligned.
+  /// Returns the number of bits left to read before this reader is aligned.
   #[inline]
-  pub fn to_read(&self,) -> Option<Bits> { self.buffer.to_read() }
+  pub fn to_read(&self,) -> Option<Bits> { Bits::try_from(self.acc_bits % 8,).ok() }
   /// Clears the internal byte buffer so that this reader is aligned.
   pub fn clear_buf(&mut self,) -> &mut Self {
-    if let Some(bits) = self.to_read() {
-      self.skip(bits,).ok();
-    }
+    self.consume(self.acc_bits % 8,);
 
     self
   }
   /// Unwraps the inner iterator if the reader is aligned.
+  /// 
+  /// Whole bytes which were taken from the iterator to peek ahead are dropped, use
+  /// `into_parts` to keep them.
+  #[allow(clippy::should_implement_trait,)]
   pub fn into_iter(self,) -> Result<I, UnalignedError<Self,>> {
-    match self.buffer.to_read() {
+    self.into_parts().map(|(iter, _,),| iter,)
+  }
+  /// Unwraps the inner iterator if the reader is aligned.
+  /// 
+  /// Any whole bytes which were taken from the iterator but not read are returned with
+  /// it.
+  pub fn into_parts(self,) -> Result<(I, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_read() {
       Some(misalign) => Err(UnalignedError(self, misalign,)),
-      None => Ok(self.iterator),
+      None => {
+        let buffered = self.acc.to_be_bytes()[..self.acc_bits as usize / 8].to_vec();
+
+        Ok((self.iterator, buffered,))
+      },
     }
   }
+  /// Removes `bits` bits from the accumulator.
+  #[inline]
+  fn consume(&mut self, bits: u8,) {
+    self.acc = self.acc.checked_shl(bits as u32,).unwrap_or(0,);
+    self.acc_bits -= bits;
+  }
+  /// Takes whole bytes from the iterator until the accumulator holds at least `bits`
+  /// bits.
+  /// 
+  /// If the iterator runs out the number of bits available is returned.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits needed, at most `57`.  
+  fn fill(&mut self, bits: u8,) -> Result<(), u8> {
+    while self.acc_bits < bits {
+      let byte = *self.iterator.next().ok_or(self.acc_bits,)?.borrow();
+
+      self.acc |= (byte as u64) << (56 - self.acc_bits);
+      self.acc_bits += 8;
+    }
+
+    Ok(())
+  }
 }
 
 impl<I,> BitRead for ReadIter<I,>
@@ -227,37 +656,66 @@ impl<I,> BitRead for ReadIter<I,>
   type Error = Option<Bits>;
 
   #[inline]
-  fn is_aligned(&self,) -> bool { self.buffer.is_aligned() }
+  fn is_aligned(&self,) -> bool { self.to_read().is_none() }
+  #[inline]
   fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
-    //Attempt to read the bits from the buffer.
-    let available = match self.buffer.read_bits(bits,) {
-      //Return the bits read.
-      Ok(v) => return Ok(v),
-      Err(v) => v,
-    };
-    //Read in the next byte.
-    let next_byte = *self.iterator.next().ok_or(available,)?.borrow();
-
-    //Read the bits.
-    if let Some(available) = available {
-      //The number of bits which need to be read from the next byte.
-      let remaining = unsafe { Bits::from_u8(bits as u8 - available as u8,) };
-      //Get the high bits from the current buffer and shift them into the higher bits of
-      //the output.
-      let high_bits = self.buffer.buffer << remaining as u8;
-      //Get the low bits from the next byte.
-      let low_bits = {
-        //Populate the buffer with the next byte and skip the bits being read now.
-        self.buffer.set(next_byte,).skip(remaining,).ok();
-
-        //Read the bits and shift them into the lower bits of the output.
-        //Apply the mask to clear the high bits of the part.
-        self.buffer.buffer >> (8 - remaining as u8)
-      };
-
-      //Combine the bits in the output.
-      Ok(high_bits | low_bits)
-    //Reset the byte and continue reading.
-    } else { self.buffer.set(next_byte,).read_bits(bits,) }
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, take the last byte of a
+    //longer read separately.
+    if bits > 56 {
+      self.fill(56,).map_err(|available,| Bits::try_from(available.min(8,),).ok(),)?;
+      if self.acc_bits < bits {
+        let byte = *self.iterator.next().ok_or(Some(Bits::B8,),)?.borrow();
+        let (res, acc, acc_bits,) = take_past(self.acc, self.acc_bits, byte, bits,);
+
+        self.acc = acc;
+        self.acc_bits = acc_bits;
+
+        return Ok(res)
+      }
+    }
+
+    //Ensure there are enough bits in the accumulator.
+    self.fill(bits,).map_err(|available,| Bits::try_from(available.min(8,),).ok(),)?;
+
+    //Take the bits from the top of the accumulator.
+    let res = self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,);
+
+    self.consume(bits,);
+
+    Ok(res)
+  }
+  fn read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, Self::Error> {
+    let mut count = 0;
+    while count < max {
+      self.fill(1,).map_err(|_,| None,)?;
+
+      //Count the bits in the accumulator.
+      let (run, terminated,) = count_run(self.acc, self.acc_bits, max - count, terminator,);
+
+      count += run as u64;
+      if terminated { self.consume(run + 1,); break }
+      self.consume(run,);
+    }
+
+    Ok(count)
+  }
+}
+
+impl<I,> BitPeek for ReadIter<I,>
+  where I: Iterator,
+    I::Item: Borrow<u8>, {
+  const ORDER: BitOrder = BitOrder::Msb;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    self.fill(bits,).ok();
+
+    (self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,), self.acc_bits.min(bits,),)
   }
 }
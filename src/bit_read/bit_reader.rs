@@ -1,85 +1,277 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-30
+//! Last Moddified --- 2026-10-18
 
 use super::*;
-use std::io::{self, Read, Write, Error,};
+use alloc::{vec::Vec, boxed::Box,};
+use std::io::{self, Read, Write, Error, ErrorKind,};
+
+/// The default capacity of the internal buffer of a `ReadIO`.
+pub const DEFAULT_READ_CAPACITY: usize = 8 * 1024;
 
 /// Wraps an IO reader and reads from it bitwise, high bits first.
-#[derive(Clone, Copy, Debug,)]
+/// 
+/// Bytes are read from the inner reader in blocks so that reading bits does not issue a
+/// call to the inner reader for every byte.
+#[derive(Clone, Debug,)]
 pub struct ReadIO<R,>
   where R: Read, {
-  /// The current byte being read.
-  buffer: ReadByte,
+  /// The bits waiting to be read, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+  /// The block of bytes read from the inner reader.
+  buffer: Box<[u8]>,
+  /// The index of the next byte in `buffer` to move into `acc`.
+  pos: usize,
+  /// The number of bytes in `buffer` which have been filled from the inner reader.
+  filled: usize,
   /// The reader of bytes to read.
   reader: R,
 }
 
 impl<R,> ReadIO<R,>
   where R: Read, {
-  /// Constructs a new `ReadIO` over the reader.
+  /// Constructs a new `ReadIO` over the reader with the default buffer capacity.
   /// 
   /// # Params
   /// 
   /// reader --- The reader to read from.  
   #[inline]
-  pub const fn new(reader: R,) -> Self {
-    Self { reader, buffer: ReadByte::EMPTY, }
+  pub fn new(reader: R,) -> Self { Self::with_capacity(DEFAULT_READ_CAPACITY, reader,) }
+  /// Constructs a new `ReadIO` over the reader which reads blocks of `capacity` bytes.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The size of the internal buffer, at least one byte is used.  
+  /// reader --- The reader to read from.  
+  pub fn with_capacity(capacity: usize, reader: R,) -> Self {
+    Self {
+      acc: 0,
+      acc_bits: 0,
+      buffer: alloc::vec![0; capacity.max(1,)].into_boxed_slice(),
+      pos: 0,
+      filled: 0,
+      reader,
+    }
   }
-  /// Returns the number of bytes left to read before this reader is aligned.
-  pub fn to_read(&self,) -> Option<Bits> { self.buffer.to_read().filter(|&b,| b != Bits::B8,) }
+  /// Returns the capacity of the internal buffer.
+  #[inline]
+  pub fn capacity(&self,) -> usize { self.buffer.len() }
+  /// Returns the number of bits left to read before this reader is aligned.
+  pub fn to_read(&self,) -> Option<Bits> { Bits::try_from(self.acc_bits % 8,).ok() }
   /// Clears the internal byte buffer so that this reader is aligned.
   pub fn clear_buf(&mut self,) -> &mut Self {
-    if let Some(bits) = self.buffer.to_read() {
-      self.buffer.skip(bits,)
-    }
+    self.consume(self.acc_bits % 8,);
 
     self
   }
-  /// Unwraps the inner reader if the inner buffer is empty.
-  pub fn into_reader(self,) -> Result<R, UnalignedError<Self,>> {
-    match self.buffer.cursor {
-      None => Ok(self.reader),
-      Some(misalign) => Err(UnalignedError(self, misalign,))
+  /// Returns the whole bytes which have been read from the inner reader but not yet
+  /// read from this reader.
+  /// 
+  /// Any bits before this reader is aligned are not included.
+  pub fn buffered(&self,) -> Vec<u8> {
+    let mut acc = self.acc.checked_shl((self.acc_bits % 8) as u32,).unwrap_or(0,);
+    let mut bytes = Vec::with_capacity((self.acc_bits / 8) as usize + self.filled - self.pos,);
+
+    for _ in 0..self.acc_bits / 8 {
+      bytes.push((acc >> 56) as u8,);
+      acc <<= 8;
+    }
+    bytes.extend_from_slice(&self.buffer[self.pos..self.filled],);
+
+    bytes
+  }
+  /// Unwraps the inner reader if the reader is aligned.
+  /// 
+  /// The bytes which were read from the inner reader but not yet read from this reader
+  /// are returned with the inner reader.
+  pub fn into_reader(self,) -> Result<(R, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_read() {
+      None => {
+        let buffered = self.buffered();
+
+        Ok((self.reader, buffered,))
+      },
+      Some(misalign) => Err(UnalignedError(self, misalign,)),
     }
   }
+  /// Removes `bits` bits from the accumulator.
+  #[inline]
+  fn consume(&mut self, bits: u8,) {
+    self.acc = self.acc.checked_shl(bits as u32,).unwrap_or(0,);
+    self.acc_bits -= bits;
+  }
+  /// Refills the accumulator until it holds at least `bits` bits.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits needed, at most `57`.  
+  fn fill(&mut self, bits: u8,) -> Result<(), (Option<Bits>, Error,)> {
+    while self.acc_bits < bits {
+      let available = Bits::try_from(self.acc_bits.min(8,),).ok();
+
+      match self.refill() {
+        Ok(true) => {},
+        Ok(false) => return Err((available, ErrorKind::UnexpectedEof.into(),)),
+        Err(e) => return Err((available, e,)),
+      }
+    }
+
+    Ok(())
+  }
+  /// Reads a new block from the inner reader if the internal buffer is empty.
+  /// 
+  /// Returns `Ok(false)` if the inner reader has no more bytes.
+  fn fill_buffer(&mut self,) -> io::Result<bool> {
+    if self.pos < self.filled { return Ok(true) }
+
+    self.filled = loop {
+      match self.reader.read(&mut self.buffer,) {
+        Ok(filled) => break filled,
+        Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+        Err(e) => return Err(e),
+      }
+    };
+    self.pos = 0;
+
+    Ok(self.filled > 0)
+  }
+  /// Moves bytes from the internal buffer into the accumulator, reading a new block
+  /// from the inner reader if the internal buffer is empty.
+  /// 
+  /// Returns `Ok(false)` if the inner reader has no more bytes.
+  fn refill(&mut self,) -> io::Result<bool> {
+    if !self.fill_buffer()? { return Ok(false) }
+
+    //Move as many whole bytes as will fit into the accumulator.
+    while self.acc_bits <= 56 && self.pos < self.filled {
+      self.acc |= (self.buffer[self.pos] as u64) << (56 - self.acc_bits);
+      self.acc_bits += 8;
+      self.pos += 1;
+    }
+
+    Ok(true)
+  }
 }
 
 impl<R,> BitRead for ReadIO<R,>
   where R: Read, {
-  /// The number of bytes available to read and the error encountered.
+  /// The number of bits available to read and the error encountered.
   type Error = (Option<Bits>, Error,);
 
   #[inline]
-  fn is_aligned(&self,) -> bool { self.buffer.is_aligned() }
+  fn is_aligned(&self,) -> bool { self.to_read().is_none() }
+  #[inline]
   fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
-    //Attempt to read the bits from the buffer, store the number of bits in the buffer if
-    //not enough are avaialable.
-    let available = match self.buffer.read_bits(bits,) {
-      //Return the bits read.
-      Ok(v) => return Ok(v),
-      Err(v) => v,
-    };
-    //Read in the next byte.
-    let mut next_byte = 0;
-    self.reader.read_exact(core::slice::from_mut(&mut next_byte,),).map_err(|e,| (available, e,),)?;
-
-    //The number of bits which need to be read from the next byte.
-    let remaining = unsafe { Bits::from_u8(bits as u8 - Bits::as_u8(available,),) };
-    //Get the high bits from the current buffer and shift them into the higher bits of
-    //the output.
-    let high_bits = self.buffer.buffer.wrapping_shl(remaining as u32,);
-    //Get the low bits from the next byte.
-    let low_bits = {
-      //Populate the buffer with the next byte and skip the bits being read now.
-      self.buffer.set(*next_byte.borrow(),).skip(remaining,).ok();
-
-      //Read the bits and shift them into the lower bits of the output.
-      //Apply the mask to clear the high bits of the part.
-      self.buffer.buffer.wrapping_shr(8 - remaining as u32,) & remaining.mask()
-    };
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, take the last byte of a
+    //longer read separately.
+    if bits > 56 {
+      self.fill(56,)?;
+      if self.acc_bits < bits {
+        match self.fill_buffer() {
+          Ok(true) => {},
+          Ok(false) => return Err((Some(Bits::B8,), ErrorKind::UnexpectedEof.into(),)),
+          Err(e) => return Err((Some(Bits::B8,), e,)),
+        }
+
+        let (res, acc, acc_bits,) = take_past(self.acc, self.acc_bits, self.buffer[self.pos], bits,);
+
+        self.acc = acc;
+        self.acc_bits = acc_bits;
+        self.pos += 1;
+
+        return Ok(res)
+      }
+    }
+
+    //Ensure there are enough bits in the accumulator.
+    self.fill(bits,)?;
+
+    //Take the bits from the top of the accumulator.
+    let res = self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,);
+
+    self.consume(bits,);
+
+    Ok(res)
+  }
+  fn read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, Self::Error> {
+    let mut count = 0;
+    while count < max {
+      self.fill(1,).map_err(|(_, e,),| (None, e,),)?;
+
+      //Count the bits in the accumulator.
+      let (run, terminated,) = count_run(self.acc, self.acc_bits, max - count, terminator,);
+
+      count += run as u64;
+      if terminated { self.consume(run + 1,); break }
+      self.consume(run,);
+    }
+
+    Ok(count)
+  }
+  fn read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, Self::Error,)> {
+    if !self.is_aligned() {
+      for (read, byte,) in buf.iter_mut().enumerate() {
+        *byte = self.read_byte().map_err(move |e,| (read, e,),)?;
+      }
+
+      return Ok(())
+    }
+
+    let mut read = 0;
+    //Empty the accumulator first.
+    while self.acc_bits > 0 && read < buf.len() {
+      buf[read] = (self.acc >> 56) as u8;
+      self.acc <<= 8;
+      self.acc_bits -= 8;
+      read += 1;
+    }
+    //Copy directly from the internal buffer.
+    while read < buf.len() {
+      //Large reads bypass the internal buffer.
+      if self.pos == self.filled && buf.len() - read >= self.buffer.len() {
+        match self.reader.read(&mut buf[read..],) {
+          Ok(0) => return Err((read, (None, ErrorKind::UnexpectedEof.into(),),)),
+          Ok(len) => read += len,
+          Err(e) if e.kind() == ErrorKind::Interrupted => {},
+          Err(e) => return Err((read, (None, e,),)),
+        }
+
+        continue;
+      }
+
+      match self.fill_buffer() {
+        Ok(true) => {},
+        Ok(false) => return Err((read, (None, ErrorKind::UnexpectedEof.into(),),)),
+        Err(e) => return Err((read, (None, e,),)),
+      }
+
+      let len = usize::min(self.filled - self.pos, buf.len() - read,);
+
+      buf[read..read + len].copy_from_slice(&self.buffer[self.pos..self.pos + len],);
+      self.pos += len;
+      read += len;
+    }
+
+    Ok(())
+  }
+}
+
+impl<R,> BitPeek for ReadIO<R,>
+  where R: Read, {
+  const ORDER: BitOrder = BitOrder::Msb;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    self.fill(bits,).ok();
 
-    //Combine the bits in the output.
-    Ok(high_bits ^ low_bits)
+    (self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,), self.acc_bits.min(bits,),)
   }
 }
 
@@ -0,0 +1,273 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use alloc::vec::Vec;
+
+/// The error returned when reading from a `BitUnstuffReader`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum UnstuffError<E,> {
+  /// Reading from the inner reader failed.
+  Read(E),
+  /// A flag was reached before enough bits were read.
+  Flag,
+  /// An abort was reached before enough bits were read.
+  Abort,
+  /// A frame ended part way through a byte.
+  Unaligned,
+}
+
+impl<E,> From<E> for UnstuffError<E,> {
+  #[inline]
+  fn from(from: E,) -> Self { UnstuffError::Read(from,) }
+}
+
+/// A flag or abort found in the stuffed bits.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum Marker {
+  /// The stuffed bit, `run_length + 1` opposite bits and the stuffed bit.
+  Flag,
+  /// `run_length + 2` opposite bits.
+  Abort,
+}
+
+/// Wraps a reader and removes the bits stuffed by a `BitStuffWriter`, stopping at flags
+/// and aborts.
+/// 
+/// Reads which reach a flag or abort fail with `UnstuffError::Flag` or
+/// `UnstuffError::Abort` without removing any bits, the bits before it can still be read
+/// and `skip_marker` moves past it. `read_frame` splits the stream into frames.
+/// 
+/// The bits of a word are read in the order of the inner reader.
+#[derive(Clone, Copy, Debug,)]
+pub struct BitUnstuffReader<R,>
+  where R: BitRead, {
+  /// The reader to take the stuffed bits from.
+  reader: R,
+  /// The number of opposite bits after which a bit is stuffed.
+  run_length: u8,
+  /// The value of the stuffed bit.
+  stuffed: bool,
+  /// The unstuffed bits waiting to be read, the next bit is the lowest.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+  /// `true` if a stuffed bit value is held back as it may begin a flag.
+  held: bool,
+  /// The number of opposite bits held back, up to `run_length + 2`.
+  run: u8,
+  /// The flag or abort after the bits in `acc`.
+  marker: Option<Marker>,
+  /// The number of bits read since the last marker modulo `8`.
+  read: u8,
+  /// `true` once a flag has been found by `read_frame`.
+  synced: bool,
+}
+
+impl<R,> BitUnstuffReader<R,>
+  where R: BitRead, {
+  /// Constructs a new `BitUnstuffReader` which removes the `0` after five `1`s, as in
+  /// HDLC.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from.  
+  #[inline]
+  pub const fn new(reader: R,) -> Self { Self::with_params(reader, 5, false,) }
+  /// Constructs a new `BitUnstuffReader` with a custom run length and stuffed bit.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from.  
+  /// run_length --- The number of opposite bits after which a bit is stuffed, from `1` to `8`.  
+  /// stuffed --- The value of the stuffed bit.  
+  pub const fn with_params(reader: R, run_length: u8, stuffed: bool,) -> Self {
+    assert!(run_length >= 1 && run_length <= 8, "The run length must be between 1 and 8",);
+
+    Self {
+      reader, run_length, stuffed,
+      acc: 0, acc_bits: 0, held: false, run: 0, marker: None, read: 0, synced: false,
+    }
+  }
+  /// Returns a reference to the inner reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the inner reader.
+  /// 
+  /// Any bits which were taken from the inner reader but not read are lost.
+  #[inline]
+  pub fn into_inner(self,) -> R { self.reader }
+  /// Moves past the flag or abort which stopped a read, discarding the bits before it.
+  /// 
+  /// Returns the number of bits discarded or `None` if no flag or abort has been reached.
+  pub fn skip_marker(&mut self,) -> Option<u8> {
+    self.marker.take()?;
+
+    let discarded = self.acc_bits;
+    self.acc = 0;
+    self.acc_bits = 0;
+    self.read = 0;
+
+    Some(discarded)
+  }
+  /// Appends the bytes of the next frame to `frame`.
+  /// 
+  /// The first call skips everything before the first flag. Repeated flags between
+  /// frames are skipped.
+  /// If an abort is reached the frame is abandoned and the next call skips everything
+  /// before the next flag. If a frame does not end on a byte `UnstuffError::Unaligned` is
+  /// returned with its whole bytes in `frame`.
+  /// 
+  /// # Params
+  /// 
+  /// frame --- The buffer to append the frame to.  
+  pub fn read_frame(&mut self, frame: &mut Vec<u8>,) -> Result<(), UnstuffError<R::Error,>>
+    where R: BitPeek, {
+    //Hunt for the flag which starts the frame.
+    while !self.synced {
+      match self.read_word(56,) {
+        Ok(_) => {},
+        Err(UnstuffError::Flag) => { self.skip_marker(); self.synced = true; },
+        Err(UnstuffError::Abort) => { self.skip_marker(); },
+        Err(e) => return Err(e),
+      }
+    }
+
+    let start = frame.len();
+    loop {
+      match self.read_byte() {
+        Ok(byte) => frame.push(byte,),
+        Err(UnstuffError::Flag) => {
+          if self.skip_marker() != Some(0) { return Err(UnstuffError::Unaligned) }
+          if frame.len() > start { return Ok(()) }
+        },
+        Err(UnstuffError::Abort) => {
+          self.skip_marker();
+          self.synced = false;
+
+          return Err(UnstuffError::Abort)
+        },
+        Err(e) => return Err(e),
+      }
+    }
+  }
+  /// Adds a bit to the end of `acc`.
+  #[inline]
+  fn push(&mut self, bit: bool,) {
+    self.acc |= (bit as u64) << self.acc_bits;
+    self.acc_bits += 1;
+  }
+  /// Reads a stuffed bit, moving any held bits which cannot be part of a flag into `acc`.
+  fn decode(&mut self,) -> Result<(), R::Error> {
+    let bit = self.reader.read_bit()?;
+    let limit = self.run_length;
+
+    if bit != self.stuffed {
+      if self.run == limit + 1 {
+        //A bit held before an abort was data.
+        if self.held { self.push(self.stuffed,); }
+
+        self.marker = Some(Marker::Abort,);
+        self.held = false;
+      }
+      self.run = (self.run + 1).min(limit + 2,);
+
+      return Ok(())
+    }
+
+    if self.run == limit + 1 {
+      self.marker = Some(Marker::Flag,);
+      self.held = false;
+    } else if self.run > limit + 1 {
+      //The end of an abort may begin a flag.
+      self.held = true;
+    } else {
+      if self.held { self.push(self.stuffed,); }
+      for _ in 0..self.run { self.push(!self.stuffed,); }
+
+      //A bit after a full run is stuffed.
+      self.held = self.run < limit;
+    }
+    self.run = 0;
+
+    Ok(())
+  }
+  /// Decodes bits until `acc` holds at least `bits` bits or a marker is reached.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits needed, at most `56`.  
+  fn fill(&mut self, bits: u8,) -> Result<(), R::Error> {
+    while self.acc_bits < bits && self.marker.is_none() { self.decode()?; }
+
+    Ok(())
+  }
+  /// Returns the error for a read which reached the marker.
+  fn marker_error(&self,) -> UnstuffError<R::Error,> {
+    match self.marker {
+      Some(Marker::Abort) => UnstuffError::Abort,
+      _ => UnstuffError::Flag,
+    }
+  }
+}
+
+/// Reverses the order of the low `bits` bits of `word`.
+#[inline]
+fn reverse(word: u64, bits: u8,) -> u64 { word.reverse_bits().checked_shr(64 - bits as u32,).unwrap_or(0,) }
+
+impl<R,> BitRead for BitUnstuffReader<R,>
+  where R: BitPeek, {
+  type Error = UnstuffError<R::Error,>;
+
+  /// Returns `true` if a multiple of `8` bits have been read since the last flag or
+  /// abort.
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.read == 0 }
+  #[inline]
+  fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, split the read.
+    if bits > 56 {
+      return match R::ORDER {
+        BitOrder::Msb => Ok((self.read_word(bits - 32,)? << 32) | self.read_word(32,)?),
+        BitOrder::Lsb => Ok(self.read_word(32,)? | (self.read_word(bits - 32,)? << 32)),
+      }
+    }
+
+    self.fill(bits,)?;
+    if self.acc_bits < bits { return Err(self.marker_error()) }
+
+    let word = self.acc & (1u64 << bits).wrapping_sub(1,);
+    self.acc = self.acc.checked_shr(bits as u32,).unwrap_or(0,);
+    self.acc_bits -= bits;
+    self.read = (self.read + bits) % 8;
+
+    Ok(match R::ORDER {
+      BitOrder::Msb => reverse(word, bits,),
+      BitOrder::Lsb => word,
+    })
+  }
+}
+
+impl<R,> BitPeek for BitUnstuffReader<R,>
+  where R: BitPeek, {
+  const ORDER: BitOrder = R::ORDER;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    self.fill(bits,).ok();
+
+    let word = self.acc & (1u64 << bits).wrapping_sub(1,);
+    let word = match R::ORDER {
+      BitOrder::Msb => reverse(word, bits,),
+      BitOrder::Lsb => word,
+    };
+
+    (word, self.acc_bits.min(bits,),)
+  }
+}
@@ -0,0 +1,94 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// The error returned when a backward bitstream is empty or its last byte is `0`, so
+/// that there is no sentinel bit marking the end of the data.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct SentinelError;
+
+/// Reads a bitstream from its end towards its start, as written by a `WriteBackward`.
+/// 
+/// The data ends below the highest set bit of the last byte, the sentinel. Reads take
+/// the bits closest to the end first so the first bit read is the highest bit of a word.  
+/// Reading more bits than are left reads nothing and reports the bits available.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub struct ReadBackward<'a,> {
+  /// The bytes of the stream.
+  bytes: &'a [u8],
+  /// The number of bits left to read.
+  pos: usize,
+}
+
+impl<'a,> ReadBackward<'a,> {
+  /// Constructs a new `ReadBackward` over a stream, finding the sentinel bit.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes of the stream.  
+  pub fn new(bytes: &'a [u8],) -> Result<Self, SentinelError> {
+    match bytes.last() {
+      Some(&last) if last != 0 => {
+        let pos = (bytes.len() - 1) * 8 + 7 - last.leading_zeros() as usize;
+
+        Ok(Self { bytes, pos, })
+      },
+      _ => Err(SentinelError),
+    }
+  }
+  /// Returns the number of bits left to read.
+  #[inline]
+  pub const fn bits_left(&self,) -> usize { self.pos }
+  /// Returns `true` if every bit has been read.
+  #[inline]
+  pub const fn is_empty(&self,) -> bool { self.pos == 0 }
+  /// Returns the `bits` bits below `pos`.
+  fn bits_below(&self, pos: usize, bits: u8,) -> u64 {
+    let start = (pos - bits as usize) / 8;
+    let end = usize::min(start + 8, self.bytes.len(),);
+    let mut word = [0; 8];
+    word[..end - start].copy_from_slice(&self.bytes[start..end],);
+
+    (u64::from_le_bytes(word,) >> ((pos - bits as usize) % 8)) & (1u64 << bits).wrapping_sub(1,)
+  }
+}
+
+impl BitRead for ReadBackward<'_,> {
+  type Error = Option<Bits>;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.pos.is_multiple_of(8,) }
+  #[inline]
+  fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    if bits as usize > self.pos { return Err(Bits::try_from(self.pos.min(8,) as u8,).ok()) }
+    //A word cannot always hold more than 56 bits, split the read.
+    if bits > 56 {
+      let high = self.read_word(bits - 32,)?;
+
+      return Ok((high << 32) | self.read_word(32,)?)
+    }
+
+    let res = self.bits_below(self.pos, bits,);
+    self.pos -= bits as usize;
+
+    Ok(res)
+  }
+}
+
+impl BitPeek for ReadBackward<'_,> {
+  const ORDER: BitOrder = BitOrder::Msb;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    let available = bits.min(self.pos.min(56,) as u8,);
+
+    (self.bits_below(self.pos, available,) << (bits - available), available,)
+  }
+}
@@ -0,0 +1,193 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// Wraps a byte aligned reader over the entropy coded data of a JPEG scan and reads it
+/// bitwise, high bits first.
+/// 
+/// The `0x00` stuffed after each `0xFF` data byte is dropped and fill `0xFF` bytes before
+/// a marker are skipped. Reading stops at the first marker, such as `RSTn` or `EOI`,
+/// which is taken from the inner reader and returned by `marker`. Reads past the marker
+/// are filled with `1` bits and recorded by `is_filled`.
+#[derive(Clone, Copy, Debug,)]
+pub struct JpegScanReader<R,>
+  where R: BitRead, {
+  /// The byte aligned reader to take the stuffed bytes from.
+  reader: R,
+  /// `true` if a `0xFF` has been taken and the byte after it has not.
+  escape: bool,
+  /// The code of the marker which ended the data.
+  marker: Option<u8>,
+  /// `true` if a read went past the marker.
+  filled: bool,
+  /// The bits waiting to be read, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+  /// The number of bits in `acc` which came before the marker.
+  data_bits: u8,
+}
+
+impl<R,> JpegScanReader<R,>
+  where R: BitRead, {
+  /// Constructs a new `JpegScanReader` over the reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from, it should be aligned.  
+  pub const fn new(reader: R,) -> Self {
+    Self { reader, escape: false, marker: None, filled: false, acc: 0, acc_bits: 0, data_bits: 0, }
+  }
+  /// Returns the code of the marker which ended the data, once it has been reached.
+  /// 
+  /// Restart markers are `0xD0` to `0xD7` and the end of image marker is `0xD9`.
+  #[inline]
+  pub const fn marker(&self,) -> Option<u8> { self.marker }
+  /// Returns `true` if a read went past the marker and was filled with `1` bits.
+  #[inline]
+  pub const fn is_filled(&self,) -> bool { self.filled }
+  /// Returns the number of bits left to read before this reader is aligned.
+  #[inline]
+  pub fn to_read(&self,) -> Option<Bits> { Bits::try_from(self.acc_bits % 8,).ok() }
+  /// Discards the bits before the marker and moves past it so that the data after a
+  /// restart marker can be read.
+  /// 
+  /// Returns the code of the marker. If the marker has not been reached yet every data
+  /// byte up to it is discarded.
+  pub fn restart(&mut self,) -> Result<u8, R::Error> {
+    let marker = loop {
+      if let Some(marker) = self.marker.take() { break marker }
+
+      self.next_byte()?;
+    };
+
+    self.acc = 0;
+    self.acc_bits = 0;
+    self.data_bits = 0;
+    self.filled = false;
+
+    Ok(marker)
+  }
+  /// Returns a reference to the inner reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the inner reader.
+  /// 
+  /// Any bits which were taken from the inner reader but not read are lost.
+  #[inline]
+  pub fn into_inner(self,) -> R { self.reader }
+  /// Takes the next data byte from the inner reader.
+  /// 
+  /// Returns `None` once a marker is reached.
+  fn next_byte(&mut self,) -> Result<Option<u8>, R::Error> {
+    if self.marker.is_some() { return Ok(None) }
+
+    loop {
+      let byte = self.reader.read_byte()?;
+      if !self.escape {
+        if byte != 0xFF { return Ok(Some(byte,)) }
+
+        self.escape = true;
+        continue
+      }
+
+      match byte {
+        //Fill bytes may come before a marker.
+        0xFF => continue,
+        0x00 => { self.escape = false; return Ok(Some(0xFF,)) },
+        code => {
+          self.escape = false;
+          self.marker = Some(code,);
+
+          return Ok(None)
+        },
+      }
+    }
+  }
+  /// Removes `bits` bits from the accumulator.
+  #[inline]
+  fn consume(&mut self, bits: u8,) {
+    if bits > self.data_bits { self.filled = true; }
+
+    self.acc = self.acc.checked_shl(bits as u32,).unwrap_or(0,);
+    self.acc_bits -= bits;
+    self.data_bits = self.data_bits.saturating_sub(bits,);
+  }
+  /// Takes whole bytes until the accumulator holds at least `bits` bits, using `0xFF`
+  /// once the marker is reached.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits needed, at most `57`.  
+  fn fill(&mut self, bits: u8,) -> Result<(), R::Error> {
+    while self.acc_bits < bits {
+      let byte = match self.next_byte()? {
+        Some(byte) => { self.data_bits += 8; byte },
+        None => 0xFF,
+      };
+
+      self.acc |= (byte as u64) << (56 - self.acc_bits);
+      self.acc_bits += 8;
+    }
+
+    Ok(())
+  }
+}
+
+impl<R,> BitRead for JpegScanReader<R,>
+  where R: BitRead, {
+  type Error = R::Error;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.to_read().is_none() }
+  #[inline]
+  fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, take the last byte of a
+    //longer read separately.
+    if bits > 56 {
+      self.fill(56,)?;
+      if self.acc_bits < bits {
+        let byte = match self.next_byte()? {
+          Some(byte) => { self.data_bits += 8; byte },
+          None => 0xFF,
+        };
+        let (res, acc, acc_bits,) = take_past(self.acc, self.acc_bits, byte, bits,);
+
+        if bits > self.data_bits { self.filled = true; }
+        self.acc = acc;
+        self.acc_bits = acc_bits;
+        self.data_bits = self.data_bits.saturating_sub(bits,);
+
+        return Ok(res)
+      }
+    }
+
+    self.fill(bits,)?;
+
+    //Take the bits from the top of the accumulator.
+    let res = self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,);
+
+    self.consume(bits,);
+
+    Ok(res)
+  }
+}
+
+impl<R,> BitPeek for JpegScanReader<R,>
+  where R: BitRead, {
+  const ORDER: BitOrder = BitOrder::Msb;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    self.fill(bits,).ok();
+
+    (self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,), self.acc_bits.min(bits,),)
+  }
+}
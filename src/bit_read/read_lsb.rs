@@ -0,0 +1,161 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use alloc::vec::Vec;
+
+/// Wraps a byte aligned reader and reads from it bitwise, low bits first.
+/// 
+/// This is the bit order of DEFLATE: the first bit read is the lowest bit of the first
+/// byte and the bits of a word are returned with the first bit read in the lowest bit.
+/// 
+/// Bytes are only taken from the inner reader when they are needed.
+#[derive(Clone, Copy, Debug,)]
+pub struct ReadLsb<R,>
+  where R: BitRead, {
+  /// The byte aligned reader to take bytes from.
+  reader: R,
+  /// The bits waiting to be read, stored in the low bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+}
+
+impl<R,> ReadLsb<R,>
+  where R: BitRead, {
+  /// Constructs a new `ReadLsb` over the reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from, it should be aligned.  
+  pub const fn new(reader: R,) -> Self { Self { reader, acc: 0, acc_bits: 0, } }
+  /// Returns the number of bits left to read before this reader is aligned.
+  #[inline]
+  pub fn to_read(&self,) -> Option<Bits> { Bits::try_from(self.acc_bits % 8,).ok() }
+  /// Clears the internal byte buffer so that this reader is aligned.
+  pub fn clear_buf(&mut self,) -> &mut Self {
+    self.consume(self.acc_bits % 8,);
+
+    self
+  }
+  /// Returns a reference to the inner reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the inner reader if this reader is aligned.
+  /// 
+  /// Any whole bytes which were taken from the inner reader but not read are returned
+  /// with it.
+  pub fn into_inner(self,) -> Result<(R, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_read() {
+      Some(misalign) => Err(UnalignedError(self, misalign,)),
+      None => {
+        let buffered = self.acc.to_le_bytes()[..self.acc_bits as usize / 8].to_vec();
+
+        Ok((self.reader, buffered,))
+      },
+    }
+  }
+  /// Removes `bits` bits from the accumulator.
+  #[inline]
+  fn consume(&mut self, bits: u8,) {
+    self.acc = self.acc.checked_shr(bits as u32,).unwrap_or(0,);
+    self.acc_bits -= bits;
+  }
+  /// Takes whole bytes from the inner reader until the accumulator holds at least
+  /// `bits` bits.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits needed, at most `57`.  
+  fn fill(&mut self, bits: u8,) -> Result<(), R::Error> {
+    if self.acc_bits >= bits { return Ok(()) }
+
+    let mut bytes = [0; 8];
+    let need = (bits - self.acc_bits + 7) as usize / 8;
+    let (read, res,) = match self.reader.read_bytes(&mut bytes[..need],) {
+      Ok(()) => (need, Ok(()),),
+      Err((read, e,)) => (read, Err(e),),
+    };
+
+    for &byte in &bytes[..read] {
+      self.acc |= (byte as u64) << self.acc_bits;
+      self.acc_bits += 8;
+    }
+
+    res
+  }
+}
+
+impl<R,> BitRead for ReadLsb<R,>
+  where R: BitRead, {
+  type Error = R::Error;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.to_read().is_none() }
+  #[inline]
+  fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, take the last byte of a
+    //longer read separately.
+    if bits > 56 {
+      self.fill(56,)?;
+      if self.acc_bits < bits {
+        let mut byte = [0];
+        self.reader.read_bytes(&mut byte,).map_err(|(_, e,),| e,)?;
+
+        let need = bits - self.acc_bits;
+        let res = self.acc | ((byte[0] as u64 & ((1 << need) - 1)) << self.acc_bits);
+
+        self.acc = byte[0].checked_shr(need as u32,).unwrap_or(0,) as u64;
+        self.acc_bits = 8 - need;
+
+        return Ok(res)
+      }
+    }
+
+    self.fill(bits,)?;
+
+    //Take the bits from the bottom of the accumulator.
+    let res = self.acc & (1u64 << bits).wrapping_sub(1,);
+
+    self.consume(bits,);
+
+    Ok(res)
+  }
+  fn read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, Self::Error,)> {
+    if !self.is_aligned() {
+      for (read, byte,) in buf.iter_mut().enumerate() {
+        *byte = self.read_byte().map_err(move |e,| (read, e,),)?;
+      }
+
+      return Ok(())
+    }
+
+    let mut read = 0;
+    //Empty the accumulator first.
+    while self.acc_bits > 0 && read < buf.len() {
+      buf[read] = self.acc as u8;
+      self.consume(8,);
+      read += 1;
+    }
+
+    self.reader.read_bytes(&mut buf[read..],).map_err(|(len, e,),| (read + len, e,),)
+  }
+}
+
+impl<R,> BitPeek for ReadLsb<R,>
+  where R: BitRead, {
+  const ORDER: BitOrder = BitOrder::Lsb;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    self.fill(bits,).ok();
+
+    (self.acc & (1u64 << bits).wrapping_sub(1,), self.acc_bits.min(bits,),)
+  }
+}
@@ -0,0 +1,180 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use alloc::{vec::Vec, collections::VecDeque,};
+
+/// Wraps a byte aligned reader over a H.264 or HEVC NAL unit and reads its RBSP bitwise,
+/// high bits first.
+/// 
+/// The `0x03` emulation prevention byte of every `0x000003` is dropped. The reader
+/// should end with the NAL unit for `more_rbsp_data` to find the trailing bits.
+#[derive(Clone, Debug,)]
+pub struct RbspReader<R,>
+  where R: BitRead, {
+  /// The byte aligned reader to take the escaped bytes from.
+  reader: R,
+  /// The number of zero bytes taken in a row.
+  zeros: u8,
+  /// The bits waiting to be read, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+  /// The bytes after `acc` taken to look for the trailing bits.
+  ahead: VecDeque<u8>,
+}
+
+impl<R,> RbspReader<R,>
+  where R: BitRead, {
+  /// Constructs a new `RbspReader` over the reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from, it should be aligned.  
+  pub const fn new(reader: R,) -> Self {
+    Self { reader, zeros: 0, acc: 0, acc_bits: 0, ahead: VecDeque::new(), }
+  }
+  /// Returns the number of bits left to read before this reader is aligned.
+  #[inline]
+  pub fn to_read(&self,) -> Option<Bits> { Bits::try_from(self.acc_bits % 8,).ok() }
+  /// Returns a reference to the inner reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the inner reader if this reader is aligned.
+  /// 
+  /// Any whole bytes which were taken from the inner reader but not read are returned
+  /// with it, with the emulation prevention bytes removed.
+  pub fn into_inner(self,) -> Result<(R, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_read() {
+      Some(misalign) => Err(UnalignedError(self, misalign,)),
+      None => {
+        let mut buffered = self.acc.to_be_bytes()[..self.acc_bits as usize / 8].to_vec();
+        buffered.extend(self.ahead,);
+
+        Ok((self.reader, buffered,))
+      },
+    }
+  }
+  /// Returns `true` if there is more data before the RBSP trailing bits.
+  /// 
+  /// The trailing bits are the last `1` bit of the NAL unit and the `0` bits after it, so
+  /// if the next bit is a `1` this reads ahead to the end of the NAL unit while the rest
+  /// of it is zeros. Errors from the inner reader are taken to be its end.
+  pub fn more_rbsp_data(&mut self,) -> bool {
+    if self.fill(1,).is_err() { return false }
+    //Only the stop bit followed by zeros ends the data.
+    if self.acc >> 63 == 0 || self.acc << 1 != 0 { return true }
+    if self.ahead.iter().any(|&byte,| byte != 0,) { return true }
+
+    loop {
+      match self.next_byte() {
+        Ok(byte) => {
+          self.ahead.push_back(byte,);
+          if byte != 0 { return true }
+        },
+        Err(_) => return false,
+      }
+    }
+  }
+  /// Reads the RBSP trailing bits, a `1` bit then `0` bits until this reader is aligned.
+  /// 
+  /// Returns `CodeError::Invalid` if the bits do not match.
+  pub fn rbsp_trailing_bits(&mut self,) -> Result<(), CodeError<R::Error,>> {
+    if !self.read_bit()? { return Err(CodeError::Invalid) }
+
+    let bits = self.acc_bits % 8;
+    if bits > 0 && self.read_word(bits,)? != 0 { return Err(CodeError::Invalid) }
+
+    Ok(())
+  }
+  /// Takes the next byte from the inner reader, dropping emulation prevention bytes.
+  fn next_byte(&mut self,) -> Result<u8, R::Error> {
+    loop {
+      let byte = self.reader.read_byte()?;
+      if self.zeros >= 2 && byte == 0x03 { self.zeros = 0; continue }
+
+      self.zeros = if byte == 0 { self.zeros.saturating_add(1,) } else { 0 };
+
+      return Ok(byte)
+    }
+  }
+  /// Removes `bits` bits from the accumulator.
+  #[inline]
+  fn consume(&mut self, bits: u8,) {
+    self.acc = self.acc.checked_shl(bits as u32,).unwrap_or(0,);
+    self.acc_bits -= bits;
+  }
+  /// Takes whole bytes until the accumulator holds at least `bits` bits.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits needed, at most `57`.  
+  fn fill(&mut self, bits: u8,) -> Result<(), R::Error> {
+    while self.acc_bits < bits {
+      let byte = match self.ahead.pop_front() {
+        Some(byte) => byte,
+        None => self.next_byte()?,
+      };
+
+      self.acc |= (byte as u64) << (56 - self.acc_bits);
+      self.acc_bits += 8;
+    }
+
+    Ok(())
+  }
+}
+
+impl<R,> BitRead for RbspReader<R,>
+  where R: BitRead, {
+  type Error = R::Error;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.to_read().is_none() }
+  #[inline]
+  fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> {
+    self.read_word(bits as u8,).map(|word,| word as u8,)
+  }
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> {
+    assert!(bits <= 64, "Cannot read more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, take the last byte of a
+    //longer read separately.
+    if bits > 56 {
+      self.fill(56,)?;
+      if self.acc_bits < bits {
+        let byte = match self.ahead.pop_front() {
+          Some(byte) => byte,
+          None => self.next_byte()?,
+        };
+        let (res, acc, acc_bits,) = take_past(self.acc, self.acc_bits, byte, bits,);
+
+        self.acc = acc;
+        self.acc_bits = acc_bits;
+
+        return Ok(res)
+      }
+    }
+
+    self.fill(bits,)?;
+
+    //Take the bits from the top of the accumulator.
+    let res = self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,);
+
+    self.consume(bits,);
+
+    Ok(res)
+  }
+}
+
+impl<R,> BitPeek for RbspReader<R,>
+  where R: BitRead, {
+  const ORDER: BitOrder = BitOrder::Msb;
+
+  fn peek_word(&mut self, bits: u8,) -> (u64, u8,) {
+    assert!(bits <= 56, "Cannot peek more than 56 bits at once",);
+
+    self.fill(bits,).ok();
+
+    (self.acc.checked_shr(64 - bits as u32,).unwrap_or(0,), self.acc_bits.min(bits,),)
+  }
+}
@@ -1,9 +1,10 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-31
+//! Last Moddified --- 2026-10-18
 
 #![cfg(test,)]
 
 use super::*;
+use crate::{CodeError, BitWrite, BitStuffWriter, WriteVec,};
 use alloc::vec::Vec;
 use core::iter::FromIterator;
 
@@ -37,7 +38,7 @@ fn test_ReadIter() {
 
   assert_eq!(reader.to_read(), None,);
   assert_eq!(reader.read_bit(), Ok(true),);
-  assert_eq!(reader.read_bits(Bits::B3,), Ok(0b1101),);
+  assert_eq!(reader.read_bits(Bits::B3,), Ok(0b101),);
   assert_eq!(reader.read_bit(), Ok(false),);
   assert_eq!(reader.read_byte(), Ok(byte),);
   assert_eq!(reader.skip(Bits::B3,).expect("Error skipping bits 1",).to_read(), None,);
@@ -53,6 +54,203 @@ fn test_ReadIter() {
   assert_eq!(reader.to_read(), None,);
   assert_eq!(reader.into_iter().ok().map(Vec::from_iter,), Some(Vec::new()),);
   assert_eq!(ReadIter::new(bytes.iter(),).into_iter().ok().map(Vec::from_iter,), Some(bytes.iter().collect()),);
+
+  //Bytes taken to peek ahead are returned with the iterator.
+  let bytes = [0xA5u8, 0x11, 0x22, 0x33,];
+  let mut reader = ReadIter::new(bytes.iter().copied(),);
+  assert_eq!(reader.read_word(8,), Ok(0xA5),);
+  assert_eq!(reader.peek_word(16,), (0x1122, 16,),);
+  let (iter, buffered,) = reader.into_parts().expect("Error unwrapping",);
+  assert_eq!(buffered, [0x11, 0x22,],);
+  assert_eq!(Vec::from_iter(iter,), [0x33,],);
+
+  //A failed long read removes no bits.
+  let long = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x0F,];
+  let mut reader = ReadIter::new(long[..7].iter(),);
+  assert_eq!(reader.read_word(64,), Err(Some(Bits::B8)),);
+  assert_eq!(reader.read_word(56,), Ok(0x12_3456_789A_BCDE),);
+  let mut reader = ReadIter::new(long.iter(),);
+  assert_eq!(reader.read_word(4,), Ok(0x1),);
+  assert_eq!(reader.read_word(64,), Ok(0x2345_6789_ABCD_EF00),);
+  assert_eq!(reader.read_word(4,), Ok(0xF),);
+  assert_eq!(reader.read_word(60,), Err(None),);
+}
+
+#[test]
+fn test_read_unary() {
+  let bytes = [0b11101001, 0, 0, 0, 0, 0, 0, 0, 0, 0b00000001, 0b10100000,];
+  let mut reader = ReadIter::new(bytes.iter(),);
+
+  assert_eq!(reader.read_unary(false,), Ok(3),);
+  assert_eq!(reader.read_unary(true,), Ok(0),);
+  assert_eq!(reader.read_truncated_unary(2, true,), Ok(2),);
+  assert_eq!(reader.read_truncated_unary(2, false,), Ok(1),);
+  assert_eq!(reader.read_unary(true,), Ok(70),);
+  assert_eq!(reader.read_bits(Bits::B3,), Ok(0b101),);
+  assert_eq!(reader.read_unary(true,), Err(None),);
+
+  let mut reader = ReadByte::new(0b00011011,);
+  assert_eq!(reader.read_unary(true,), Ok(3),);
+  assert_eq!(reader.read_truncated_unary(3, false,), Ok(1),);
+  assert_eq!(reader.read_truncated_unary(3, true,), Ok(0),);
+  assert_eq!(reader.read_truncated_unary(1, false,), Ok(1),);
+  assert_eq!(reader.read_unary(false,), Err(None),);
+}
+
+#[test]
+fn test_read_exp_golomb() {
+  //ue: 0, 1, 2, 3, 7; se: 1, -1, 2; k = 2: 5
+  let bytes = [0b10100110, 0b01000001, 0b00001001, 0b10010001, 0b00100000,];
+  let mut reader = ReadIter::new(bytes.iter(),);
+
+  assert_eq!(reader.read_ue(), Ok(0),);
+  assert_eq!(reader.read_ue(), Ok(1),);
+  assert_eq!(reader.read_ue(), Ok(2),);
+  assert_eq!(reader.read_ue(), Ok(3),);
+  assert_eq!(reader.read_ue(), Ok(7),);
+  assert_eq!(reader.read_se(), Ok(1),);
+  assert_eq!(reader.read_se(), Ok(-1),);
+  assert_eq!(reader.read_se(), Ok(2),);
+  assert_eq!(reader.read_exp_golomb(2,), Ok(5),);
+  assert_eq!(reader.read_ue(), Err(CodeError::Read(None)),);
+
+  //33 leading zeros.
+  let bytes = [0, 0, 0, 0, 0b01000000,];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_ue(), Err(CodeError::Overflow),);
+  //32 leading zeros with a value of `u32::MAX + 1`.
+  let bytes = [0, 0, 0, 0, 0b10000000, 0, 0, 0, 0b10000000,];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_ue(), Err(CodeError::Overflow),);
+  let bytes = [0, 0, 0, 0, 0b10000000, 0, 0, 0, 0,];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_ue(), Ok(u32::MAX),);
+  assert_eq!(ReadIter::new(bytes.iter(),).read_se(), Err(CodeError::Overflow),);
+}
+
+#[test]
+fn test_read_elias() {
+  //gamma: 1, 2, 4; delta: 1, 3, 4; omega: 1, 3, 16
+  let bytes = [0b10100010, 0b01010101, 0b10001101, 0b01001000, 0,];
+  let mut reader = ReadIter::new(bytes.iter(),);
+
+  assert_eq!(reader.read_gamma(), Ok(1),);
+  assert_eq!(reader.read_gamma(), Ok(2),);
+  assert_eq!(reader.read_gamma(), Ok(4),);
+  assert_eq!(reader.read_delta(), Ok(1),);
+  assert_eq!(reader.read_delta(), Ok(3),);
+  assert_eq!(reader.read_delta(), Ok(4),);
+  assert_eq!(reader.read_omega(), Ok(1),);
+  assert_eq!(reader.read_omega(), Ok(3),);
+  assert_eq!(reader.read_omega(), Ok(16),);
+  assert_eq!(reader.read_gamma(), Err(CodeError::Read(None)),);
+
+  //A gamma prefix which runs off the end of the input.
+  let bytes = [0, 0,];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_gamma(), Err(CodeError::Read(None)),);
+  //A gamma code for a value larger than `u64::MAX`.
+  let bytes = [0u8; 9];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_gamma(), Err(CodeError::Overflow),);
+  //A delta code with a length of 65.
+  let bytes = [0b00000010, 0b00001000,];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_delta(), Err(CodeError::Overflow),);
+  //An omega code with a group of 65 bits.
+  let bytes = [0xFF; 3];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_omega(), Err(CodeError::Overflow),);
+}
+
+#[test]
+fn test_read_golomb() {
+  //rice k = 2: 5, 0; signed rice k = 1: -2; golomb m = 5: 9, 2; golomb m = 1: 2
+  let bytes = [0b01011000, 0b11011111, 0b10001000,];
+  let mut reader = ReadIter::new(bytes.iter(),);
+
+  assert_eq!(reader.read_rice(2,), Ok(5),);
+  assert_eq!(reader.read_rice(2,), Ok(0),);
+  assert_eq!(reader.read_signed_rice(1,), Ok(-2),);
+  assert_eq!(reader.read_golomb(5,), Ok(9),);
+  assert_eq!(reader.read_golomb(5,), Ok(2),);
+  assert_eq!(reader.read_golomb(1,), Ok(2),);
+  assert_eq!(reader.read_rice(0,), Err(CodeError::Read(None)),);
+
+  //A quotient of 2 with `k = 63`.
+  let bytes = [0b00100000,];
+  assert_eq!(ReadIter::new(bytes.iter(),).read_rice(63,), Err(CodeError::Overflow),);
+  //A quotient of 2 with `m = u64::MAX`.
+  assert_eq!(ReadIter::new(bytes.iter().chain(&[0; 8],),).read_golomb(u64::MAX,), Err(CodeError::Overflow),);
+}
+
+#[test]
+fn test_read_leb128() {
+  let bytes = [0xE5, 0x8E, 0x26, 0xC0, 0xBB, 0x78, 0xC0, 0x00, 0xBF, 0x7F, 0x40, 0x03,];
+  let mut reader = ReadIter::new(bytes.iter(),);
+
+  assert_eq!(reader.read_uleb128(), Ok(624485),);
+  assert_eq!(reader.read_sleb128(), Ok(-123456),);
+  assert_eq!(reader.read_sleb128(), Ok(64),);
+  assert_eq!(reader.read_sleb128(), Ok(-65),);
+  assert_eq!(reader.read_sleb128(), Ok(-64),);
+  assert_eq!(reader.read_zigzag_leb128(), Ok(-2),);
+  assert_eq!(reader.read_uleb128(), Err(CodeError::Read(None)),);
+
+  //Unaligned readers read the same codes bitwise.
+  let mut writer = WriteVec::new();
+  writer.write_bit(true,).expect("Error writing",)
+    .write_bytes(&bytes,).expect("Error writing",);
+  let shifted = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = ReadIter::new(shifted.iter(),);
+  assert_eq!(reader.read_bit(), Ok(true),);
+  assert_eq!(reader.read_uleb128(), Ok(624485),);
+  assert_eq!(reader.read_sleb128(), Ok(-123456),);
+  assert_eq!(reader.read_sleb128(), Ok(64),);
+  assert_eq!(reader.read_sleb128(), Ok(-65),);
+  assert_eq!(reader.read_sleb128(), Ok(-64),);
+  assert_eq!(reader.read_zigzag_leb128(), Ok(-2),);
+
+  let mut bytes = [0xFF; 11];
+  bytes[9] = 0x01;
+  assert_eq!(ReadIter::new(bytes.iter(),).read_uleb128(), Ok(u64::MAX),);
+  bytes[9] = 0x02;
+  assert_eq!(ReadIter::new(bytes.iter(),).read_uleb128(), Err(CodeError::Overflow),);
+  bytes[9] = 0x81;
+  assert_eq!(ReadIter::new(bytes.iter(),).read_uleb128(), Err(CodeError::Overflow),);
+  assert_eq!(ReadIter::new([0x80, 0x00,].iter(),).read_uleb128(), Err(CodeError::Overlong),);
+  assert_eq!(ReadIter::new([0x00,].iter(),).read_uleb128(), Ok(0),);
+
+  let mut bytes = [0x80; 10];
+  bytes[9] = 0x7F;
+  assert_eq!(ReadIter::new(bytes.iter(),).read_sleb128(), Ok(i64::MIN),);
+  bytes[9] = 0x3F;
+  assert_eq!(ReadIter::new(bytes.iter(),).read_sleb128(), Err(CodeError::Overflow),);
+  assert_eq!(ReadIter::new([0xFF, 0x7F,].iter(),).read_sleb128(), Err(CodeError::Overlong),);
+  assert_eq!(ReadIter::new([0x80, 0x00,].iter(),).read_sleb128(), Err(CodeError::Overlong),);
+  assert_eq!(ReadIter::new([0x7F,].iter(),).read_sleb128(), Ok(-1),);
+}
+
+#[test]
+fn test_read_fibonacci() {
+  //1, 4, 11
+  let bytes = [0b11101100, 0b10110000,];
+  let mut reader = ReadIter::new(bytes.iter(),);
+
+  assert_eq!(reader.read_fibonacci(), Ok(1),);
+  assert_eq!(reader.read_fibonacci(), Ok(4),);
+  assert_eq!(reader.read_fibonacci(), Ok(11),);
+  assert_eq!(reader.read_fibonacci(), Err(CodeError::Read(None)),);
+
+  //A code with no terminator for 92 Fibonacci numbers.
+  let mut bytes = [0u8; 14];
+  bytes[12] = 0b00001011;
+  let mut reader = ReadIter::new(bytes.iter(),);
+  assert_eq!(reader.read_fibonacci(), Err(CodeError::Overflow),);
+  assert_eq!(reader.resync_fibonacci(), Ok(11),);
+  assert_eq!(reader.read_bits(Bits::B3,), Ok(0),);
+
+  //A code for a sum larger than `u64::MAX`.
+  let mut bytes = [0u8; 12];
+  bytes[10] = 0b00000001;
+  bytes[11] = 0b01011000;
+  let mut reader = ReadIter::new(bytes.iter().chain(&[0b11000000,],),);
+  assert_eq!(reader.read_fibonacci(), Err(CodeError::Overflow),);
+  assert_eq!(reader.resync_fibonacci(), Ok(6),);
+  assert_eq!(reader.to_read(), Some(Bits::B6),);
 }
 
 #[allow(non_snake_case,)]
@@ -66,17 +264,315 @@ fn test_ReadIO() {
 
   assert_eq!(reader.to_read(), None,);
   assert_eq!(reader.read_bit().ok(), Some(true),);
-  assert_eq!(reader.read_bits(Bits::B3,).ok(), Some(0b1101),);
+  assert_eq!(reader.read_bits(Bits::B3,).ok(), Some(0b101),);
   assert_eq!(reader.read_bit().ok(), Some(false),);
   assert_eq!(reader.read_byte().ok(), Some(byte),);
   assert_eq!(reader.skip(Bits::B3,).expect("Error skipping bits 1",).to_read(), None,);
-  assert_eq!(reader.read_bits(Bits::B3,).ok(), Some(0b11110010),);
+  assert_eq!(reader.read_bits(Bits::B3,).ok(), Some(0b010),);
   assert_eq!(reader.skip(Bits::B3,).expect("Error skipping bits 2").read_bits(Bits::B8,).map_err(|e,| e.0,), Err(Some(Bits::B2)),);
-  assert_eq!(reader.into_reader().err().map(|b,| b.1,), Some(Bits::B2),);
+  match reader.into_reader() {
+    //The conversion failed successfully, return the reader.
+    Err(e) => { assert_eq!(e.misalign(), Bits::B2,); reader = e.into_inner() },
+    Ok(_) => panic!("Reader unwrapped unexpectedly",),
+  }
   reader.skip(Bits::B2,).expect("Error skipping bits 3",);
   assert_eq!(reader.read_bits(Bits::B8,).map_err(|e,| e.0,), Err(None),);
   assert_eq!(reader.to_read(), None,);
-  assert_eq!(reader.into_reader().ok(), Some([].as_ref()),);
-  assert_eq!(ReadIO::new(bytes.as_ref(),).into_reader().ok(), Some(bytes.as_ref()),);
+  assert_eq!(reader.into_reader().ok(), Some(([].as_ref(), Vec::new(),)),);
+  assert_eq!(ReadIO::new(bytes.as_ref(),).into_reader().ok(), Some((bytes.as_ref(), Vec::new(),)),);
+
+  let mut reader = ReadIO::new(bytes.as_ref(),);
+  assert_eq!(reader.read_bits(Bits::B4,).ok(), Some(0b1101),);
+  assert_eq!(reader.buffered(), &bytes[1..],);
+  assert_eq!(reader.read_bits(Bits::B4,).ok(), Some(0b0110),);
+  assert_eq!(reader.into_reader().ok(), Some(([].as_ref(), bytes[1..].to_vec(),)),);
+
+  //A failed long read removes no bits.
+  let long = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x0F,];
+  let mut reader = ReadIO::with_capacity(3, &long[..7],);
+  assert_eq!(reader.read_word(64,).map_err(|e,| e.0,), Err(Some(Bits::B8)),);
+  assert_eq!(reader.read_word(56,).ok(), Some(0x12_3456_789A_BCDE),);
+  let mut reader = ReadIO::with_capacity(3, long.as_ref(),);
+  assert_eq!(reader.read_word(4,).ok(), Some(0x1),);
+  assert_eq!(reader.read_word(64,).ok(), Some(0x2345_6789_ABCD_EF00),);
+  assert_eq!(reader.read_word(4,).ok(), Some(0xF),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_ReadIO_unary() {
+  #![cfg(feature = "std",)]
+
+  let mut bytes = alloc::vec![0xFFu8; 40];
+  bytes.extend_from_slice(&[0b01000000, 0, 0, 0b00100000,],);
+  let mut reader = ReadIO::with_capacity(3, bytes.as_slice(),);
+
+  assert_eq!(reader.read_unary(false,).ok(), Some(320),);
+  assert_eq!(reader.read_unary(false,).ok(), Some(1),);
+  assert_eq!(reader.read_truncated_unary(10, true,).ok(), Some(10),);
+  assert_eq!(reader.read_unary(true,).ok(), Some(13),);
+  assert_eq!(reader.read_unary(true,).map_err(|e,| e.1.kind(),), Err(std::io::ErrorKind::UnexpectedEof),);
 }
 
+#[allow(non_snake_case,)]
+#[test]
+fn test_ReadIO_refill() {
+  #![cfg(feature = "std",)]
+
+  /// A reader which returns at most one byte from each call.
+  struct Trickle<'a,>(&'a [u8],);
+
+  impl std::io::Read for Trickle<'_,> {
+    fn read(&mut self, buf: &mut [u8],) -> std::io::Result<usize> {
+      let len = usize::min(1, usize::min(buf.len(), self.0.len(),),);
+
+      buf[..len].copy_from_slice(&self.0[..len],);
+      self.0 = &self.0[len..];
+
+      Ok(len)
+    }
+  }
+
+  let bytes = (0..=255u8).collect::<Vec<_>>();
+  let mut reader = ReadIO::with_capacity(3, bytes.as_slice(),);
+
+  assert_eq!(reader.capacity(), 3,);
+  assert_eq!(reader.read_bits(Bits::B4,).ok(), Some(0),);
+  for &byte in &bytes[..255] {
+    let next = bytes[byte as usize + 1];
+
+    assert_eq!(reader.read_byte().ok(), Some((byte << 4) | (next >> 4)),);
+  }
+  assert_eq!(reader.read_byte().map_err(|e,| e.0,), Err(Some(Bits::B4)),);
+  assert_eq!(reader.clear_buf().read_bit().map_err(|e,| e.0,), Err(None),);
+
+  let mut reader = ReadIO::with_capacity(0, Trickle(&bytes[..4],),);
+  assert_eq!(reader.capacity(), 1,);
+  assert_eq!(reader.read_byte().ok(), Some(0),);
+  assert_eq!(reader.read_bits(Bits::B3,).ok(), Some(0),);
+  assert_eq!(reader.read_bits(Bits::B7,).ok(), Some(0b0000100),);
+  match reader.into_reader() {
+    Err(e) => assert_eq!(e.misalign(), Bits::B6,),
+    Ok(_) => panic!("Reader unwrapped unexpectedly",),
+  }
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_ReadLsb() {
+  let bytes = [0b1011_0001u8, 0x34, 0x12, 0xFF,];
+  let mut reader = ReadLsb::new(ReadIter::new(bytes.iter().copied(),),);
+
+  assert_eq!(reader.read_bit(), Ok(true),);
+  assert_eq!(reader.read_word(3,), Ok(0b000,),);
+  assert_eq!(reader.peek_word(4,), (0b1011, 4,),);
+  assert_eq!(reader.read_word(4,), Ok(0b1011,),);
+  assert_eq!(reader.read_word(16,), Ok(0x1234,),);
+  assert_eq!(reader.peek_word(16,), (0xFF, 8,),);
+
+  let (_, buffered,) = reader.into_inner().expect("Error unwrapping",);
+  assert_eq!(buffered, [0xFF,],);
+
+  //A failed long read removes no bits.
+  let long = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x0F,];
+  let mut reader = ReadLsb::new(ReadIter::new(long[..7].iter().copied(),),);
+  assert_eq!(reader.read_word(64,), Err(None),);
+  assert_eq!(reader.read_word(56,), Ok(0xDE_BC9A_7856_3412),);
+  let mut reader = ReadLsb::new(ReadIter::new(long.iter().copied(),),);
+  assert_eq!(reader.read_word(4,), Ok(0x2),);
+  assert_eq!(reader.read_word(64,), Ok(0xFF0D_EBC9_A785_6341),);
+  assert_eq!(reader.read_word(4,), Ok(0x0),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_ReadBackward() {
+  //Written as `0b101`, `0xABC`, `0`, `0x00FF_EEDD_CCBB_AA99` then the sentinel.
+  let bytes = [0xE5, 0x55, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, 0x01,];
+
+  let mut reader = ReadBackward::new(&bytes,).expect("Error reading",);
+  assert_eq!(reader.bits_left(), 72,);
+  assert!(reader.is_aligned(),);
+  assert_eq!(reader.peek_word(8,), (0xFF, 8,),);
+  assert_eq!(reader.read_word(56,), Ok(0x00FF_EEDD_CCBB_AA99),);
+  assert_eq!(reader.read_bit(), Ok(false),);
+  assert_eq!(reader.read_word(4,), Ok(0xA),);
+  assert_eq!(reader.read_byte(), Ok(0xBC),);
+  assert!(!reader.is_aligned(),);
+  assert_eq!(reader.peek_word(5,), (0b10100, 3,),);
+  //Underflow reads nothing.
+  assert_eq!(reader.read_word(4,), Err(Some(Bits::B3)),);
+  assert_eq!(reader.read_bits(Bits::B3,), Ok(0b101),);
+  assert!(reader.is_empty(),);
+  assert_eq!(reader.read_bit(), Err(None),);
+
+  //Long words are read high bits first.
+  let mut reader = ReadBackward::new(&[0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x01,],).expect("Error reading",);
+  assert_eq!(reader.read_word(64,), Ok(0x8877_6655_4433_2211),);
+  assert_eq!(reader.read_word(0,), Ok(0),);
+  assert_eq!(reader.read_word(9,), Err(None),);
+
+  assert_eq!(ReadBackward::new(&[0x80,],).map(|reader,| reader.bits_left(),), Ok(7),);
+  assert_eq!(ReadBackward::new(&[],), Err(SentinelError),);
+  assert_eq!(ReadBackward::new(&[0x12, 0x00,],), Err(SentinelError),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_BitUnstuffReader() {
+  //`0xFF` with a stuffed `0` then a flag.
+  let bytes = [0xFB, 0xBF, 0x00,];
+  let mut reader = BitUnstuffReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.peek_word(8,), (0xFF, 8,),);
+  assert_eq!(reader.skip_marker(), None,);
+  assert_eq!(reader.read_word(4,), Ok(0xF),);
+  assert!(!reader.is_aligned(),);
+  //Reads past the flag remove nothing.
+  assert_eq!(reader.read_byte(), Err(UnstuffError::Flag),);
+  assert_eq!(reader.read_word(4,), Ok(0xF),);
+  assert_eq!(reader.read_bit(), Err(UnstuffError::Flag),);
+  assert_eq!(reader.skip_marker(), Some(0),);
+  assert!(reader.is_aligned(),);
+
+  //Low bits first: three bits of noise, a flag, `[0x7E, 0xFF, 0x00, 0x3E]`, two flags,
+  //`[0x01, 0x02]`, an abort of ten `1`s, a flag, `[0xAA]`, a flag, four bits and a flag.
+  let bytes = [0xF5, 0xF3, 0xF5, 0x1D, 0xC0, 0x87, 0x9F, 0x5F, 0x80, 0xC0, 0xFF, 0x7E, 0xAA, 0x7E, 0xED, 0x07,];
+  let mut reader = BitUnstuffReader::new(ReadLsb::new(ReadIter::new(bytes.iter().copied(),),),);
+  let mut frame = Vec::new();
+  assert_eq!(reader.read_frame(&mut frame,), Ok(()),);
+  assert_eq!(frame, [0x7E, 0xFF, 0x00, 0x3E,],);
+  frame.clear();
+  assert_eq!(reader.read_frame(&mut frame,), Err(UnstuffError::Abort),);
+  assert_eq!(frame, [0x01, 0x02,],);
+  frame.clear();
+  assert_eq!(reader.read_frame(&mut frame,), Ok(()),);
+  assert_eq!(frame, [0xAA,],);
+  frame.clear();
+  assert_eq!(reader.read_frame(&mut frame,), Err(UnstuffError::Unaligned),);
+  assert!(frame.is_empty(),);
+  assert!(matches!(reader.read_frame(&mut frame,), Err(UnstuffError::Read(_,)),),);
+
+  //A `1` stuffed after two `0`s then an abort of four `0`s.
+  let bytes = [0x24, 0x00,];
+  let mut reader = BitUnstuffReader::with_params(ReadIter::new(bytes.iter().copied(),), 2, true,);
+  assert_eq!(reader.read_bits(Bits::B4,), Ok(0),);
+  assert_eq!(reader.read_bit(), Err(UnstuffError::Abort),);
+  assert_eq!(reader.skip_marker(), Some(0),);
+
+  //Long words round trip.
+  let mut writer = BitStuffWriter::new(WriteVec::new(),);
+  writer.write_word(64, 0xFFFF_FFFF_0123_FFFF,).expect("Error writing",)
+    .write_word(7, 0x7F,).expect("Error writing",)
+    .write_flag().expect("Error writing",);
+  let mut writer = writer.into_inner();
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = BitUnstuffReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.read_word(64,), Ok(0xFFFF_FFFF_0123_FFFF),);
+  assert_eq!(reader.read_word(7,), Ok(0x7F),);
+  assert_eq!(reader.read_bit(), Err(UnstuffError::Flag),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_RbspReader() {
+  let bytes = [0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x01, 0x00, 0x00, 0x03, 0x03, 0xFF, 0x00, 0x00, 0x04, 0x24,];
+  let mut reader = RbspReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.peek_word(24,), (0x00_0000, 24,),);
+  assert_eq!(reader.read_word(40,), Ok(0x00_0000_0001),);
+  assert_eq!(reader.read_word(24,), Ok(0x00_0003),);
+  assert_eq!(reader.read_byte(), Ok(0xFF),);
+  assert_eq!(reader.read_word(24,), Ok(0x00_0004),);
+  assert!(reader.more_rbsp_data(),);
+  assert_eq!(reader.read_ue(), Ok(3),);
+  assert!(!reader.is_aligned(),);
+  assert!(!reader.more_rbsp_data(),);
+  assert_eq!(reader.rbsp_trailing_bits(), Ok(()),);
+  assert!(reader.is_aligned(),);
+  assert_eq!(reader.read_bit(), Err(None),);
+
+  //The trailing bits may be followed by `cabac_zero_word`s.
+  let bytes = [0xA5, 0x80, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03,];
+  let mut reader = RbspReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert!(reader.more_rbsp_data(),);
+  assert_eq!(reader.read_word(7,), Ok(0x52),);
+  assert!(reader.more_rbsp_data(),);
+  assert_eq!(reader.read_bit(), Ok(true),);
+  assert!(!reader.more_rbsp_data(),);
+  assert_eq!(reader.rbsp_trailing_bits(), Ok(()),);
+  let (_, buffered,) = reader.into_inner().expect("Error unwrapping",);
+  assert_eq!(buffered, [0x00, 0x00, 0x00, 0x00,],);
+
+  //Data which ends in zero bits.
+  let mut reader = RbspReader::new(ReadIter::new([0x20,],),);
+  assert!(reader.more_rbsp_data(),);
+  assert_eq!(reader.read_word(2,), Ok(0b00),);
+  assert!(!reader.more_rbsp_data(),);
+  assert_eq!(reader.rbsp_trailing_bits(), Ok(()),);
+  let mut reader = RbspReader::new(ReadIter::new([0x80, 0x00, 0x01, 0x80,],),);
+  assert!(reader.more_rbsp_data(),);
+  assert_eq!(reader.read_word(24,), Ok(0x80_0001),);
+  assert!(!reader.more_rbsp_data(),);
+
+  //A failed long read removes no bits.
+  let bytes = [0x12, 0x00, 0x00, 0x03, 0x00, 0xBC, 0xDE, 0xF0, 0x0F,];
+  let mut reader = RbspReader::new(ReadIter::new(bytes[..7].iter().copied(),),);
+  assert_eq!(reader.read_word(64,), Err(None),);
+  assert_eq!(reader.read_word(48,), Ok(0x1200_0000_BCDE),);
+  let mut reader = RbspReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.read_word(4,), Ok(0x1),);
+  assert_eq!(reader.read_word(60,), Ok(0x200_0000_BCDE_F00F),);
+
+  let mut reader = RbspReader::new(ReadIter::new([0x40,],),);
+  assert_eq!(reader.rbsp_trailing_bits(), Err(CodeError::Invalid),);
+  let mut reader = RbspReader::new(ReadIter::new([0x81,],),);
+  assert_eq!(reader.rbsp_trailing_bits(), Err(CodeError::Invalid),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_JpegScanReader() {
+  //`0xFF`, `0xA` and padding, `RST0`, `0x12`, `0b001` and padding, fill bytes and `EOI`.
+  let bytes = [0xFF, 0x00, 0xAF, 0xFF, 0xD0, 0x12, 0x3F, 0xFF, 0xFF, 0xD9,];
+  let mut reader = JpegScanReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.read_byte(), Ok(0xFF),);
+  assert_eq!(reader.read_word(4,), Ok(0xA),);
+  assert!(!reader.is_aligned(),);
+  assert_eq!(reader.marker(), None,);
+  assert_eq!(reader.peek_word(12,), (0xFFF, 12,),);
+  assert_eq!(reader.marker(), Some(0xD0),);
+  assert_eq!(reader.read_word(4,), Ok(0xF),);
+  assert!(!reader.is_filled(),);
+  assert_eq!(reader.read_word(8,), Ok(0xFF),);
+  assert!(reader.is_filled(),);
+
+  assert_eq!(reader.restart(), Ok(0xD0),);
+  assert!(!reader.is_filled(),);
+  assert!(reader.is_aligned(),);
+  assert_eq!(reader.read_word(11,), Ok(0x091),);
+  assert_eq!(reader.read_word(5,), Ok(0x1F),);
+  assert!(!reader.is_filled(),);
+  assert_eq!(reader.read_word(64,), Ok(!0),);
+  assert!(reader.is_filled(),);
+  assert_eq!(reader.marker(), Some(0xD9),);
+  assert_eq!(reader.restart(), Ok(0xD9),);
+  assert_eq!(reader.read_bit(), Err(None),);
+
+  //Restarting skips the rest of the interval.
+  let bytes = [0x12, 0xFF, 0x00, 0x34, 0xFF, 0xD7, 0x56,];
+  let mut reader = JpegScanReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.read_word(4,), Ok(0x1),);
+  assert_eq!(reader.restart(), Ok(0xD7),);
+  assert_eq!(reader.read_byte(), Ok(0x56),);
+  assert_eq!(reader.into_inner().into_iter().map(|iter,| iter.count(),).ok(), Some(0),);
+
+  //A failed long read removes no bits.
+  let bytes = [0x12, 0xFF, 0x00, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x0F,];
+  let mut reader = JpegScanReader::new(ReadIter::new(bytes[..8].iter().copied(),),);
+  assert_eq!(reader.read_word(64,), Err(None),);
+  assert_eq!(reader.read_word(56,), Ok(0x12_FF56_789A_BCDE),);
+  let mut reader = JpegScanReader::new(ReadIter::new(bytes.iter().copied(),),);
+  assert_eq!(reader.read_word(4,), Ok(0x1),);
+  assert_eq!(reader.read_word(64,), Ok(0x2FF5_6789_ABCD_EF00),);
+  assert_eq!(reader.read_word(4,), Ok(0xF),);
+  assert!(!reader.is_filled(),);
+}
@@ -1,17 +1,23 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-31
+//! Last Moddified --- 2026-10-18
 
-use crate::{bits::Bits, UnalignedError,};
+use crate::{bits::Bits, BitOrder, UnalignedError, zigzag_encode, golomb::truncated_binary, fibonacci::fibonacci_code,};
 use core::{
   fmt,
-  convert::TryFrom,
+  convert::{TryFrom, Infallible,},
   borrow::BorrowMut,
 };
 
 mod tests;
+mod write_lsb;
+mod write_backward;
+mod bit_stuff;
+mod write_rbsp;
+mod write_jpeg;
 #[cfg(feature = "std",)]
 mod bit_writer;
 
+pub use self::{write_lsb::*, write_backward::*, bit_stuff::*, write_rbsp::*, write_jpeg::*,};
 #[cfg(feature = "std",)]
 pub use self::bit_writer::*;
 use alloc::vec::Vec;
@@ -46,6 +52,285 @@ pub trait BitWrite {
   /// bits --- The number of bits to write out.  
   /// buf --- The buffer of bits to write.  
   fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error>;
+  /// Writes up to `64` low bits from `word` to the input at once.
+  /// 
+  /// The state of the higher bits are ignored.
+  /// 
+  /// Writers which buffer whole words should override this to avoid writing a byte at a
+  /// time.  
+  /// The default implementation writes a byte at a time and may have written some of
+  /// the bits when an error is returned.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The number of bits to write out, at most `64`.  
+  /// word --- The bits to write.  
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    let mut bits = bits;
+    while bits > 0 {
+      let chunk = Bits::BITS[bits.min(8,) as usize - 1];
+
+      bits -= chunk as u8;
+      self.write_bits(chunk, (word >> bits) as u8,)?;
+    }
+
+    Ok(self)
+  }
+  /// Writes a unary code, `value` bits followed by a `terminator` bit.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write.  
+  /// terminator --- The bit which ends the code.  
+  fn write_unary(&mut self, value: u64, terminator: bool,) -> Result<&mut Self, Self::Error> {
+    self.write_truncated_unary(value, u64::MAX, terminator,)?;
+    //A code of `u64::MAX` is not terminated by `write_truncated_unary`.
+    if value == u64::MAX { self.write_bit(terminator,)?; }
+
+    Ok(self)
+  }
+  /// Writes a truncated unary code, `value` bits followed by a `terminator` bit unless
+  /// `value` is `max`.
+  /// 
+  /// The bits are written a word at a time.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write, at most `max`.  
+  /// max --- The largest value of the code.  
+  /// terminator --- The bit which ends the code.  
+  fn write_truncated_unary(&mut self, value: u64, max: u64, terminator: bool,) -> Result<&mut Self, Self::Error> {
+    assert!(value <= max, "Cannot write a truncated unary code larger than its maximum",);
+
+    let run = if terminator { 0 } else { !0 };
+    let mut remaining = value;
+    while remaining > 0 {
+      let bits = remaining.min(56,) as u8;
+
+      self.write_word(bits, run,)?;
+      remaining -= bits as u64;
+    }
+    if value < max { self.write_bit(terminator,)?; }
+
+    Ok(self)
+  }
+  /// Writes a k-th order Exp-Golomb code.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The order of the code, at most `32`.  
+  /// value --- The value to write.  
+  fn write_exp_golomb(&mut self, k: u8, value: u32,) -> Result<&mut Self, Self::Error> {
+    assert!(k <= 32, "Exp-Golomb codes have an order of at most 32",);
+
+    let value = value as u64 + (1 << k);
+    let bits = 64 - value.leading_zeros() as u8;
+
+    self.write_word(bits - 1 - k, 0,)?.write_word(bits, value,)
+  }
+  /// Writes an unsigned Exp-Golomb code, `ue(v)`.
+  #[inline]
+  fn write_ue(&mut self, value: u32,) -> Result<&mut Self, Self::Error> { self.write_exp_golomb(0, value,) }
+  /// Writes a signed Exp-Golomb code, `se(v)`.
+  /// 
+  /// The code of `i32::MIN` is `u32::MAX + 1` so it is written as a 65 bit `ue(v)` code.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write.  
+  fn write_se(&mut self, value: i32,) -> Result<&mut Self, Self::Error> {
+    let code = if value > 0 { value as u64 * 2 - 1 } else { value.unsigned_abs() as u64 * 2 };
+    let value = code + 1;
+    let bits = 64 - value.leading_zeros() as u8;
+
+    self.write_word(bits - 1, 0,)?.write_word(bits, value,)
+  }
+  /// Writes an Elias gamma code.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write, `0` cannot be written.  
+  fn write_gamma(&mut self, value: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(value != 0, "`0` cannot be written as an Elias code",);
+
+    let zeros = 63 - value.leading_zeros() as u8;
+
+    self.write_word(zeros, 0,)?.write_word(zeros + 1, value,)
+  }
+  /// Writes an Elias delta code.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write, `0` cannot be written.  
+  fn write_delta(&mut self, value: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(value != 0, "`0` cannot be written as an Elias code",);
+
+    let bits = 64 - value.leading_zeros() as u8;
+
+    self.write_gamma(bits as u64,)?.write_word(bits - 1, value,)
+  }
+  /// Writes an Elias omega code.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write, `0` cannot be written.  
+  fn write_omega(&mut self, value: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(value != 0, "`0` cannot be written as an Elias code",);
+
+    //The groups of the code from last to first, a `u64` needs at most four groups.
+    let mut groups = [(0u8, 0u64,); 4];
+    let mut len = 0;
+    let mut value = value;
+    while value > 1 {
+      let bits = 64 - value.leading_zeros() as u8;
+
+      groups[len] = (bits, value,);
+      len += 1;
+      value = bits as u64 - 1;
+    }
+    for &(bits, group,) in groups[..len].iter().rev() { self.write_word(bits, group,)?; }
+
+    self.write_bit(false,)
+  }
+  /// Writes a Rice code, a unary quotient of zeros ended by a one followed by a `k` bit
+  /// remainder.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The Rice parameter, less than `64`.  
+  /// value --- The value to write.  
+  fn write_rice(&mut self, k: u8, value: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(k < 64, "The Rice parameter must be less than 64",);
+
+    self.write_unary(value >> k, true,)?.write_word(k, value,)
+  }
+  /// Writes a Rice code for a signed value mapped by `zigzag_encode`.
+  /// 
+  /// # Params
+  /// 
+  /// k --- The Rice parameter, less than `64`.  
+  /// value --- The value to write.  
+  #[inline]
+  fn write_signed_rice(&mut self, k: u8, value: i64,) -> Result<&mut Self, Self::Error> {
+    self.write_rice(k, zigzag_encode(value,),)
+  }
+  /// Writes a Golomb code, a unary quotient of zeros ended by a one followed by a
+  /// truncated binary remainder.
+  /// 
+  /// # Params
+  /// 
+  /// m --- The Golomb parameter, greater than `0`.  
+  /// value --- The value to write.  
+  fn write_golomb(&mut self, m: u64, value: u64,) -> Result<&mut Self, Self::Error> {
+    let (bits, cutoff,) = truncated_binary(m,);
+    let remainder = value % m;
+
+    self.write_unary(value / m, true,)?;
+    //Short remainders drop the low bit.
+    if remainder < cutoff { self.write_word(bits - 1, remainder,) }
+    else { self.write_word(bits, remainder + cutoff,) }
+  }
+  /// Writes an unsigned LEB128 code.
+  /// 
+  /// The code is written with `write_bytes` so aligned writers can copy it in bulk.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write.  
+  fn write_uleb128(&mut self, value: u64,) -> Result<&mut Self, Self::Error> {
+    let mut code = [0u8; 10];
+    let mut len = 0;
+    let mut value = value;
+    loop {
+      code[len] = value as u8 & 0x7F;
+      value >>= 7;
+      len += 1;
+
+      if value == 0 { break }
+      code[len - 1] |= 0x80;
+    }
+
+    self.write_bytes(&code[..len],).map_err(|(_, e,),| e,)
+  }
+  /// Writes a signed LEB128 code.
+  /// 
+  /// The code is written with `write_bytes` so aligned writers can copy it in bulk.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write.  
+  fn write_sleb128(&mut self, value: i64,) -> Result<&mut Self, Self::Error> {
+    let mut code = [0u8; 10];
+    let mut len = 0;
+    let mut value = value;
+    loop {
+      let byte = value as u8 & 0x7F;
+
+      code[len] = byte;
+      value >>= 7;
+      len += 1;
+
+      //Stop once the remaining bits are all sign extension.
+      if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) { break }
+      code[len - 1] |= 0x80;
+    }
+
+    self.write_bytes(&code[..len],).map_err(|(_, e,),| e,)
+  }
+  /// Writes a LEB128 code for a signed value mapped by `zigzag_encode`.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write.  
+  #[inline]
+  fn write_zigzag_leb128(&mut self, value: i64,) -> Result<&mut Self, Self::Error> {
+    self.write_uleb128(zigzag_encode(value,),)
+  }
+  /// Writes a Fibonacci code, the Zeckendorf representation of the value from the
+  /// smallest Fibonacci number up ended by an extra one.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to write, `0` cannot be written.  
+  fn write_fibonacci(&mut self, value: u64,) -> Result<&mut Self, Self::Error> {
+    let (code, bits,) = fibonacci_code(value,);
+
+    //The code may be longer than a word.
+    if bits > 64 { self.write_word(bits - 64, (code >> 64) as u64,)?; }
+
+    self.write_word(bits.min(64,), code as u64,)
+  }
+  /// Writes all of `bytes` to the input.
+  /// 
+  /// If an error is returned the number of bytes which were written is returned with
+  /// it.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to write.  
+  fn write_bytes(&mut self, bytes: &[u8],) -> Result<&mut Self, (usize, Self::Error,)> {
+    for (written, &byte,) in bytes.iter().enumerate() {
+      self.write_byte(byte,).map_err(move |e,| (written, e,),)?;
+    }
+
+    Ok(self)
+  }
+}
+
+/// A writer with a known bit order.
+pub trait OrderedWrite: BitWrite {
+  /// The order bits are written to each byte.
+  /// 
+  /// For `BitOrder::Msb` the high bits of a word are written first, for `BitOrder::Lsb`
+  /// the low bits are.
+  const ORDER: BitOrder;
+}
+
+impl<W,> OrderedWrite for &'_ mut W
+  where W: OrderedWrite, {
+  const ORDER: BitOrder = W::ORDER;
 }
 
 impl<W,> BitWrite for &'_ mut W
@@ -59,6 +344,18 @@ impl<W,> BitWrite for &'_ mut W
   fn write_byte(&mut self, byte: u8,) -> Result<&mut Self, Self::Error> { W::write_byte(self, byte,)?; Ok(self) }
   #[inline]
   fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> { W::write_bits(*self, bits, buf,) }
+  #[inline]
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> { W::write_word(self, bits, word,)?; Ok(self) }
+  #[inline]
+  fn write_unary(&mut self, value: u64, terminator: bool,) -> Result<&mut Self, Self::Error> {
+    W::write_unary(self, value, terminator,)?; Ok(self)
+  }
+  #[inline]
+  fn write_truncated_unary(&mut self, value: u64, max: u64, terminator: bool,) -> Result<&mut Self, Self::Error> {
+    W::write_truncated_unary(self, value, max, terminator,)?; Ok(self)
+  }
+  #[inline]
+  fn write_bytes(&mut self, bytes: &[u8],) -> Result<&mut Self, (usize, Self::Error,)> { W::write_bytes(self, bytes,)?; Ok(self) }
 }
 
 /// Progressively fill a byte from high bits to low bits.
@@ -164,6 +461,12 @@ impl<B,> BitWrite for WriteByte<B,>
   }
 }
 
+impl<B,> OrderedWrite for WriteByte<B,>
+  where B: BorrowMut<u8>, {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
+
+
 /// Progressively fill a slice from high bits to low bits.
 /// 
 /// The slices pointer will be updated as bytes are written.
@@ -250,6 +553,12 @@ impl<B,> BitWrite for WriteSlice<'_, B,>
   }
 }
 
+impl<B,> OrderedWrite for WriteSlice<'_, B,>
+  where B: BorrowMut<u8>, {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
+
+
 /// Progressively fill a slice from high bits to low bits.
 /// 
 /// The slices pointer will be updated as bytes are written.
@@ -285,6 +594,7 @@ impl<I,> WriteIter<I,>
     self
   }
   /// Unwraps the unfilled portion of the inner iterator if the writer is aligned.
+  #[allow(clippy::should_implement_trait,)]
   pub fn into_iter(self,) -> Result<I, UnalignedError<Self,>> {
     match self.to_write() {
       None => Ok(self.iter),
@@ -302,7 +612,7 @@ impl<I,> BitWrite for WriteIter<I,>
   fn is_aligned(&self,) -> bool { self.buffer.is_none() }
   fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
     //Get the cursor and the byte to populate.
-    let (cursor, mut byte,) = match core::mem::replace(&mut self.buffer, None,) {
+    let (cursor, mut byte,) = match self.buffer.take() {
       Some(v) => v,
       //Get the next byte to populate.
       None => self.iter.next().map(|b,| (Bits::B8, b,),).ok_or(bits,)?,
@@ -320,6 +630,13 @@ impl<I,> BitWrite for WriteIter<I,>
   }
 }
 
+impl<I,> OrderedWrite for WriteIter<I,>
+  where I: Iterator,
+    I::Item: BorrowMut<u8>, {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
+
+
 impl<I,> fmt::Debug for WriteIter<I,>
   where I: Iterator + fmt::Debug,
     I::Item: BorrowMut<u8> + fmt::Debug, {
@@ -333,21 +650,23 @@ impl<I,> fmt::Debug for WriteIter<I,>
 
 /// Progressively fill a `Vec` from high bits to low bits.
 /// 
-/// The slices pointer will be updated as bytes are written.
+/// Bits are collected in a word and moved into the `Vec` a word at a time.
 #[derive(Clone, Debug,)]
 pub struct WriteVec {
   /// The store of bits being written.
   vec: Vec<u8>,
-  /// The cursor over the next bit to be written.
-  cursor: Bits,
+  /// The bits waiting to be moved into `vec`, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
 }
 
 impl WriteVec {
   /// Creates a new empty writer.  
   #[inline]
-  pub const fn new() -> Self { Self { cursor: Bits::B8, vec: Vec::new(), } }
+  pub const fn new() -> Self { Self { vec: Vec::new(), acc: 0, acc_bits: 0, } }
   /// Returns the number of bits left to write before the writer is byte aligned.
-  pub fn to_write(&self,) -> Option<Bits> { Some(self.cursor).filter(|&b,| b != Bits::B8,) }
+  pub fn to_write(&self,) -> Option<Bits> { Bits::try_from((8 - self.acc_bits % 8) % 8,).ok() }
   /// Pads the internal buffer with zeros so that the writer is aligned.
   pub fn pad_zeros(&mut self,) -> &mut Self {
     if let Some(bits) = self.to_write() {
@@ -357,44 +676,67 @@ impl WriteVec {
     self
   }
   /// Unwraps the inner `Vec` if the writer is aligned.
-  pub fn into_vec(self,) -> Result<Vec<u8>, UnalignedError<Self,>> {
-    match self.cursor {
-      Bits::B8 => Ok(self.vec),
-      misalign => Err(UnalignedError(self, misalign,))
+  pub fn into_vec(mut self,) -> Result<Vec<u8>, UnalignedError<Self,>> {
+    match self.to_write() {
+      None => { self.drain(); Ok(self.vec) },
+      Some(misalign) => Err(UnalignedError(self, misalign,))
     }
   }
+  /// Moves all of the whole bytes in the accumulator into the `Vec`.
+  fn drain(&mut self,) {
+    let bytes = self.acc_bits / 8;
+
+    self.vec.extend_from_slice(&self.acc.to_be_bytes()[..bytes as usize],);
+    self.acc = self.acc.checked_shl(bytes as u32 * 8,).unwrap_or(0,);
+    self.acc_bits -= bytes * 8;
+  }
+}
+
+impl Default for WriteVec {
+  #[inline]
+  fn default() -> Self { Self::new() }
 }
 
 impl BitWrite for WriteVec {
-  type Error = !;
+  type Error = Infallible;
 
   #[inline]
-  fn is_aligned(&self,) -> bool { self.cursor == Bits::B8 }
+  fn is_aligned(&self,) -> bool { self.to_write().is_none() }
+  #[inline]
   fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
-    //Get the byte to write too.
-    let buffer = if self.cursor == Bits::B8 {
-      //We are starting a new byte.
-
-      let last = self.vec.len();
-      self.vec.push(0,);
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
 
-      &mut self.vec[last]
-    } else {
-      //We are continuing an old byte.
+    //The accumulator cannot always hold more than 56 bits, split the write.
+    if bits > 56 {
+      return self.write_word(bits - 32, word >> 32,)?.write_word(32, word,)
+    }
+    if bits == 0 { return Ok(self) }
 
-      let last = self.vec.len() - 1;
+    //Make space in the accumulator.
+    if self.acc_bits + bits > 64 { self.drain() }
 
-      &mut self.vec[last]
-    };
-    //Get the writer to write too.
-    let mut buffer = WriteByte { cursor: Some(self.cursor), buffer, };
+    //Write the bits below the bits already in the accumulator.
+    let word = word & (!0u64 >> (64 - bits));
+    self.acc |= word << (64 - self.acc_bits - bits);
+    self.acc_bits += bits;
 
-    //Write the bits out.
-    match buffer.write_bits(bits, buf,) {
-      //Update the cursor.
-      Ok(bits) => { self.cursor = buffer.cursor.unwrap_or(Bits::B8,); Ok(bits) },
-      //Write the remaining bits.
-      Err(to_write) => { self.cursor = Bits::B8; self.write_bits(to_write, buf,) },
+    Ok(self)
+  }
+  fn write_bytes(&mut self, bytes: &[u8],) -> Result<&mut Self, (usize, Self::Error,)> {
+    if self.is_aligned() {
+      self.drain();
+      self.vec.extend_from_slice(bytes,);
+    } else {
+      for &byte in bytes { self.write_byte(byte,).map_err(|e,| (0, e,),)?; }
     }
+
+    Ok(self)
   }
 }
+
+impl OrderedWrite for WriteVec {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
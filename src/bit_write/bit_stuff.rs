@@ -0,0 +1,152 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// Wraps a writer and inserts a stuffed bit after every run of `run_length` bits of the
+/// opposite value, as HDLC inserts a `0` after five `1`s.
+/// 
+/// Data written through this writer never contains the flag, the stuffed bit followed by
+/// `run_length + 1` opposite bits and the stuffed bit again, or the abort, `run_length + 2`
+/// opposite bits. Both are written unstuffed by `write_flag` and `write_abort`.
+/// 
+/// The bits of a word are written in the order of the inner writer.
+#[derive(Clone, Copy, Debug,)]
+pub struct BitStuffWriter<W,>
+  where W: BitWrite, {
+  /// The writer to write the stuffed bits to.
+  writer: W,
+  /// The number of opposite bits after which a bit is stuffed.
+  run_length: u8,
+  /// The value of the stuffed bit.
+  stuffed: bool,
+  /// The number of opposite bits written in a row.
+  run: u8,
+  /// The number of data bits written since the last flag or abort modulo `8`.
+  written: u8,
+}
+
+impl<W,> BitStuffWriter<W,>
+  where W: BitWrite, {
+  /// Constructs a new `BitStuffWriter` which stuffs a `0` after five `1`s, as in HDLC.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write to.  
+  #[inline]
+  pub const fn new(writer: W,) -> Self { Self::with_params(writer, 5, false,) }
+  /// Constructs a new `BitStuffWriter` with a custom run length and stuffed bit.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write to.  
+  /// run_length --- The number of opposite bits after which a bit is stuffed, from `1` to `8`.  
+  /// stuffed --- The value of the stuffed bit.  
+  pub const fn with_params(writer: W, run_length: u8, stuffed: bool,) -> Self {
+    assert!(run_length >= 1 && run_length <= 8, "The run length must be between 1 and 8",);
+
+    Self { writer, run_length, stuffed, run: 0, written: 0, }
+  }
+  /// Returns a reference to the inner writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &W { &self.writer }
+  /// Unwraps the inner writer.
+  #[inline]
+  pub fn into_inner(self,) -> W { self.writer }
+  /// Writes a flag, resetting the current run.
+  /// 
+  /// If an error is returned some of the flag may have been written.
+  pub fn write_flag(&mut self,) -> Result<&mut Self, W::Error> {
+    self.write_stuffing()?;
+    self.writer.write_bit(self.stuffed,)?;
+    for _ in 0..=self.run_length { self.writer.write_bit(!self.stuffed,)?; }
+    self.writer.write_bit(self.stuffed,)?;
+    self.run = 0;
+    self.written = 0;
+
+    Ok(self)
+  }
+  /// Writes an abort, resetting the current run.
+  /// 
+  /// If an error is returned some of the abort may have been written.
+  pub fn write_abort(&mut self,) -> Result<&mut Self, W::Error> {
+    self.write_stuffing()?;
+    for _ in 0..self.run_length + 2 { self.writer.write_bit(!self.stuffed,)?; }
+    self.run = 0;
+    self.written = 0;
+
+    Ok(self)
+  }
+  /// Writes a whole frame of bytes between two flags.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes of the frame.  
+  pub fn write_frame(&mut self, bytes: &[u8],) -> Result<&mut Self, W::Error>
+    where W: OrderedWrite, {
+    self.write_flag()?;
+    for &byte in bytes { self.write_byte(byte,)?; }
+
+    self.write_flag()
+  }
+  /// Writes the stuffed bit if a run has been completed.
+  fn write_stuffing(&mut self,) -> Result<(), W::Error> {
+    if self.run == self.run_length {
+      self.writer.write_bit(self.stuffed,)?;
+      self.run = 0;
+    }
+
+    Ok(())
+  }
+  /// Writes a bit of data, stuffing a bit after it if it completes a run.
+  /// 
+  /// # Params
+  /// 
+  /// bit --- The bit to write.  
+  fn write_data(&mut self, bit: bool,) -> Result<(), W::Error> {
+    //A stuffed bit left unwritten by an error is written first.
+    self.write_stuffing()?;
+    self.writer.write_bit(bit,)?;
+    self.written = (self.written + 1) % 8;
+
+    if bit == self.stuffed { self.run = 0; } else { self.run += 1; }
+
+    self.write_stuffing()
+  }
+}
+
+impl<W,> BitWrite for BitStuffWriter<W,>
+  where W: OrderedWrite, {
+  type Error = W::Error;
+
+  /// Returns `true` if a multiple of `8` bits of data have been written since the last
+  /// flag or abort.
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.written == 0 }
+  #[inline]
+  fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  /// Writes the bits of `word` one at a time.
+  /// 
+  /// If an error is returned some of the bits may have been written.
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    for index in 0..bits {
+      let shift = match W::ORDER {
+        BitOrder::Msb => bits - 1 - index,
+        BitOrder::Lsb => index,
+      };
+
+      self.write_data(word & (1 << shift) != 0,)?;
+    }
+
+    Ok(self)
+  }
+}
+
+impl<W,> OrderedWrite for BitStuffWriter<W,>
+  where W: OrderedWrite, {
+  const ORDER: BitOrder = W::ORDER;
+}
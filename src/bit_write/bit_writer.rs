@@ -1,32 +1,70 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-31
+//! Last Moddified --- 2026-10-18
 
 use super::*;
-use std::io::{self, Read, Write, Error,};
+use std::io::{self, Read, Write, Error, ErrorKind,};
+
+/// The panic message if the inner writer is used after it was unwrapped.
+const TAKEN: &str = "The inner writer has been unwrapped";
+/// The default capacity of the internal buffer of a `WriteIO`.
+pub const DEFAULT_WRITE_CAPACITY: usize = 8 * 1024;
 
 /// Wraps an IO writer and writes to it bitwise, high bits first.
-#[derive(Clone, Copy, Debug,)]
+/// 
+/// Whole bytes are collected in an internal buffer and written to the inner writer in
+/// batches, the buffer is only written when it is full or the writer is flushed.  
+/// Dropping the writer tries to commit the whole bytes which have been written, like a
+/// `BufWriter`, ignoring any errors and losing a partially written byte. Use `finish`
+/// or `into_writer` to commit every bit and see the errors.  
+/// The writer cannot be cloned as both copies would commit the same bytes.
+#[derive(Debug,)]
 pub struct WriteIO<W,>
   where W: Write, {
-  /// The current byte being read.
-  buffer: WriteByte,
-  /// The writer of bytes to write too.
-  writer: W,
+  /// The bits waiting to be moved into `buffer`, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+  /// The bytes waiting to be written to the inner writer.
+  buffer: Vec<u8>,
+  /// The number of bytes `buffer` may hold before it is written.
+  capacity: usize,
+  /// The writer of bytes to write too, only taken when the writer is unwrapped.
+  writer: Option<W>,
 }
 
 impl<W,> WriteIO<W,>
   where W: Write, {
-  /// Constructs a new `WriteIO` over the writer.
+  /// Constructs a new `WriteIO` over the writer with the default buffer capacity.
   /// 
   /// # Params
   /// 
   /// writer --- The writer to write too.  
   #[inline]
-  pub const fn new(writer: W,) -> Self {
-    Self { writer, buffer: WriteByte::EMPTY, }
+  pub fn new(writer: W,) -> Self { Self::with_capacity(DEFAULT_WRITE_CAPACITY, writer,) }
+  /// Constructs a new `WriteIO` over the writer which writes batches of up to `capacity`
+  /// bytes.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The size of the internal buffer, at least one byte is used.  
+  /// writer --- The writer to write too.  
+  pub fn with_capacity(capacity: usize, writer: W,) -> Self {
+    let capacity = capacity.max(1,);
+
+    Self { acc: 0, acc_bits: 0, buffer: Vec::with_capacity(capacity,), capacity, writer: Some(writer,), }
   }
-  /// The number of bytes before the writer is byte aligned.
-  pub fn to_write(&self,) -> Option<Bits> { self.buffer.to_write().filter(|&b,| b != Bits::B8,) }
+  /// Returns the capacity of the internal buffer.
+  #[inline]
+  pub fn capacity(&self,) -> usize { self.capacity }
+  /// Returns a reference to the inner writer.
+  #[inline]
+  pub fn get_ref(&self,) -> &W { self.writer.as_ref().expect(TAKEN,) }
+  /// Returns the number of bits which have been written but not yet committed to the
+  /// inner writer.
+  #[inline]
+  pub fn buffered_bits(&self,) -> usize { self.buffer.len() * 8 + self.acc_bits as usize }
+  /// The number of bits before the writer is byte aligned.
+  pub fn to_write(&self,) -> Option<Bits> { Bits::try_from((8 - self.acc_bits % 8) % 8,).ok() }
   /// Pads the internal buffer with zeros so that the writer is aligned.
   pub fn pad_zeros(&mut self,) -> &mut Self {
     if let Some(bits) = self.to_write() {
@@ -35,55 +73,209 @@ impl<W,> WriteIO<W,>
 
     self
   }
-  /// Attempts to clear the internal buffer if it is full.
+  /// Writes all of the whole bytes which have been written to the inner writer and
+  /// flushes it.
+  /// 
+  /// Bits before the writer is aligned are kept in the internal buffer.
   pub fn flush(&mut self,) -> io::Result<&mut Self> {
-    if self.buffer.cursor == None {
-      self.writer.write_all(core::slice::from_mut(&mut self.buffer.buffer,),)?;
-      self.buffer.reset();
-    }
+    self.drain()?;
+    self.write_buffer()?;
+    self.inner().flush()?;
 
     Ok(self)
   }
-  /// Clears the internal buffer, returning its current state.
+  /// Pads the writer with zeros so that it is aligned and flushes it.
   #[inline]
-  pub fn clear_buffer(&mut self,) -> WriteByte {
-    core::mem::replace(&mut self.buffer, WriteByte::EMPTY,)
+  pub fn finish(&mut self,) -> io::Result<&mut Self> { self.pad_zeros().flush() }
+  /// Clears the internal buffer, returning its current state.
+  /// 
+  /// The whole bytes which were not committed to the inner writer are returned along
+  /// with the partially written byte.
+  pub fn clear_buffer(&mut self,) -> (Vec<u8>, WriteByte,) {
+    let mut bytes = core::mem::take(&mut self.buffer,);
+    let mut partial = WriteByte::EMPTY;
+
+    self.buffer = Vec::with_capacity(self.capacity,);
+    for _ in 0..self.acc_bits / 8 {
+      bytes.push((self.acc >> 56) as u8,);
+      self.acc <<= 8;
+    }
+    if let Ok(bits) = Bits::try_from(self.acc_bits % 8,) {
+      partial.write_bits(bits, (self.acc >> (64 - bits as u32)) as u8,).ok();
+    }
+    self.acc = 0;
+    self.acc_bits = 0;
+
+    (bytes, partial,)
+  }
+  /// Flushes the writer and unwraps the inner writer if the writer is aligned.
+  /// 
+  /// If the writer is unaligned pad the writer or write the missing bits.
+  pub fn into_writer(mut self,) -> Result<W, IntoWriterError<W,>> {
+    if let Some(misalign) = self.to_write() {
+      return Err(IntoWriterError::Unaligned(UnalignedError(self, misalign,),))
+    }
+
+    if let Err(e) = self.flush() { return Err(IntoWriterError::Io(self, e,)) }
+
+    //The internal buffer is empty so there is nothing left for `drop` to commit.
+    Ok(self.writer.take().expect(TAKEN,))
   }
-  /// Unwraps the inner writer if the inner buffer is empty.
+  /// Returns a mutable reference to the inner writer.
+  #[inline]
+  fn inner(&mut self,) -> &mut W { self.writer.as_mut().expect(TAKEN,) }
+  /// Moves all of the whole bytes in the accumulator into the internal buffer, writing
+  /// the internal buffer whenever it is full.
   /// 
-  /// If an unaligned error is returned, and the misalignment is `8` bits try flushing
-  /// the writer, else write the missing bits.  
-  pub fn into_writer(self,) -> Result<W, UnalignedError<Self,>> {
-    match self.buffer.cursor {
-      Some(Bits::B8) => Ok(self.writer),
-      misalign => Err(UnalignedError(self, misalign.unwrap_or(Bits::B8,),)),
+  /// The bytes are moved even if writing fails so the internal buffer grows past its
+  /// capacity while the inner writer is failing, the first error is returned.
+  fn drain(&mut self,) -> io::Result<()> {
+    let mut res = Ok(());
+    while self.acc_bits >= 8 {
+      if res.is_ok() && self.buffer.len() >= self.capacity { res = self.write_buffer() }
+
+      self.buffer.push((self.acc >> 56) as u8,);
+      self.acc <<= 8;
+      self.acc_bits -= 8;
     }
+
+    res
+  }
+  /// Writes the internal buffer to the inner writer.
+  /// 
+  /// Only the bytes which were not written are left in the internal buffer.
+  fn write_buffer(&mut self,) -> io::Result<()> {
+    let mut written = 0;
+    let res = loop {
+      if written == self.buffer.len() { break Ok(()) }
+
+      match self.writer.as_mut().expect(TAKEN,).write(&self.buffer[written..],) {
+        Ok(0) => break Err(ErrorKind::WriteZero.into()),
+        Ok(len) => written += len,
+        Err(e) if e.kind() == ErrorKind::Interrupted => {},
+        Err(e) => break Err(e),
+      }
+    };
+
+    self.buffer.drain(..written,);
+
+    res
   }
 }
 
 impl<W,> BitWrite for WriteIO<W,>
   where W: Write, {
+  /// The number of bits left unwritten and the error encountered.
+  /// 
+  /// Writes of bits are always accepted entirely before the internal buffer is
+  /// committed, so their errors report `None` bits left unwritten and `buffered_bits`
+  /// reports the bits still waiting to be committed.  
+  /// `write_bytes` reports a full byte left unwritten along with the number of bytes
+  /// which were written.
   type Error = (Option<Bits>, Error,);
 
   #[inline]
-  fn is_aligned(&self,) -> bool { self.buffer.cursor == Some(Bits::B8) }
+  fn is_aligned(&self,) -> bool { self.to_write().is_none() }
+  #[inline]
   fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
-    //Write the bits too the internal buffer.
-    match self.buffer.write_bits(bits, buf,) {
-      //Attempt to flush the inner buffer.
-      Ok(bits) => { self.flush().map_err(|e,| (None, e,),)?; Ok(bits) },
-      //Attempt to flush the inner buffer and continue writing.
-      Err(to_write) => self.flush()
-        //Return any error while flushing.
-        .map_err(move |e,| (Some(to_write), e,),)?
-        //Continue writing.
-        .write_bits(to_write, buf,),
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, split the write and accept
+    //both halves before returning an error.
+    if bits > 56 {
+      let high = self.write_word(bits - 32, word >> 32,).map(|_,| (),);
+      let low = self.write_word(32, word,).map(|_,| (),);
+
+      return high.and(low,).map(move |_,| self,)
+    }
+    if bits == 0 { return Ok(self) }
+
+    //Make space in the accumulator.
+    let res = if self.acc_bits + bits > 64 { self.drain() } else { Ok(()) };
+
+    //Write the bits below the bits already in the accumulator.
+    let word = word & (!0u64 >> (64 - bits));
+    self.acc |= word << (64 - self.acc_bits - bits);
+    self.acc_bits += bits;
+
+    res.map_err(|e,| (None, e,),)?;
+
+    Ok(self)
+  }
+  fn write_bytes(&mut self, bytes: &[u8],) -> Result<&mut Self, (usize, Self::Error,)> {
+    if !self.is_aligned() {
+      for (written, &byte,) in bytes.iter().enumerate() {
+        self.write_byte(byte,).map_err(move |e,| (written, e,),)?;
+      }
+
+      return Ok(self)
+    }
+
+    self.drain().map_err(|e,| (0, (Some(Bits::B8), e,),),)?;
+    //Make space in the internal buffer.
+    if self.buffer.len() + bytes.len() > self.capacity {
+      self.write_buffer().map_err(|e,| (0, (Some(Bits::B8), e,),),)?;
     }
+    //Large writes bypass the internal buffer.
+    if bytes.len() >= self.capacity {
+      let mut written = 0;
+      while written < bytes.len() {
+        match self.inner().write(&bytes[written..],) {
+          Ok(0) => return Err((written, (Some(Bits::B8), ErrorKind::WriteZero.into(),),)),
+          Ok(len) => written += len,
+          Err(e) if e.kind() == ErrorKind::Interrupted => {},
+          Err(e) => return Err((written, (Some(Bits::B8), e,),)),
+        }
+      }
+    } else { self.buffer.extend_from_slice(bytes,) }
+
+    Ok(self)
+  }
+}
+
+impl<W,> Drop for WriteIO<W,>
+  where W: Write, {
+  fn drop(&mut self,) {
+    //The inner writer has been unwrapped.
+    if self.writer.is_none() { return }
+
+    self.drain().ok();
+    self.write_buffer().ok();
   }
 }
 
+impl<W,> OrderedWrite for WriteIO<W,>
+  where W: Write, {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
+
+
 impl<W,> Read for WriteIO<W,>
   where W: Read + Write, {
   #[inline]
-  fn read(&mut self, buf: &mut [u8],) -> io::Result<usize> { W::read(&mut self.writer, buf,) }
+  fn read(&mut self, buf: &mut [u8],) -> io::Result<usize> { W::read(self.inner(), buf,) }
+}
+
+/// The error returned when unwrapping a `WriteIO` fails.
+#[derive(Debug,)]
+pub enum IntoWriterError<W,>
+  where W: Write, {
+  /// The writer was not aligned.
+  Unaligned(UnalignedError<WriteIO<W,>>),
+  /// The internal buffer could not be written to the inner writer.
+  Io(WriteIO<W,>, Error,),
+}
+
+impl<W,> IntoWriterError<W,>
+  where W: Write, {
+  /// Unwraps the writer from the error.
+  pub fn into_inner(self,) -> WriteIO<W,> {
+    match self {
+      IntoWriterError::Unaligned(e) => e.into_inner(),
+      IntoWriterError::Io(writer, _,) => writer,
+    }
+  }
 }
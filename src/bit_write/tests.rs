@@ -1,9 +1,10 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-31
+//! Last Moddified --- 2026-10-18
 
 #![cfg(test,)]
 
 use super::*;
+use crate::BitRead;
 
 #[allow(non_snake_case,)]
 #[test]
@@ -126,6 +127,187 @@ fn test_WriteVec() {
   assert_eq!(writer.into_vec().ok(), Some(alloc::vec![0b10100011, 0b11111111, 0b00000000,],),);
 }
 
+#[allow(non_snake_case,)]
+#[test]
+fn test_WriteVec_words() {
+  use crate::{BitRead, ReadIter,};
+
+  let mut writer = WriteVec::new();
+  for (i, bits,) in Bits::BITS.iter().copied().cycle().take(96,).enumerate() {
+    assert_eq!(writer.write_bits(bits, i as u8,), Ok(bits),);
+  }
+  assert_eq!(writer.to_write(), None,);
+  let bytes = writer.into_vec().expect("Error unwrapping",);
+  assert_eq!(bytes.len(), 12 * 36 / 8,);
+
+  let mut reader = ReadIter::new(bytes.iter(),);
+  for (i, bits,) in Bits::BITS.iter().copied().cycle().take(96,).enumerate() {
+    assert_eq!(reader.read_bits(bits,), Ok(i as u8 & bits.mask()), "Error reading {}", i,);
+  }
+  assert_eq!(reader.read_bit(), Err(None),);
+}
+
+#[test]
+fn test_write_unary() {
+  let mut writer = WriteVec::new();
+
+  writer.write_unary(3, false,).expect("Error writing 1",);
+  writer.write_unary(0, true,).expect("Error writing 2",);
+  writer.write_truncated_unary(2, 2, true,).expect("Error writing 3",);
+  writer.write_truncated_unary(1, 2, false,).expect("Error writing 4",);
+  writer.write_unary(70, true,).expect("Error writing 5",);
+  assert_eq!(writer.to_write(), None,);
+  writer.write_bits(Bits::B3, 0b101,).expect("Error writing 6",);
+  assert_eq!(
+    writer.pad_zeros().clone().into_vec().ok(),
+    Some(alloc::vec![0b11101001, 0, 0, 0, 0, 0, 0, 0, 0, 0b00000001, 0b10100000,]),
+  );
+}
+
+#[test]
+fn test_write_exp_golomb() {
+  let mut writer = WriteVec::new();
+
+  for &value in &[0, 1, 2, 3, 7,] { writer.write_ue(value,).expect("Error writing ue",); }
+  for &value in &[1, -1, 2,] { writer.write_se(value,).expect("Error writing se",); }
+  writer.write_exp_golomb(2, 5,).expect("Error writing k = 2",);
+  assert_eq!(
+    writer.pad_zeros().clone().into_vec().ok(),
+    Some(alloc::vec![0b10100110, 0b01000001, 0b00001001, 0b10010001, 0b00100000,]),
+  );
+
+  let mut writer = WriteVec::new();
+  writer.write_ue(u32::MAX,).expect("Error writing ue",);
+  writer.write_se(i32::MAX,).expect("Error writing se",);
+  writer.write_se(-i32::MAX,).expect("Error writing se",);
+  writer.write_se(i32::MIN,).expect("Error writing se",);
+  writer.write_exp_golomb(32, u32::MAX,).expect("Error writing k = 32",);
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = crate::ReadIter::new(bytes.iter(),);
+  assert_eq!(reader.read_ue(), Ok(u32::MAX),);
+  assert_eq!(reader.read_se(), Ok(i32::MAX),);
+  assert_eq!(reader.read_se(), Ok(-i32::MAX),);
+  assert_eq!(reader.read_se(), Ok(i32::MIN),);
+  assert_eq!(reader.read_exp_golomb(32,), Ok(u32::MAX),);
+
+  //`i32::MIN` is 32 zeros followed by `u32::MAX + 2`.
+  let mut writer = WriteVec::new();
+  writer.write_se(i32::MIN,).expect("Error writing se",);
+  assert_eq!(writer.pad_zeros().clone().into_vec().ok(), Some(alloc::vec![0, 0, 0, 0, 0b10000000, 0, 0, 0, 0b10000000,]),);
+}
+
+#[test]
+fn test_write_elias() {
+  let mut writer = WriteVec::new();
+
+  for &value in &[1, 2, 4,] { writer.write_gamma(value,).expect("Error writing gamma",); }
+  for &value in &[1, 3, 4,] { writer.write_delta(value,).expect("Error writing delta",); }
+  for &value in &[1, 3, 16,] { writer.write_omega(value,).expect("Error writing omega",); }
+  assert_eq!(
+    writer.pad_zeros().clone().into_vec().ok(),
+    Some(alloc::vec![0b10100010, 0b01010101, 0b10001101, 0b01001000, 0,]),
+  );
+
+  let values = [1, 2, 3, 1000, 1 << 32, (1 << 63) - 1, 1 << 63, u64::MAX,];
+  let mut writer = WriteVec::new();
+  for &value in &values {
+    writer.write_gamma(value,).expect("Error writing gamma",);
+    writer.write_delta(value,).expect("Error writing delta",);
+    writer.write_omega(value,).expect("Error writing omega",);
+  }
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = crate::ReadIter::new(bytes.iter(),);
+  for &value in &values {
+    assert_eq!(reader.read_gamma(), Ok(value),);
+    assert_eq!(reader.read_delta(), Ok(value),);
+    assert_eq!(reader.read_omega(), Ok(value),);
+  }
+}
+
+#[test]
+fn test_write_golomb() {
+  let mut writer = WriteVec::new();
+
+  writer.write_rice(2, 5,).expect("Error writing rice",);
+  writer.write_rice(2, 0,).expect("Error writing rice",);
+  writer.write_signed_rice(1, -2,).expect("Error writing signed rice",);
+  writer.write_golomb(5, 9,).expect("Error writing golomb",);
+  writer.write_golomb(5, 2,).expect("Error writing golomb",);
+  writer.write_golomb(1, 2,).expect("Error writing golomb",);
+  assert_eq!(
+    writer.pad_zeros().clone().into_vec().ok(),
+    Some(alloc::vec![0b01011000, 0b11011111, 0b10001000,]),
+  );
+
+  let mut writer = WriteVec::new();
+  for m in 1..20 {
+    for value in 0..50 { writer.write_golomb(m, value,).expect("Error writing golomb",); }
+  }
+  writer.write_rice(63, u64::MAX,).expect("Error writing rice",);
+  writer.write_golomb(u64::MAX, u64::MAX - 1,).expect("Error writing golomb",);
+  writer.write_golomb(u64::MAX, u64::MAX,).expect("Error writing golomb",);
+  writer.write_signed_rice(4, -1000,).expect("Error writing signed rice",);
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = crate::ReadIter::new(bytes.iter(),);
+  for m in 1..20 {
+    for value in 0..50 { assert_eq!(reader.read_golomb(m,), Ok(value), "m = {}", m,); }
+  }
+  assert_eq!(reader.read_rice(63,), Ok(u64::MAX),);
+  assert_eq!(reader.read_golomb(u64::MAX,), Ok(u64::MAX - 1),);
+  assert_eq!(reader.read_golomb(u64::MAX,), Ok(u64::MAX),);
+  assert_eq!(reader.read_signed_rice(4,), Ok(-1000),);
+}
+
+#[test]
+fn test_write_leb128() {
+  let mut writer = WriteVec::new();
+
+  writer.write_uleb128(624485,).expect("Error writing uleb128",);
+  writer.write_sleb128(-123456,).expect("Error writing sleb128",);
+  writer.write_sleb128(64,).expect("Error writing sleb128",);
+  writer.write_sleb128(-65,).expect("Error writing sleb128",);
+  writer.write_sleb128(-64,).expect("Error writing sleb128",);
+  writer.write_zigzag_leb128(-2,).expect("Error writing zigzag",);
+  assert_eq!(
+    writer.into_vec().ok(),
+    Some(alloc::vec![0xE5, 0x8E, 0x26, 0xC0, 0xBB, 0x78, 0xC0, 0x00, 0xBF, 0x7F, 0x40, 0x03,]),
+  );
+
+  let mut writer = WriteVec::new();
+  writer.write_bits(Bits::B3, 0b101,).expect("Error writing bits",);
+  for &value in &[0, 1, 127, 128, u64::MAX,] { writer.write_uleb128(value,).expect("Error writing uleb128",); }
+  for &value in &[0, -1, 63, -64, i64::MAX, i64::MIN,] { writer.write_sleb128(value,).expect("Error writing sleb128",); }
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = crate::ReadIter::new(bytes.iter(),);
+  assert_eq!(reader.read_bits(Bits::B3,), Ok(0b101),);
+  for &value in &[0, 1, 127, 128, u64::MAX,] { assert_eq!(reader.read_uleb128(), Ok(value),); }
+  for &value in &[0, -1, 63, -64, i64::MAX, i64::MIN,] { assert_eq!(reader.read_sleb128(), Ok(value),); }
+}
+
+#[test]
+fn test_write_fibonacci() {
+  let mut writer = WriteVec::new();
+
+  for &value in &[1, 4, 11,] { writer.write_fibonacci(value,).expect("Error writing fibonacci",); }
+  assert_eq!(writer.pad_zeros().clone().into_vec().ok(), Some(alloc::vec![0b11101100, 0b10110000,]),);
+
+  let values = [1, 2, 3, 100, 1 << 40, 12200160415121876738, u64::MAX,];
+  let mut writer = WriteVec::new();
+  for &value in &values { writer.write_fibonacci(value,).expect("Error writing fibonacci",); }
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+  let mut reader = crate::ReadIter::new(bytes.iter(),);
+  for &value in &values { assert_eq!(reader.read_fibonacci(), Ok(value),); }
+
+  //Corrupt the second code and resynchronise at the start of the third.
+  let mut bytes = bytes;
+  bytes[0] ^= 0b00100000;
+  let mut reader = crate::ReadIter::new(bytes.iter(),);
+  assert_eq!(reader.read_fibonacci(), Ok(1),);
+  assert_eq!(reader.read_fibonacci(), Ok(1),);
+  assert!(reader.resync_fibonacci().is_ok(),);
+  for &value in &values[3..] { assert_eq!(reader.read_fibonacci(), Ok(value),); }
+}
+
 #[allow(non_snake_case,)]
 #[test]
 fn test_WriteIO() {
@@ -138,32 +320,267 @@ fn test_WriteIO() {
   assert!(writer.write_bit(true,).is_ok(),);
   assert_eq!(writer.write_bits(Bits::B3, 0b111101,).ok(), Some(Bits::B3),);
   assert_eq!(writer.write_bits(Bits::B4, 0b0110111,).ok(), Some(Bits::B4),);
+  assert_eq!(writer.buffered_bits(), 8,);
   match writer.into_writer() {
     //The conversion succeeded, return the writer.
     Ok(slice) => assert_eq!(slice, &[0,],),
     Err(e) => panic!("Expected slice, found: {:?}", e,),
   }
+  assert_eq!(buffer[0], 0b11010111,);
 
   for b in buffer.iter_mut() { *b = 0 }
-  writer = WriteIO::new(buffer.as_mut(),);
+  let mut writer = WriteIO::new(buffer.as_mut(),);
   assert_eq!(writer.write_bits(Bits::B4, 0b1011010,).ok(), Some(Bits::B4),);
   assert_eq!(writer.to_write(), Some(Bits::B4),);
   assert_eq!(writer.write_bits(Bits::B3, 0b10001,).ok(), Some(Bits::B3),);
   match writer.into_writer() {
     //The conversion failed successfully, return the writer.
-    Err(e) => writer = e.into_inner(),
-    Ok(e) => panic!("Expected error, found: {:?}", e,),
+    Err(IntoWriterError::Unaligned(e)) => { assert_eq!(e.misalign(), Bits::B1,); writer = e.into_inner() },
+    e => panic!("Expected error, found: {:?}", e,),
   }
   assert!(writer.write_byte(!0,).is_ok(),);
   assert_eq!(writer.to_write(), Some(Bits::B1),);
   assert_eq!(writer.write_bits(Bits::B1, 0b111,).ok(), Some(Bits::B1),);
   assert_eq!(writer.to_write(), None,);
-  assert!(writer.write_byte(0,).is_err(),);
-  let WriteByte { buffer: byte, cursor, } = writer.clear_buffer();
-  assert_eq!((byte, cursor,), (0, None,),);
+  assert!(writer.write_byte(0,).is_ok(),);
+  assert!(writer.write_bits(Bits::B3, 0b101,).is_ok(),);
+  assert_eq!(writer.buffered_bits(), 27,);
+  match writer.finish() {
+    Err(e) => assert_eq!(e.kind(), std::io::ErrorKind::WriteZero,),
+    Ok(_) => panic!("Expected error",),
+  }
+  assert_eq!(writer.buffered_bits(), 16,);
+  let (bytes, partial,) = writer.clear_buffer();
+  assert_eq!((bytes, partial.to_write(),), (alloc::vec![0, 0b10100000,], Some(Bits::B8),),);
+  writer.write_bits(Bits::B3, 0b101,).expect("Error writing",);
+  let (bytes, partial,) = writer.clear_buffer();
+  assert_eq!((bytes, partial.to_write(), partial.buffer,), (Vec::new(), Some(Bits::B5), 0b10100000,),);
+  assert_eq!(writer.buffered_bits(), 0,);
   match writer.into_writer() {
     Ok(slice) => assert_eq!(slice, [].as_mut(),),
     Err(e) => panic!("Expected slice, found: {:?}", e,),
   }
   assert_eq!(buffer, [0b10100011u8, 0b11111111,],);
 }
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_WriteIO_batches() {
+  #![cfg(feature = "std",)]
+
+  /// A writer which records the size of each write and fails after `limit` bytes.
+  #[derive(Debug,)]
+  struct Batches { bytes: Vec<u8>, writes: Vec<usize>, limit: usize, }
+
+  impl std::io::Write for Batches {
+    fn write(&mut self, buf: &[u8],) -> std::io::Result<usize> {
+      let len = usize::min(buf.len(), self.limit - self.bytes.len(),);
+
+      if len == 0 { return Err(std::io::ErrorKind::Other.into()) }
+
+      self.bytes.extend_from_slice(&buf[..len],);
+      self.writes.push(len,);
+
+      Ok(len)
+    }
+    fn flush(&mut self,) -> std::io::Result<()> { Ok(()) }
+  }
+
+  let mut writer = WriteIO::with_capacity(4, Batches { bytes: Vec::new(), writes: Vec::new(), limit: 100, },);
+  assert_eq!(writer.capacity(), 4,);
+  for byte in 0..20u8 {
+    assert!(writer.write_bits(Bits::B4, byte >> 4,).is_ok(),);
+    assert!(writer.write_bits(Bits::B4, byte,).is_ok(),);
+  }
+  assert!(writer.write_bits(Bits::B5, 0b10101,).is_ok(),);
+  assert_eq!(writer.to_write(), Some(Bits::B3),);
+  assert!(writer.finish().is_ok(),);
+  assert_eq!(writer.buffered_bits(), 0,);
+  let inner = match writer.into_writer() {
+    Ok(inner) => inner,
+    Err(e) => panic!("Expected writer, found: {:?}", e,),
+  };
+  assert!(inner.writes.iter().all(|&len,| len <= 4,),);
+  assert_eq!(inner.writes.len(), 6,);
+  assert_eq!(inner.bytes[..20], (0..20).collect::<Vec<u8>>()[..],);
+  assert_eq!(inner.bytes[20..], [0b10101000,],);
+  //Unwrapping leaves nothing for `drop` to commit again.
+  assert_eq!(inner.bytes.len(), 21,);
+
+  let mut writer = WriteIO::with_capacity(4, Batches { bytes: Vec::new(), writes: Vec::new(), limit: 6, },);
+  let mut written = 0;
+  let err = loop {
+    match writer.write_byte(written as u8,) {
+      Ok(_) => written += 1,
+      Err(e) => break e,
+    }
+  };
+  //The failed write was still accepted.
+  assert_eq!(err.0, None,);
+  written += 1;
+  assert_eq!(written * 8, 6 * 8 + writer.buffered_bits(),);
+  //Writes of every size are accepted entirely while the inner writer fails.
+  let mut errors = 0;
+  for bits in [16, 56, 64, 64,] {
+    let buffered = writer.buffered_bits();
+
+    match writer.write_word(bits, !0,) {
+      Ok(_) => {},
+      Err((unwritten, _,)) => { assert_eq!(unwritten, None,); errors += 1 },
+    }
+    assert_eq!(writer.buffered_bits(), buffered + bits as usize,);
+    written += bits as usize / 8;
+  }
+  assert!(errors > 1,);
+  match writer.into_writer() {
+    Err(IntoWriterError::Io(writer, _,)) => {
+      assert_eq!(writer.buffered_bits(), (written - 6) * 8,);
+      assert_eq!(writer.get_ref().bytes, [0, 1, 2, 3, 4, 5,],);
+    },
+    e => panic!("Expected error, found: {:?}", e,),
+  }
+
+  //Dropping the writer commits the whole bytes written.
+  let mut inner = Batches { bytes: Vec::new(), writes: Vec::new(), limit: 100, };
+  let mut writer = WriteIO::new(&mut inner,);
+  writer.write_word(20, 0x12345,).expect("Error writing",);
+  drop(writer,);
+  assert_eq!(inner.bytes, [0x12, 0x34,],);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_WriteLsb() {
+  let mut writer = WriteLsb::new(WriteVec::new(),);
+
+  writer.write_bit(true,).expect("Error writing",);
+  writer.write_word(3, 0b000,).expect("Error writing",);
+  writer.write_word(4, 0b1011,).expect("Error writing",);
+  writer.write_word(16, 0x1234,).expect("Error writing",);
+  writer.write_word(3, 0b101,).expect("Error writing",);
+  assert_eq!(writer.to_write(), Some(Bits::B5),);
+  writer.write_bytes(&[0xAB,],).expect("Error writing",);
+  writer.pad_zeros();
+
+  let (writer, buffered,) = writer.into_inner().expect("Error unwrapping",);
+  assert_eq!(buffered, [0b1011_0001, 0x34, 0x12, 0b0101_1101, 0b0000_0101,],);
+  assert!(writer.into_vec().expect("Error unwrapping",).is_empty(),);
+
+  let mut writer = WriteLsb::new(WriteVec::new(),);
+  writer.write_word(64, 0x0123_4567_89AB_CDEF,).expect("Error writing",);
+  writer.write_word(4, 0xF,).expect("Error writing",);
+  writer.pad_zeros().flush().expect("Error flushing",);
+
+  let (writer, buffered,) = writer.into_inner().expect("Error unwrapping",);
+  assert!(buffered.is_empty(),);
+  assert_eq!(writer.into_vec().expect("Error unwrapping",), [0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01, 0x0F,],);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_WriteBackward() {
+  let mut writer = WriteBackward::new();
+  assert!(writer.is_empty(),);
+  writer.write_word(3, 0b101,).expect("Error writing",)
+    .write_word(12, 0xABC,).expect("Error writing",)
+    .write_bit(false,).expect("Error writing",)
+    .write_word(56, 0x00FF_EEDD_CCBB_AA99,).expect("Error writing",)
+    .write_word(0, 0,).expect("Error writing",);
+  assert_eq!(writer.len(), 72,);
+  assert!(writer.is_aligned(),);
+  assert_eq!(writer.finish(), [0xE5, 0x55, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, 0x01,],);
+
+  let mut writer = WriteBackward::new();
+  writer.write_word(64, 0x8877_6655_4433_2211,).expect("Error writing",)
+    .write_bits(Bits::B3, 0xFE,).expect("Error writing",);
+  assert!(!writer.is_aligned(),);
+  assert_eq!(writer.finish(), [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x0E,],);
+
+  assert_eq!(WriteBackward::new().finish(), [0x01,],);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_BitStuffWriter() {
+  let mut writer = BitStuffWriter::new(WriteVec::new(),);
+  //A `0` is stuffed after the fifth `1`.
+  writer.write_byte(0xFF,).expect("Error writing",);
+  //Alignment counts the data bits, not the stuffed bits.
+  assert!(writer.is_aligned(),);
+  assert!(!writer.get_ref().is_aligned(),);
+  writer.write_flag().expect("Error writing",);
+  let mut writer = writer.into_inner();
+  assert_eq!(writer.to_write(), Some(Bits::B7),);
+  assert_eq!(writer.pad_zeros().clone().into_vec().expect("Error unwrapping",), [0xFB, 0xBF, 0x00,],);
+
+  //HDLC sends low bits first.
+  let mut writer = BitStuffWriter::new(WriteLsb::new(WriteVec::new(),),);
+  writer.write_frame(&[0x7E, 0xFF,],).expect("Error writing",);
+  let mut writer = writer.into_inner();
+  writer.pad_zeros().flush().expect("Error flushing",);
+  let (writer, _,) = writer.into_inner().expect("Error unwrapping",);
+  assert_eq!(writer.into_vec().expect("Error unwrapping",), [0x7E, 0xBE, 0xBE, 0xFB, 0x01,],);
+
+  //A `1` stuffed after two `0`s then an abort of four `0`s.
+  let mut writer = BitStuffWriter::with_params(WriteVec::new(), 2, true,);
+  writer.write_word(4, 0,).expect("Error writing",)
+    .write_abort().expect("Error writing",);
+  let mut writer = writer.into_inner();
+  assert_eq!(writer.pad_zeros().clone().into_vec().expect("Error unwrapping",), [0x24, 0x00,],);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_RbspWriter() {
+  let mut writer = RbspWriter::new(WriteVec::new(),);
+  writer.write_bytes(&[0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x03, 0xFF, 0x00, 0x00, 0x04,],).expect("Error writing",)
+    .write_ue(3,).expect("Error writing",);
+  assert_eq!(writer.to_write(), Some(Bits::B3),);
+  writer.rbsp_trailing_bits().expect("Error writing",)
+    .finish().expect("Error writing",);
+  let (writer, buffered,) = writer.into_inner().expect("Error unwrapping",);
+  assert!(buffered.is_empty(),);
+  assert_eq!(
+    writer.into_vec().expect("Error unwrapping",),
+    [0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x01, 0x00, 0x00, 0x03, 0x03, 0xFF, 0x00, 0x00, 0x04, 0x24,],
+  );
+
+  //A NAL unit ending in a `cabac_zero_word` gets a final `0x03`.
+  let mut writer = RbspWriter::new(WriteVec::new(),);
+  writer.rbsp_trailing_bits().expect("Error writing",)
+    .write_word(16, 0,).expect("Error writing",)
+    .finish().expect("Error writing",);
+  let (writer, _,) = writer.into_inner().expect("Error unwrapping",);
+  assert_eq!(writer.into_vec().expect("Error unwrapping",), [0x80, 0x00, 0x00, 0x03,],);
+
+  let mut writer = RbspWriter::new(WriteVec::new(),);
+  writer.write_word(60, 1,).expect("Error writing",);
+  assert!(writer.clone().into_inner().is_err(),);
+  writer.write_word(4, 0,).expect("Error writing",);
+  let (writer, buffered,) = writer.into_inner().expect("Error unwrapping",);
+  assert_eq!(buffered, [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,],);
+  assert!(writer.into_vec().expect("Error unwrapping",).is_empty(),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_JpegScanWriter() {
+  let mut writer = JpegScanWriter::new(WriteVec::new(),);
+  writer.write_byte(0xFF,).expect("Error writing",)
+    .write_word(4, 0b1010,).expect("Error writing",);
+  assert_eq!(writer.to_write(), Some(Bits::B4),);
+  writer.write_marker(0xD0,).expect("Error writing",)
+    .write_word(11, 0x091,).expect("Error writing",);
+  writer.pad_ones();
+  assert!(writer.is_aligned(),);
+  writer.write_marker(0xD9,).expect("Error writing",);
+  let (writer, buffered,) = writer.into_inner().expect("Error unwrapping",);
+  assert!(buffered.is_empty(),);
+  assert_eq!(writer.into_vec().expect("Error unwrapping",), [0xFF, 0x00, 0xAF, 0xFF, 0xD0, 0x12, 0x3F, 0xFF, 0xD9,],);
+
+  let mut writer = JpegScanWriter::new(WriteVec::new(),);
+  writer.write_word(64, !0,).expect("Error writing",)
+    .flush().expect("Error flushing",);
+  let (writer, _,) = writer.into_inner().expect("Error unwrapping",);
+  assert_eq!(writer.into_vec().expect("Error unwrapping",), [0xFF, 0x00,].repeat(8,),);
+}
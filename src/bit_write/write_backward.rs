@@ -0,0 +1,71 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// Writes a bitstream to be read backwards by a `ReadBackward`.
+/// 
+/// Bits are packed low bits first into little endian bytes, as in zstd, so the bits of a
+/// write are returned by a read of the same length once everything written after them
+/// has been read. Codes built from several writes are read in reverse and should be
+/// written in reverse.
+/// 
+/// When finished a single `1` bit is written after the data so that the reader can find
+/// where the data ends in the final byte.
+#[derive(PartialEq, Eq, Clone, Debug, Default,)]
+pub struct WriteBackward {
+  /// The whole bytes written.
+  bytes: Vec<u8>,
+  /// The bits waiting to be moved into `bytes`, stored in the low bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+}
+
+impl WriteBackward {
+  /// Constructs a new empty `WriteBackward`.
+  pub const fn new() -> Self { Self { bytes: Vec::new(), acc: 0, acc_bits: 0, } }
+  /// Returns the number of bits written.
+  #[inline]
+  pub fn len(&self,) -> usize { self.bytes.len() * 8 + self.acc_bits as usize }
+  /// Returns `true` if no bits have been written.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.len() == 0 }
+  /// Writes the sentinel bit and returns the bytes of the stream.
+  pub fn finish(mut self,) -> Vec<u8> {
+    self.write_word(1, 1,).ok();
+    if self.acc_bits > 0 { self.bytes.push(self.acc as u8,); }
+
+    self.bytes
+  }
+}
+
+impl BitWrite for WriteBackward {
+  type Error = Infallible;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.acc_bits == 0 }
+  #[inline]
+  fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, split the write so that the
+    //high bits are read first.
+    if bits > 56 {
+      return self.write_word(32, word,)?.write_word(bits - 32, word >> 32,)
+    }
+
+    self.acc |= (word & (1u64 << bits).wrapping_sub(1,)) << self.acc_bits;
+    self.acc_bits += bits;
+    while self.acc_bits >= 8 {
+      self.bytes.push(self.acc as u8,);
+      self.acc >>= 8;
+      self.acc_bits -= 8;
+    }
+
+    Ok(self)
+  }
+}
@@ -0,0 +1,133 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// Wraps a byte aligned writer and writes the entropy coded data of a JPEG scan bitwise,
+/// high bits first.
+/// 
+/// A `0x00` is stuffed after each `0xFF` data byte. `write_marker` pads the data with `1`
+/// bits and writes a marker, such as `RSTn` or `EOI`, unstuffed.
+/// 
+/// Bits are collected in a word and only written to the inner writer as whole bytes
+/// when the word is full or `flush` is called.
+#[derive(Clone, Copy, Debug,)]
+pub struct JpegScanWriter<W,>
+  where W: BitWrite, {
+  /// The byte aligned writer to write the stuffed bytes to.
+  writer: W,
+  /// `true` if a `0xFF` has been written without the `0x00` after it.
+  escape: bool,
+  /// The bits waiting to be written, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+}
+
+impl<W,> JpegScanWriter<W,>
+  where W: BitWrite, {
+  /// Constructs a new `JpegScanWriter` over the writer.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write to, it should be aligned.  
+  pub const fn new(writer: W,) -> Self { Self { writer, escape: false, acc: 0, acc_bits: 0, } }
+  /// Returns the number of bits left to write before the writer is byte aligned.
+  pub fn to_write(&self,) -> Option<Bits> { Bits::try_from((8 - self.acc_bits % 8) % 8,).ok() }
+  /// Pads the internal buffer with ones so that the writer is aligned.
+  pub fn pad_ones(&mut self,) -> &mut Self {
+    if let Some(bits) = self.to_write() {
+      self.acc |= ((1u64 << bits as u8) - 1) << (64 - self.acc_bits - bits as u8);
+      self.acc_bits += bits as u8;
+    }
+
+    self
+  }
+  /// Returns a reference to the inner writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &W { &self.writer }
+  /// Writes all of the whole bytes waiting in the accumulator to the inner writer.
+  /// 
+  /// If an error occurs the bytes which were not written are kept.
+  pub fn flush(&mut self,) -> Result<&mut Self, W::Error> {
+    loop {
+      //The `0x00` after a `0xFF` is written before anything else, even after an error.
+      if self.escape {
+        self.writer.write_byte(0x00,)?;
+        self.escape = false;
+      }
+      if self.acc_bits < 8 { break }
+
+      let byte = (self.acc >> 56) as u8;
+      self.writer.write_byte(byte,)?;
+      self.acc <<= 8;
+      self.acc_bits -= 8;
+      self.escape = byte == 0xFF;
+    }
+
+    Ok(self)
+  }
+  /// Pads the data with ones, writes all of it to the inner writer and writes a marker.
+  /// 
+  /// If an error is returned some of the marker may have been written.
+  /// 
+  /// # Params
+  /// 
+  /// code --- The code of the marker, `0xD0` to `0xD7` for `RSTn` and `0xD9` for `EOI`.  
+  pub fn write_marker(&mut self, code: u8,) -> Result<&mut Self, W::Error> {
+    self.pad_ones().flush()?;
+    self.writer.write_byte(0xFF,)?.write_byte(code,)?;
+
+    Ok(self)
+  }
+  /// Unwraps the inner writer if this writer is aligned.
+  /// 
+  /// Any whole bytes which have not been written to the inner writer are returned with
+  /// it unstuffed, call `flush` first to write them.
+  pub fn into_inner(self,) -> Result<(W, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_write() {
+      Some(misalign) => Err(UnalignedError(self, misalign,)),
+      None => {
+        let buffered = self.acc.to_be_bytes()[..self.acc_bits as usize / 8].to_vec();
+
+        Ok((self.writer, buffered,))
+      },
+    }
+  }
+}
+
+impl<W,> BitWrite for JpegScanWriter<W,>
+  where W: BitWrite, {
+  type Error = W::Error;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.to_write().is_none() }
+  #[inline]
+  fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, split the write.
+    if bits > 56 {
+      return self.write_word(bits - 32, word >> 32,)?.write_word(32, word,)
+    }
+    if bits == 0 { return Ok(self) }
+
+    //Make space in the accumulator, nothing is written if this fails.
+    if self.acc_bits + bits > 64 { self.flush()?; }
+
+    //Write the bits below the bits already in the accumulator.
+    let word = word & (!0u64 >> (64 - bits));
+    self.acc |= word << (64 - self.acc_bits - bits);
+    self.acc_bits += bits;
+
+    Ok(self)
+  }
+}
+
+impl<W,> OrderedWrite for JpegScanWriter<W,>
+  where W: BitWrite, {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
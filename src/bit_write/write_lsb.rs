@@ -0,0 +1,124 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// Wraps a byte aligned writer and writes to it bitwise, low bits first.
+/// 
+/// This is the bit order of DEFLATE: the first bit written is the lowest bit of the
+/// first byte and the bits of a word are written starting from its lowest bit.
+/// 
+/// Bits are collected in a word and only written to the inner writer as whole bytes
+/// when the word is full or `flush` is called.
+#[derive(Clone, Copy, Debug,)]
+pub struct WriteLsb<W,>
+  where W: BitWrite, {
+  /// The byte aligned writer to write bytes to.
+  writer: W,
+  /// The bits waiting to be written, stored in the low bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+}
+
+impl<W,> WriteLsb<W,>
+  where W: BitWrite, {
+  /// Constructs a new `WriteLsb` over the writer.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write to, it should be aligned.  
+  pub const fn new(writer: W,) -> Self { Self { writer, acc: 0, acc_bits: 0, } }
+  /// Returns the number of bits left to write before the writer is byte aligned.
+  pub fn to_write(&self,) -> Option<Bits> { Bits::try_from((8 - self.acc_bits % 8) % 8,).ok() }
+  /// Pads the internal buffer with zeros so that the writer is aligned.
+  pub fn pad_zeros(&mut self,) -> &mut Self {
+    if let Some(bits) = self.to_write() {
+      self.acc_bits += bits as u8;
+    }
+
+    self
+  }
+  /// Returns a reference to the inner writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &W { &self.writer }
+  /// Writes all of the whole bytes waiting in the accumulator to the inner writer.
+  /// 
+  /// If an error occurs the bytes which were not written are kept.
+  pub fn flush(&mut self,) -> Result<&mut Self, W::Error> {
+    let bytes = self.acc_bits / 8;
+    let res = self.writer.write_bytes(&self.acc.to_le_bytes()[..bytes as usize],);
+    let (written, res,) = match res {
+      Ok(_) => (bytes, Ok(()),),
+      Err((written, e,)) => (written as u8, Err(e),),
+    };
+
+    self.acc = self.acc.checked_shr(written as u32 * 8,).unwrap_or(0,);
+    self.acc_bits -= written * 8;
+
+    res.map(move |_,| self,)
+  }
+  /// Unwraps the inner writer if this writer is aligned.
+  /// 
+  /// Any whole bytes which have not been written to the inner writer are returned with
+  /// it, call `flush` first to write them.
+  pub fn into_inner(self,) -> Result<(W, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_write() {
+      Some(misalign) => Err(UnalignedError(self, misalign,)),
+      None => {
+        let buffered = self.acc.to_le_bytes()[..self.acc_bits as usize / 8].to_vec();
+
+        Ok((self.writer, buffered,))
+      },
+    }
+  }
+}
+
+impl<W,> BitWrite for WriteLsb<W,>
+  where W: BitWrite, {
+  type Error = W::Error;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.to_write().is_none() }
+  #[inline]
+  fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, split the write.
+    if bits > 56 {
+      return self.write_word(32, word,)?.write_word(bits - 32, word >> 32,)
+    }
+    if bits == 0 { return Ok(self) }
+
+    //Make space in the accumulator, nothing is written if this fails.
+    if self.acc_bits + bits > 64 { self.flush()?; }
+
+    //Write the bits above the bits already in the accumulator.
+    let word = word & (!0u64 >> (64 - bits));
+    self.acc |= word << self.acc_bits;
+    self.acc_bits += bits;
+
+    Ok(self)
+  }
+  fn write_bytes(&mut self, bytes: &[u8],) -> Result<&mut Self, (usize, Self::Error,)> {
+    if !self.is_aligned() {
+      for (written, &byte,) in bytes.iter().enumerate() {
+        self.write_byte(byte,).map_err(move |e,| (written, e,),)?;
+      }
+
+      return Ok(self)
+    }
+
+    //Write the waiting bytes first to keep the byte order.
+    self.flush().map_err(|e,| (0, e,),)?;
+    self.writer.write_bytes(bytes,)?; Ok(self)
+  }
+}
+
+impl<W,> OrderedWrite for WriteLsb<W,>
+  where W: BitWrite, {
+  const ORDER: BitOrder = BitOrder::Lsb;
+}
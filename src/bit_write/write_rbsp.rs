@@ -0,0 +1,129 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+
+/// Wraps a byte aligned writer and writes the RBSP of a H.264 or HEVC NAL unit bitwise,
+/// high bits first.
+/// 
+/// A `0x03` emulation prevention byte is inserted wherever two zero bytes are followed
+/// by a byte of at most `0x03`.
+/// 
+/// Bits are collected in a word and only written to the inner writer as whole bytes
+/// when the word is full or `flush` is called.
+#[derive(Clone, Copy, Debug,)]
+pub struct RbspWriter<W,>
+  where W: BitWrite, {
+  /// The byte aligned writer to write the escaped bytes to.
+  writer: W,
+  /// The number of zero bytes written in a row.
+  zeros: u8,
+  /// The bits waiting to be written, stored in the high bits.
+  acc: u64,
+  /// The number of bits stored in `acc`.
+  acc_bits: u8,
+}
+
+impl<W,> RbspWriter<W,>
+  where W: BitWrite, {
+  /// Constructs a new `RbspWriter` over the writer.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write to, it should be aligned.  
+  pub const fn new(writer: W,) -> Self { Self { writer, zeros: 0, acc: 0, acc_bits: 0, } }
+  /// Returns the number of bits left to write before the writer is byte aligned.
+  pub fn to_write(&self,) -> Option<Bits> { Bits::try_from((8 - self.acc_bits % 8) % 8,).ok() }
+  /// Returns a reference to the inner writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &W { &self.writer }
+  /// Writes the RBSP trailing bits, a `1` bit then `0` bits until this writer is aligned.
+  pub fn rbsp_trailing_bits(&mut self,) -> Result<&mut Self, W::Error> {
+    self.write_bit(true,)?;
+
+    match self.to_write() {
+      Some(bits) => self.write_word(bits as u8, 0,),
+      None => Ok(self),
+    }
+  }
+  /// Writes all of the whole bytes waiting in the accumulator to the inner writer.
+  /// 
+  /// If an error occurs the bytes which were not written are kept.
+  pub fn flush(&mut self,) -> Result<&mut Self, W::Error> {
+    while self.acc_bits >= 8 {
+      let byte = (self.acc >> 56) as u8;
+      if self.zeros >= 2 && byte <= 0x03 {
+        self.writer.write_byte(0x03,)?;
+        self.zeros = 0;
+      }
+
+      self.writer.write_byte(byte,)?;
+      self.zeros = if byte == 0 { self.zeros.saturating_add(1,) } else { 0 };
+      self.acc <<= 8;
+      self.acc_bits -= 8;
+    }
+
+    Ok(self)
+  }
+  /// Writes all of the whole bytes waiting in the accumulator and, if this writer is
+  /// aligned and the NAL unit ends with a zero byte, the final `0x03`.
+  pub fn finish(&mut self,) -> Result<&mut Self, W::Error> {
+    self.flush()?;
+    if self.acc_bits == 0 && self.zeros > 0 {
+      self.writer.write_byte(0x03,)?;
+      self.zeros = 0;
+    }
+
+    Ok(self)
+  }
+  /// Unwraps the inner writer if this writer is aligned.
+  /// 
+  /// Any whole bytes which have not been written to the inner writer are returned with
+  /// it without emulation prevention, call `finish` first to write them.
+  pub fn into_inner(self,) -> Result<(W, Vec<u8>,), UnalignedError<Self,>> {
+    match self.to_write() {
+      Some(misalign) => Err(UnalignedError(self, misalign,)),
+      None => {
+        let buffered = self.acc.to_be_bytes()[..self.acc_bits as usize / 8].to_vec();
+
+        Ok((self.writer, buffered,))
+      },
+    }
+  }
+}
+
+impl<W,> BitWrite for RbspWriter<W,>
+  where W: BitWrite, {
+  type Error = W::Error;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.to_write().is_none() }
+  #[inline]
+  fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> {
+    self.write_word(bits as u8, buf as u64,)?; Ok(bits)
+  }
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    assert!(bits <= 64, "Cannot write more than 64 bits at once",);
+
+    //The accumulator cannot always hold more than 56 bits, split the write.
+    if bits > 56 {
+      return self.write_word(bits - 32, word >> 32,)?.write_word(32, word,)
+    }
+    if bits == 0 { return Ok(self) }
+
+    //Make space in the accumulator, nothing is written if this fails.
+    if self.acc_bits + bits > 64 { self.flush()?; }
+
+    //Write the bits below the bits already in the accumulator.
+    let word = word & (!0u64 >> (64 - bits));
+    self.acc |= word << (64 - self.acc_bits - bits);
+    self.acc_bits += bits;
+
+    Ok(self)
+  }
+}
+
+impl<W,> OrderedWrite for RbspWriter<W,>
+  where W: BitWrite, {
+  const ORDER: BitOrder = BitOrder::Msb;
+}
@@ -1,7 +1,7 @@
 //! Defines types useful for using individual bits from a byte.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-30
+//! Last Moddified --- 2026-10-18
 
 use core::{
   fmt,
@@ -72,6 +72,10 @@ impl Bits {
     Self::try_from(8 - byte.leading_zeros() as u8,).ok()
   }
   /// Converts a `u8` into a `Bits` value.
+  /// 
+  /// # Safety
+  /// 
+  /// `byte` must be in the range `1..=8`.  
   #[inline]
   pub const unsafe fn from_u8(byte: u8,) -> Self { core::mem::transmute(byte,) }
   /// Creates a bit mask which covers exactly this bit.
@@ -118,17 +122,17 @@ impl Deref for Bits {
 
 impl AsRef<NonZeroU8> for Bits {
   #[inline]
-  fn as_ref(&self,) -> &NonZeroU8 { &*self }
+  fn as_ref(&self,) -> &NonZeroU8 { self }
 }
 
 impl Borrow<NonZeroU8> for Bits {
   #[inline]
-  fn borrow(&self,) -> &NonZeroU8 { &*self }
+  fn borrow(&self,) -> &NonZeroU8 { self }
 }
 
-impl Into<NonZeroU8> for Bits {
+impl From<Bits> for NonZeroU8 {
   #[inline]
-  fn into(self,) -> NonZeroU8 { unsafe { core::mem::transmute(self,) } }
+  fn from(from: Bits,) -> Self { unsafe { core::mem::transmute(from,) } }
 }
 
 impl TryFrom<u8> for Bits {
@@ -154,6 +158,7 @@ impl fmt::Debug for Bits {
 #[derive(PartialEq, Eq, Clone, Copy, Debug,)]
 pub struct FromU8Error(pub(crate) (),);
 
+#[cfg(feature = "nightly",)]
 impl From<!> for FromU8Error {
   #[inline]
   fn from(_: !,) -> Self { FromU8Error((),) }
@@ -1,7 +1,7 @@
 //! Defines iterators for `Bits`.
 //! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-30
+//! Last Moddified --- 2026-10-18
 
 use super::*;
 use core::iter::{Iterator, ExactSizeIterator,};
@@ -38,9 +38,9 @@ impl ExactSizeIterator for Cycle {
   fn len(&self,) -> usize { (8 - Bits::as_u8(self.0,)) as usize }
 }
 
-impl Into<Option<Bits>> for Cycle {
+impl From<Cycle> for Option<Bits> {
   #[inline]
-  fn into(self,) -> Option<Bits> { self.0 }
+  fn from(from: Cycle,) -> Self { from.0 }
 }
 
 /// An iterator which cycles through all of the bits from `B8` too `B1`.
@@ -76,7 +76,7 @@ impl ExactSizeIterator for RevCycle {
   fn len(&self,) -> usize { Bits::as_u8(self.0,) as usize }
 }
 
-impl Into<Option<Bits>> for RevCycle {
+impl From<RevCycle> for Option<Bits> {
   #[inline]
-  fn into(self,) -> Option<Bits> { self.0 }
+  fn from(from: RevCycle,) -> Self { from.0 }
 }
@@ -0,0 +1,406 @@
+//! Decodes bzip2 streams.
+//! 
+//! bzip2 is read high bits first so the decoder reads from any `BitPeek` reader such as
+//! `ReadIter`, with `--features std` it also implements `std::io::Read` over a `ReadIO`.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitPeek, ReadIter, Bits, CodeError, HuffmanDecoder, Crc32Msb,};
+use alloc::{vec, vec::Vec,};
+use core::{fmt, iter::Copied, slice::Iter,};
+
+mod tests;
+#[cfg(feature = "std",)]
+mod stream;
+
+/// The magic number which starts a block.
+const BLOCK_MAGIC: u64 = 0x3141_5926_5359;
+/// The magic number which ends a stream.
+const END_MAGIC: u64 = 0x1772_4538_5090;
+/// The number of symbols coded with each selected table.
+const GROUP_SIZE: usize = 50;
+/// The longest code length.
+const MAX_CODE_LEN: u32 = 20;
+/// The most selectors a block can use, any more are ignored as bzip2 does.
+const MAX_SELECTORS: usize = 2 + 900_000 / GROUP_SIZE;
+
+/// The error returned when decoding a bzip2 stream fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum Bzip2Error<E,> {
+  /// Reading from the input failed.
+  Read(E),
+  /// The stream header or a block magic number is invalid.
+  Header,
+  /// A block used the deprecated randomised mode, which is not supported.
+  Randomised,
+  /// The code tables or selectors of a block are invalid.
+  Table,
+  /// The compressed data contained an invalid code.
+  Code,
+  /// A block was larger than the stream allows or its origin pointer was out of range.
+  Block,
+  /// The checksum of a block or stream did not match the output.
+  Checksum,
+}
+
+impl<E,> Bzip2Error<E,> {
+  /// Converts an error decoding a symbol of the compressed data.
+  fn from_code(from: CodeError<E,>,) -> Self {
+    match from {
+      CodeError::Read(e,) => Bzip2Error::Read(e,),
+      _ => Bzip2Error::Code,
+    }
+  }
+}
+
+impl<E,> From<E> for Bzip2Error<E,> {
+  #[inline]
+  fn from(from: E,) -> Self { Bzip2Error::Read(from,) }
+}
+
+impl<E,> fmt::Display for Bzip2Error<E,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.write_str(match self {
+      Bzip2Error::Read(_,) => "reading the compressed input failed",
+      Bzip2Error::Header => "invalid stream header or block magic",
+      Bzip2Error::Randomised => "randomised blocks are not supported",
+      Bzip2Error::Table => "invalid code tables or selectors",
+      Bzip2Error::Code => "invalid code in compressed data",
+      Bzip2Error::Block => "invalid block size or origin pointer",
+      Bzip2Error::Checksum => "checksum mismatch",
+    },)
+  }
+}
+
+/// The error type of the decoder reading from `R`.
+pub type Bzip2ErrorOf<R,> = Bzip2Error<<R as BitRead>::Error,>;
+
+/// The part of the stream being decoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum State {
+  /// The next bytes are a stream header.
+  Header,
+  /// The next bits are a block or the end of the stream.
+  Block,
+  /// Outputting the current block.
+  Output,
+  /// Every stream has ended.
+  Done,
+}
+
+/// Decodes a bzip2 stream, checking the CRC of every block and stream.
+/// 
+/// Blocks are decoded whole, using up to `3.6MB` for the largest block size, and output
+/// on demand. Concatenated streams are decoded as one stream.
+/// 
+/// After an error the decoder should not be used.
+#[derive(Clone, Debug,)]
+pub struct Bzip2Decoder<R,>
+  where R: BitPeek, {
+  /// The reader to decode from.
+  reader: R,
+  /// The part of the stream being decoded.
+  state: State,
+  /// The largest block of the stream.
+  block_size: usize,
+  /// The inverse BWT of the current block, each entry holds a byte in the low bits and
+  /// the index of the next entry above them.
+  tt: Vec<u32>,
+  /// The index of the next byte of the block in `tt`.
+  pos: u32,
+  /// The number of bytes of the block left in `tt`.
+  remaining: usize,
+  /// The last byte output, `256` at the start of a block.
+  prev: u16,
+  /// The number of times `prev` has been output in a row.
+  run: u8,
+  /// The number of copies of `prev` left to output.
+  repeat: u8,
+  /// The checksum of the output of the current block.
+  block_crc: Crc32Msb,
+  /// The checksum of the current block from its header.
+  expected_crc: u32,
+  /// The combined checksum of the blocks of the current stream.
+  stream_crc: u32,
+  /// The number of bytes output.
+  total: u64,
+}
+
+impl<R,> Bzip2Decoder<R,>
+  where R: BitPeek, {
+  /// Constructs a new `Bzip2Decoder` which decodes from `reader`.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from, it should be aligned.  
+  pub fn new(reader: R,) -> Self {
+    Self {
+      reader,
+      state: State::Header,
+      block_size: 0,
+      tt: Vec::new(),
+      pos: 0,
+      remaining: 0,
+      prev: 256,
+      run: 0,
+      repeat: 0,
+      block_crc: Crc32Msb::new(),
+      expected_crc: 0,
+      stream_crc: 0,
+      total: 0,
+    }
+  }
+  /// Returns `true` if every stream has been decoded.
+  #[inline]
+  pub fn is_done(&self,) -> bool { self.state == State::Done }
+  /// Returns the number of bytes decoded so far.
+  #[inline]
+  pub const fn total_out(&self,) -> u64 { self.total }
+  /// Returns a reference to the reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the reader.
+  /// 
+  /// Once the stream is done the reader is positioned just after the last stream.
+  #[inline]
+  pub fn into_inner(self,) -> R { self.reader }
+  /// Decodes bytes into `out`.
+  /// 
+  /// Returns the number of bytes decoded which is only `0` once the stream is done or
+  /// `out` is empty.
+  /// 
+  /// # Params
+  /// 
+  /// out --- The buffer to decode into.  
+  pub fn read(&mut self, out: &mut [u8],) -> Result<usize, Bzip2ErrorOf<R,>> {
+    let mut written = 0;
+    while written < out.len() {
+      match self.state {
+        State::Header => self.read_header()?,
+        State::Block => self.read_block()?,
+        State::Output => {
+          written += self.output(&mut out[written..],);
+          if self.remaining > 0 || self.repeat > 0 { continue }
+
+          if self.block_crc.value() != self.expected_crc { return Err(Bzip2Error::Checksum) }
+          self.stream_crc = self.stream_crc.rotate_left(1,) ^ self.expected_crc;
+          self.state = State::Block;
+        },
+        State::Done => break,
+      }
+    }
+
+    Ok(written)
+  }
+  /// Outputs bytes of the current block, undoing the initial run length encoding.
+  /// 
+  /// Returns the number of bytes output.
+  fn output(&mut self, out: &mut [u8],) -> usize {
+    let mut written = 0;
+    while written < out.len() {
+      if self.repeat > 0 {
+        out[written] = self.prev as u8;
+        written += 1;
+        self.repeat -= 1;
+        continue
+      }
+      if self.remaining == 0 { break }
+
+      let entry = self.tt[self.pos as usize];
+      let byte = entry as u8;
+      self.pos = entry >> 8;
+      self.remaining -= 1;
+
+      //Four equal bytes are followed by the number of extra copies.
+      if self.run == 4 {
+        self.repeat = byte;
+        self.run = 0;
+        continue
+      }
+      if byte as u16 == self.prev { self.run += 1; } else { self.prev = byte as u16; self.run = 1; }
+
+      out[written] = byte;
+      written += 1;
+    }
+
+    self.block_crc.update(&out[..written],);
+    self.total += written as u64;
+
+    written
+  }
+  /// Reads and checks a stream header.
+  fn read_header(&mut self,) -> Result<(), Bzip2ErrorOf<R,>> {
+    let magic = self.reader.read_word(24,)?;
+    let level = self.reader.read_byte()?;
+    if magic != 0x42_5A_68 || !(b'1'..=b'9').contains(&level) { return Err(Bzip2Error::Header) }
+
+    self.block_size = (level - b'0') as usize * 100_000;
+    self.stream_crc = 0;
+    self.state = State::Block;
+
+    Ok(())
+  }
+  /// Reads the next block or the end of the stream.
+  fn read_block(&mut self,) -> Result<(), Bzip2ErrorOf<R,>> {
+    let magic = self.reader.read_word(48,)?;
+    let crc = self.reader.read_word(32,)? as u32;
+    if magic == END_MAGIC {
+      if crc != self.stream_crc { return Err(Bzip2Error::Checksum) }
+
+      //The stream is padded to a byte and another stream may follow.
+      while !self.reader.is_aligned() { self.reader.read_bit()?; }
+      self.state = if self.reader.peek_word(8,).1 == 0 { State::Done } else { State::Header };
+
+      return Ok(())
+    }
+    if magic != BLOCK_MAGIC { return Err(Bzip2Error::Header) }
+    if self.reader.read_bit()? { return Err(Bzip2Error::Randomised) }
+    let origin = self.reader.read_word(24,)? as usize;
+
+    //The bytes used in the block.
+    let ranges = self.reader.read_word(16,)?;
+    let mut used = Vec::with_capacity(256,);
+    for range in (0..16).filter(|range,| ranges & (0x8000 >> range) != 0,) {
+      let bytes = self.reader.read_word(16,)?;
+
+      used.extend((0..16).filter(|byte,| bytes & (0x8000 >> byte) != 0,).map(|byte,| (range * 16 + byte) as u8,),);
+    }
+    if used.is_empty() { return Err(Bzip2Error::Table) }
+
+    let groups = self.reader.read_word(3,)? as usize;
+    let selector_count = self.reader.read_word(15,)? as usize;
+    if !(2..=6).contains(&groups) || selector_count == 0 { return Err(Bzip2Error::Table) }
+
+    //The selectors are move to front coded in unary.
+    let mut tables = [0, 1, 2, 3, 4, 5,];
+    let mut selectors = Vec::with_capacity(selector_count.min(MAX_SELECTORS,),);
+    for _ in 0..selector_count {
+      let index = self.reader.read_truncated_unary(groups as u64, false,)? as usize;
+      if index >= groups { return Err(Bzip2Error::Table) }
+
+      let table = tables[index];
+      tables.copy_within(..index, 1,);
+      tables[0] = table;
+      if selectors.len() < MAX_SELECTORS { selectors.push(table,); }
+    }
+
+    //The code lengths are delta coded.
+    let alphabet = used.len() + 2;
+    let mut decoders = Vec::with_capacity(groups,);
+    for _ in 0..groups {
+      let mut lengths = vec![0; alphabet];
+      let mut len = self.reader.read_word(5,)? as u32;
+      for length in &mut lengths {
+        loop {
+          if !(1..=MAX_CODE_LEN).contains(&len) { return Err(Bzip2Error::Table) }
+          if !self.reader.read_bit()? { break }
+
+          if self.reader.read_bit()? { len -= 1; } else { len += 1; }
+        }
+
+        *length = len as u8;
+      }
+
+      decoders.push(HuffmanDecoder::new_incomplete(&lengths,).map_err(|_,| Bzip2Error::Table,)?,);
+    }
+
+    self.read_symbols(&used, &selectors, &decoders,)?;
+    if origin >= self.tt.len() { return Err(Bzip2Error::Block) }
+
+    //Link each byte to the byte after it in the original order.
+    let mut starts = [0u32; 256];
+    for &entry in &self.tt { starts[entry as u8 as usize] += 1; }
+    let mut sum = 0;
+    for start in &mut starts {
+      let count = *start;
+
+      *start = sum;
+      sum += count;
+    }
+    for index in 0..self.tt.len() {
+      let byte = self.tt[index] as u8 as usize;
+
+      self.tt[starts[byte] as usize] |= (index as u32) << 8;
+      starts[byte] += 1;
+    }
+
+    self.pos = self.tt[origin] >> 8;
+    self.remaining = self.tt.len();
+    self.prev = 256;
+    self.run = 0;
+    self.repeat = 0;
+    self.block_crc = Crc32Msb::new();
+    self.expected_crc = crc;
+    self.state = State::Output;
+
+    Ok(())
+  }
+  /// Decodes the symbols of a block into `tt`, undoing the move to front and zero run
+  /// length encoding.
+  fn read_symbols(&mut self, used: &[u8], selectors: &[usize], decoders: &[HuffmanDecoder],) -> Result<(), Bzip2ErrorOf<R,>> {
+    let end = used.len() as u16 + 1;
+    let mut mtf = (0..used.len()).map(|i,| i as u8,).collect::<Vec<_>>();
+    //The run of the front byte being decoded and the value of its next digit.
+    let (mut run, mut digit,) = (0usize, 1usize,);
+
+    self.tt.clear();
+    for index in 0.. {
+      let selector = *selectors.get(index / GROUP_SIZE,).ok_or(Bzip2Error::Table,)?;
+      let symbol = decoders[selector].decode(&mut self.reader,).map_err(Bzip2Error::from_code,)?;
+
+      //Runs of the front byte are written in bijective base 2 with `RUNA` and `RUNB`.
+      if symbol <= 1 {
+        run += digit << symbol;
+        digit <<= 1;
+        if run > self.block_size { return Err(Bzip2Error::Block) }
+        continue
+      }
+      if run > 0 {
+        if self.tt.len() + run > self.block_size { return Err(Bzip2Error::Block) }
+
+        let byte = used[mtf[0] as usize] as u32;
+        self.tt.extend((0..run).map(|_,| byte,),);
+        run = 0;
+        digit = 1;
+      }
+      if symbol == end { break }
+      if symbol > end { return Err(Bzip2Error::Code) }
+
+      let index = symbol as usize - 1;
+      let front = mtf[index];
+      mtf.copy_within(..index, 1,);
+      mtf[0] = front;
+
+      if self.tt.len() >= self.block_size { return Err(Bzip2Error::Block) }
+      self.tt.push(used[front as usize] as u32,);
+    }
+
+    Ok(())
+  }
+}
+
+/// A reader over a slice of bytes.
+type SliceReader<'a,> = ReadIter<Copied<Iter<'a, u8,>,>,>;
+
+/// Decodes a bzip2 stream held in memory.
+/// 
+/// # Params
+/// 
+/// bytes --- The compressed bytes.  
+pub fn bzip2_decompress(bytes: &[u8],) -> Result<Vec<u8>, Bzip2Error<Option<Bits>,>> {
+  let mut decoder = Bzip2Decoder::new(SliceReader::new(bytes.iter().copied(),),);
+  let mut out = Vec::new();
+  let mut len = 0;
+  loop {
+    if out.len() - len < 1 << 16 { out.resize(len + (1 << 16), 0,); }
+
+    match decoder.read(&mut out[len..],)? {
+      0 => break,
+      read => len += read,
+    }
+  }
+  out.truncate(len,);
+
+  Ok(out)
+}
@@ -0,0 +1,33 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use crate::ReadIO;
+use std::io::{self, Read, ErrorKind,};
+
+/// The error of a `ReadIO`.
+type IOError = (Option<Bits>, io::Error,);
+
+/// Converts an error decoding from a `ReadIO` into an IO error.
+fn into_io(error: Bzip2Error<IOError,>,) -> io::Error {
+  match error {
+    Bzip2Error::Read((_, e,),) => e,
+    e => io::Error::new(ErrorKind::InvalidData, e.to_string(),),
+  }
+}
+
+impl<R,> Bzip2Decoder<ReadIO<R,>,>
+  where R: Read, {
+  /// Constructs a new `Bzip2Decoder` which decodes from an IO reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn from_reader(reader: R,) -> Self { Self::new(ReadIO::new(reader,),) }
+}
+
+impl<R,> Read for Bzip2Decoder<R,>
+  where R: BitPeek<Error = IOError,>, {
+  #[inline]
+  fn read(&mut self, buf: &mut [u8],) -> io::Result<usize> { Bzip2Decoder::read(self, buf,).map_err(into_io,) }
+}
@@ -0,0 +1,227 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::test_data::{text, random,};
+
+/// Returns runs of every length which the initial run length encoding splits.
+fn runs() -> Vec<u8> {
+  (0..600usize).step_by(7,).flat_map(|i,| core::iter::repeat_n(i as u8, i % 300 + 1,),).collect()
+}
+
+/// An empty stream.
+const EMPTY: [u8; 14] = [0x42, 0x5A, 0x68, 0x39, 0x17, 0x72, 0x45, 0x38, 0x50, 0x90, 0x00, 0x00, 0x00, 0x00,];
+/// The compressed text is `text(400)`.
+const TEXT: [u8; 278] = [
+  0x42, 0x5A, 0x68, 0x39, 0x31, 0x41, 0x59, 0x26, 0x53, 0x59, 0xAF, 0xC0, 0xAE, 0x12, 0x00, 0x02, 0x72, 0xD1, 0x80, 0x00, 0x10, 0x40, 0x00, 0x36,
+  0xE7, 0xCC, 0x10, 0x40, 0x02, 0x50, 0x54, 0xD8, 0x39, 0x86, 0x9E, 0xD5, 0x55, 0x1A, 0x00, 0x01, 0x27, 0xAA, 0x55, 0x01, 0xA0, 0x01, 0x0A, 0xA0,
+  0x00, 0x00, 0xA5, 0x25, 0x00, 0x34, 0xD3, 0xD4, 0xEA, 0xDB, 0x7C, 0xAD, 0xB2, 0x5B, 0x30, 0x8A, 0x4A, 0xC1, 0xB1, 0xA4, 0x91, 0x2C, 0x12, 0x52,
+  0x6C, 0x58, 0xA3, 0x21, 0x64, 0xD1, 0x11, 0x8D, 0x14, 0x25, 0xA3, 0x6A, 0x23, 0x11, 0x46, 0xD8, 0xB4, 0x54, 0x6C, 0x51, 0x18, 0xC5, 0x41, 0x05,
+  0x91, 0x0C, 0x59, 0x4D, 0x1B, 0xF6, 0xB6, 0xDE, 0x25, 0x9F, 0x5C, 0x73, 0x98, 0x87, 0x4B, 0x93, 0x95, 0xD7, 0x4E, 0x13, 0x12, 0xC3, 0x4C, 0x76,
+  0x99, 0x13, 0x55, 0x4B, 0x59, 0x92, 0xE9, 0xD7, 0x23, 0x87, 0x76, 0x81, 0x0E, 0x24, 0x2E, 0x77, 0x67, 0x34, 0x85, 0x2E, 0x4D, 0x46, 0xCE, 0xAD,
+  0x27, 0x5D, 0x93, 0x35, 0xAD, 0xA9, 0xD3, 0x16, 0x55, 0x9C, 0x91, 0x54, 0xA6, 0x46, 0xAA, 0x1C, 0x58, 0xD7, 0x53, 0x13, 0x61, 0x68, 0xE8, 0x40,
+  0x90, 0x4D, 0x20, 0xD2, 0xCC, 0xF0, 0xAA, 0x10, 0x38, 0x15, 0x82, 0xA3, 0x16, 0x2D, 0x04, 0x86, 0xA3, 0x15, 0x14, 0x46, 0x35, 0x1A, 0x31, 0xBC,
+  0x56, 0xD9, 0xAE, 0x6C, 0x52, 0x54, 0x41, 0x51, 0x8B, 0x49, 0xB5, 0xE2, 0xFE, 0x24, 0x99, 0x0C, 0xD2, 0x44, 0x0C, 0x8C, 0x32, 0x25, 0x34, 0x98,
+  0x44, 0x83, 0x32, 0x1A, 0x08, 0x09, 0x23, 0x37, 0x57, 0x90, 0xC5, 0x8B, 0x44, 0x45, 0x8B, 0x1A, 0x66, 0x59, 0x83, 0x32, 0x7E, 0x11, 0xB5, 0x0E,
+  0x54, 0x38, 0x11, 0xC9, 0x57, 0xCA, 0xA3, 0x55, 0x46, 0xD4, 0x36, 0xA8, 0x75, 0x50, 0xF0, 0xA1, 0xA2, 0xAD, 0x52, 0x2E, 0xC8, 0xEF, 0x23, 0x45,
+  0x05, 0x02, 0x51, 0xF8, 0xBB, 0x92, 0x29, 0xC2, 0x84, 0x85, 0x7E, 0x05, 0x70, 0x90,
+];
+/// The compressed text is `abcdefghij` repeated `25000` times, split into three blocks.
+const BLOCKS: [u8; 187] = [
+  0x42, 0x5A, 0x68, 0x31, 0x31, 0x41, 0x59, 0x26, 0x53, 0x59, 0x1E, 0xD8, 0x63, 0xB5, 0x00, 0x13, 0x87, 0x01, 0x00, 0x3F, 0xF0, 0x20, 0x00, 0x70,
+  0x40, 0x0C, 0x02, 0x95, 0x43, 0x09, 0x9C, 0x54, 0x15, 0x1E, 0x55, 0x05, 0x47, 0xAA, 0x82, 0xA3, 0xE5, 0x41, 0x51, 0x85, 0x41, 0x51, 0x95, 0x41,
+  0x51, 0xA5, 0x41, 0x51, 0xB5, 0x41, 0x51, 0xFA, 0xA0, 0xA8, 0xE2, 0xA0, 0xA8, 0xE9, 0x8A, 0x0A, 0xC9, 0x32, 0x9A, 0xCB, 0x7D, 0x86, 0xBD, 0xC0,
+  0x01, 0x38, 0x70, 0x08, 0x01, 0xFF, 0x81, 0x00, 0x03, 0x82, 0x00, 0x60, 0x14, 0xAA, 0x18, 0x4C, 0xE2, 0xA0, 0xA8, 0xF1, 0x50, 0x54, 0x7B, 0x54,
+  0x15, 0x1F, 0x2A, 0x0A, 0x8C, 0x2A, 0x0A, 0x8C, 0xAA, 0x0A, 0x8D, 0x2A, 0x0A, 0x8D, 0xAA, 0x0A, 0x8F, 0xD5, 0x05, 0x47, 0x15, 0x05, 0x47, 0x4C,
+  0x50, 0x56, 0x49, 0x94, 0xD6, 0x7B, 0x22, 0x9F, 0xFF, 0x00, 0x04, 0xE2, 0xC0, 0x40, 0x0F, 0xFC, 0x08, 0x00, 0x1A, 0x82, 0x95, 0x09, 0xA1, 0x81,
+  0x4A, 0xA1, 0x82, 0x6E, 0x52, 0x15, 0x18, 0xA4, 0x2A, 0x31, 0x9A, 0x42, 0xA3, 0x45, 0x0A, 0x8C, 0x14, 0x2A, 0x32, 0x50, 0xA8, 0xD1, 0x42, 0xA3,
+  0x65, 0x0A, 0x8F, 0xCA, 0x15, 0x1C, 0x28, 0x54, 0x74, 0x5D, 0xC9, 0x14, 0xE1, 0x42, 0x41, 0x22, 0x29, 0x79, 0x60,
+];
+/// The compressed text is `runs()`.
+const RUNS: [u8; 456] = [
+  0x42, 0x5A, 0x68, 0x39, 0x31, 0x41, 0x59, 0x26, 0x53, 0x59, 0xC9, 0xDB, 0x70, 0x29, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+  0xF3, 0xE7, 0xCF, 0x9F, 0x3E, 0x7C, 0xE9, 0xD3, 0xA7, 0x4E, 0x9D, 0x3A, 0x74, 0xE9, 0xD3, 0xA7, 0x4E, 0x9D, 0x3A, 0x74, 0xE9, 0xD3, 0xA7, 0x4E,
+  0x9D, 0x3A, 0x7C, 0xB0, 0x01, 0x10, 0x82, 0x1F, 0xFA, 0x55, 0x00, 0x00, 0x00, 0x01, 0xFE, 0xAA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x34, 0x0D, 0x06, 0x83, 0x41, 0xA3, 0x4D, 0x34, 0x64, 0xC8, 0x34, 0x68, 0xC9, 0x82, 0x68, 0x87, 0xFE, 0xA2, 0x28, 0x3F, 0x54, 0x68,
+  0x00, 0x00, 0x00, 0x0D, 0x1E, 0x82, 0x68, 0x1A, 0x7A, 0x99, 0x32, 0x68, 0x34, 0xD1, 0xA6, 0x83, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x06, 0x82, 0x06, 0x46, 0x8D, 0x06, 0x81, 0x90, 0xD3, 0x4C, 0x9A, 0x06, 0x4C, 0x26, 0x83, 0x13, 0x11, 0x93, 0x46, 0x81, 0x90, 0xC4,
+  0x60, 0x26, 0x8C, 0x40, 0x03, 0x46, 0x08, 0x68, 0x61, 0x18, 0x99, 0x32, 0x69, 0x91, 0x91, 0x89, 0xA6, 0x83, 0x23, 0x02, 0x64, 0x69, 0xE8, 0x10,
+  0x36, 0xFC, 0x28, 0x74, 0x53, 0xCC, 0xEA, 0x0E, 0x06, 0x78, 0x97, 0x20, 0x32, 0x42, 0x87, 0xD9, 0x9E, 0xA7, 0xF0, 0x70, 0x7A, 0x82, 0x44, 0x80,
+  0xCF, 0xC5, 0x10, 0xEB, 0x3B, 0xA2, 0x41, 0xC2, 0x12, 0x31, 0x79, 0x01, 0xA2, 0x0A, 0x24, 0x58, 0x79, 0x25, 0x07, 0x08, 0xE4, 0x28, 0x88, 0x0D,
+  0x24, 0x51, 0x2B, 0x47, 0xED, 0x2C, 0x0E, 0x09, 0xE6, 0x19, 0x90, 0x19, 0x51, 0x42, 0x2D, 0x3E, 0x96, 0x03, 0x82, 0xB4, 0x56, 0xAF, 0x6A, 0xA8,
+  0x24, 0x6C, 0x50, 0x78, 0x5C, 0xA8, 0x38, 0xD0, 0xD7, 0xA9, 0x06, 0x56, 0x50, 0x78, 0x9F, 0x52, 0x11, 0x65, 0x66, 0xA0, 0x19, 0x69, 0x41, 0xE1,
+  0x8A, 0x80, 0xBA, 0x9A, 0xDD, 0x38, 0x33, 0x02, 0x83, 0xC5, 0x2A, 0x71, 0x41, 0x53, 0x76, 0x98, 0x19, 0x89, 0x41, 0xE2, 0x9D, 0x30, 0x65, 0x59,
+  0x7E, 0x80, 0x33, 0x32, 0x83, 0xC3, 0x54, 0x03, 0x4B, 0x0C, 0x32, 0x0A, 0xE9, 0x41, 0x55, 0x69, 0x8E, 0x41, 0x64, 0x94, 0x56, 0xDC, 0x6F, 0x90,
+  0xCB, 0x93, 0x18, 0xDA, 0x3C, 0x44, 0x33, 0xE4, 0x46, 0x56, 0xAA, 0x22, 0x1A, 0x71, 0xE3, 0x3B, 0x67, 0xA4, 0x86, 0xBC, 0x68, 0xD2, 0x41, 0xD8,
+  0x43, 0x6C, 0xC6, 0xB7, 0x07, 0x79, 0x0D, 0xE3, 0x1B, 0x5C, 0x7E, 0x24, 0x10, 0x98, 0xDD, 0xE6, 0x50, 0x43, 0x94, 0xC7, 0x0A, 0x35, 0x64, 0x3A,
+  0x0C, 0x72, 0x76, 0xFB, 0x90, 0xEB, 0x31, 0xCE, 0x97, 0xE4, 0x87, 0x71, 0x8E, 0xA4, 0xFF, 0x64, 0x13, 0x18, 0x48, 0xA5, 0x79, 0x0F, 0x33, 0x1E,
+  0x1E, 0xAC, 0x48, 0x2A, 0x31, 0xE4, 0xB1, 0xA1, 0x0F, 0x73, 0x1E, 0x8B, 0x5A, 0x90, 0xFA, 0x31, 0xEC, 0xB9, 0x51, 0x0F, 0xC3, 0x0B, 0x3F, 0x54,
+  0x90, 0x5E, 0x61, 0x6B, 0x1F, 0x32, 0x0C, 0x0C, 0x2E, 0x66, 0x9C, 0x83, 0x23, 0x0C, 0x3F, 0x93, 0x10, 0x68, 0x61, 0x9B, 0x54, 0xAC, 0x97, 0xAD,
+  0xF8, 0x56, 0xA1, 0x32, 0x44, 0x68, 0x90, 0xA0, 0x78, 0x0D, 0x65, 0x83, 0x5F, 0xF1, 0x77, 0x24, 0x53, 0x85, 0x09, 0x0C, 0x9D, 0xB7, 0x02, 0x90,
+];
+
+/// The compressed text is every byte value in order.
+const BYTES: [u8; 425] = [
+  0x42, 0x5A, 0x68, 0x39, 0x31, 0x41, 0x59, 0x26, 0x53, 0x59, 0xB6, 0xB5, 0xEE, 0x95, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+  0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+  0xFF, 0xFF, 0xFF, 0xB0, 0x00, 0xC5, 0x52, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x30, 0x09, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4C, 0x00, 0x04, 0xC0, 0x04, 0x98, 0x00, 0x26, 0x00, 0x02, 0x60,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x02, 0x4C, 0x00, 0x13, 0x00, 0x01, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF0, 0x08, 0x0C, 0x10, 0x14, 0x18, 0x1C, 0x20, 0x24, 0x28,
+  0x2C, 0x30, 0x34, 0x38, 0x3C, 0x40, 0x44, 0x48, 0x4C, 0x50, 0x54, 0x58, 0x5C, 0x60, 0x64, 0x68, 0x6C, 0x70, 0x74, 0x78, 0x7C, 0x80, 0x84, 0x88,
+  0x8C, 0x90, 0x94, 0x98, 0x9C, 0xA0, 0xA4, 0xA8, 0xAC, 0xB0, 0xB4, 0xB8, 0xBC, 0xC0, 0xC4, 0xC8, 0xCC, 0xD0, 0xD4, 0xD8, 0xDC, 0xE0, 0xE4, 0xE8,
+  0xEC, 0xF0, 0xF4, 0xF8, 0xFD, 0x01, 0x05, 0x09, 0x0D, 0x11, 0x15, 0x19, 0x1D, 0x21, 0x25, 0x29, 0x2D, 0x31, 0x35, 0x39, 0x3D, 0x41, 0x45, 0x49,
+  0x4D, 0x51, 0x55, 0x59, 0x5D, 0x61, 0x65, 0x69, 0x6D, 0x71, 0x75, 0x79, 0x7D, 0x81, 0x85, 0x89, 0x8D, 0x91, 0x85, 0x89, 0x8D, 0x91, 0x95, 0x99,
+  0x9D, 0xA1, 0xA5, 0xA9, 0xAD, 0xB1, 0xB5, 0xB9, 0xBD, 0xC1, 0xC5, 0xC9, 0xCD, 0xD1, 0xD5, 0xD9, 0xDD, 0xE1, 0xE5, 0xE9, 0xED, 0xF1, 0xF5, 0xF9,
+  0xFE, 0x02, 0x06, 0x0A, 0x0E, 0x12, 0x16, 0x1A, 0x1E, 0x22, 0x26, 0x2A, 0x2E, 0x32, 0x36, 0x3A, 0x3E, 0x42, 0x46, 0x4A, 0x56, 0x5A, 0x5E, 0x62,
+  0x66, 0x6A, 0x6E, 0x72, 0x76, 0x7A, 0x7E, 0x82, 0x86, 0x8A, 0x8E, 0x92, 0x96, 0x9A, 0x9E, 0xA2, 0xA6, 0xAA, 0xAE, 0xB2, 0xB6, 0xBA, 0xBE, 0xC2,
+  0xC6, 0xCA, 0xCE, 0xD2, 0xD6, 0xDA, 0xDE, 0xE2, 0xE6, 0xEA, 0xEE, 0xF2, 0xF6, 0xFA, 0xFF, 0x03, 0x07, 0x0B, 0x0F, 0x13, 0x17, 0x1B, 0x17, 0x1B,
+  0x1F, 0x23, 0x27, 0x2B, 0x2F, 0x33, 0x37, 0x3B, 0x3F, 0x43, 0x47, 0x4B, 0x4F, 0x53, 0x57, 0x5B, 0x5F, 0x63, 0x67, 0x6B, 0x6F, 0x73, 0x77, 0x7B,
+  0x7F, 0x83, 0x87, 0x8B, 0x8F, 0x93, 0x97, 0x9B, 0x9F, 0xA3, 0xA7, 0xAB, 0xAF, 0xB3, 0xB7, 0xBB, 0xBF, 0xC3, 0xC7, 0xCB, 0xCF, 0xD3, 0xD7, 0xDB,
+  0xDF, 0xE3, 0xE7, 0xEB, 0xEF, 0xF3, 0xF4, 0x5D, 0xC9, 0x14, 0xE1, 0x42, 0x42, 0xDA, 0xD7, 0xBA, 0x54,
+];
+/// The compressed text is `random(1300)`, which uses every byte value.
+const RANDOM: [u8; 1644] = [
+  0x42, 0x5A, 0x68, 0x39, 0x31, 0x41, 0x59, 0x26, 0x53, 0x59, 0x94, 0x28, 0xC8, 0x13, 0x00, 0x02, 0x2B, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+  0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+  0xFF, 0xFF, 0xFF, 0xD0, 0x03, 0x74, 0x63, 0x6D, 0x9B, 0x7B, 0x7B, 0x79, 0xEB, 0xDD, 0x3C, 0x75, 0x19, 0x1E, 0x22, 0x1E, 0xA6, 0x83, 0x4F, 0x51,
+  0xEA, 0x1E, 0xA0, 0xC6, 0x8D, 0x43, 0xD4, 0xC8, 0x07, 0xA8, 0xD3, 0x21, 0xA7, 0xA4, 0x7A, 0x40, 0xF5, 0x3D, 0x46, 0x43, 0x4F, 0x51, 0x99, 0x43,
+  0xCA, 0x34, 0x68, 0xF0, 0x88, 0x64, 0x68, 0xF5, 0x34, 0x0F, 0x53, 0x46, 0x1A, 0x8C, 0x9A, 0x34, 0xF1, 0x4D, 0x03, 0xD4, 0xCD, 0x35, 0x0D, 0x07,
+  0x85, 0x34, 0xF5, 0x3D, 0x4D, 0x01, 0xEA, 0x1B, 0x48, 0xD3, 0xD2, 0x6D, 0x11, 0xE2, 0x64, 0xD4, 0xF5, 0x36, 0x88, 0xF0, 0x9A, 0x7A, 0xA6, 0x4D,
+  0x1B, 0x26, 0xA1, 0x00, 0x4D, 0x31, 0x36, 0xA0, 0xC1, 0x3D, 0x4D, 0x34, 0x7A, 0x99, 0x3D, 0x26, 0x11, 0x93, 0x46, 0x4F, 0x49, 0xA6, 0x9A, 0x7E,
+  0x90, 0x13, 0xCA, 0x64, 0xD3, 0x34, 0xD0, 0x8D, 0xA9, 0xA1, 0xA0, 0xF4, 0x43, 0x20, 0xC6, 0x91, 0xA6, 0xD2, 0x60, 0x6A, 0x66, 0x44, 0x0F, 0x53,
+  0x08, 0xC1, 0x82, 0x0D, 0x06, 0x86, 0xA3, 0x4F, 0xD5, 0x3D, 0x4C, 0x46, 0x9E, 0x99, 0x41, 0xB2, 0x64, 0x34, 0x9A, 0x0C, 0x26, 0x26, 0x23, 0x21,
+  0xB5, 0x0D, 0x3D, 0x23, 0x34, 0x9A, 0x68, 0x80, 0xC8, 0x26, 0x6A, 0x3D, 0x43, 0x46, 0x4C, 0xCA, 0x68, 0x3D, 0x23, 0x26, 0x98, 0x4D, 0x34, 0xC2,
+  0x0D, 0x1B, 0x14, 0x7A, 0x81, 0xEA, 0x06, 0x83, 0x4C, 0xD2, 0x32, 0x30, 0x7A, 0x80, 0x6A, 0x1B, 0xD1, 0x4C, 0x69, 0xA2, 0x0F, 0x53, 0xCA, 0x7A,
+  0x8C, 0x86, 0xF4, 0x29, 0x9A, 0x9E, 0x50, 0x31, 0x94, 0x1F, 0xAA, 0x60, 0x46, 0x0D, 0x4F, 0x53, 0x26, 0x13, 0x41, 0xA6, 0x09, 0xA3, 0xD3, 0x53,
+  0x09, 0xEA, 0x69, 0x89, 0xB5, 0x32, 0x64, 0x3C, 0xA3, 0x02, 0x68, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xC0, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x02, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x01, 0x30, 0x80, 0x00,
+  0x26, 0x00, 0x04, 0xC0, 0x00, 0xD0, 0x00, 0x34, 0x00, 0x26, 0x26, 0x00, 0x00, 0x03, 0x40, 0x0D, 0x00, 0x03, 0x40, 0x00, 0x09, 0x84, 0x69, 0x84,
+  0xC0, 0x00, 0x00, 0x00, 0x00, 0x68, 0x00, 0x00, 0x00, 0x4C, 0x08, 0xD0, 0x60, 0x00, 0x00, 0x2B, 0xD4, 0x5E, 0x30, 0xC6, 0xE1, 0x53, 0xCC, 0x00,
+  0x11, 0xA0, 0x1A, 0x9E, 0x92, 0xE4, 0xE4, 0x9B, 0x54, 0x2B, 0xF4, 0x1D, 0xAF, 0xC0, 0xF0, 0x4A, 0x8A, 0x05, 0x1B, 0x8D, 0x3D, 0xAD, 0x89, 0x4F,
+  0x16, 0x8F, 0x0E, 0x0A, 0x2B, 0x10, 0x32, 0x53, 0xD3, 0x87, 0xCA, 0x97, 0x32, 0x37, 0xF6, 0x9A, 0x69, 0xF2, 0x4D, 0x8D, 0xBE, 0x10, 0x5D, 0x43,
+  0xF4, 0xDF, 0x84, 0x91, 0x40, 0x5E, 0x9A, 0xBE, 0xE3, 0xB1, 0xA5, 0x59, 0x4A, 0xDA, 0xF2, 0xC2, 0xBF, 0x1A, 0x8B, 0x42, 0x3F, 0x2E, 0x68, 0x61,
+  0x98, 0xC7, 0xAC, 0x4E, 0x40, 0xBB, 0x6C, 0x2D, 0xAE, 0xFA, 0x1D, 0x85, 0x9D, 0xCD, 0x1F, 0x1E, 0x8A, 0x39, 0x9A, 0x76, 0xA4, 0x22, 0xBA, 0xA6,
+  0x4A, 0x5C, 0xCC, 0x9C, 0x23, 0x3C, 0x8D, 0xD3, 0xA0, 0x3F, 0x27, 0xA3, 0x45, 0x87, 0xA7, 0xA0, 0x69, 0xBC, 0xBA, 0x7B, 0xB1, 0x99, 0x8F, 0x8E,
+  0x0A, 0xB5, 0x29, 0x5F, 0x42, 0x27, 0x6A, 0xC1, 0xD6, 0xA9, 0x09, 0xD8, 0x56, 0xE5, 0x5E, 0xCA, 0x35, 0x75, 0x4E, 0xE4, 0xAD, 0xA4, 0xA5, 0x0B,
+  0xC1, 0xB3, 0xFE, 0xAC, 0xF0, 0x06, 0xC7, 0x67, 0x7F, 0x00, 0x8C, 0x14, 0x59, 0x4D, 0x60, 0xAD, 0xCC, 0xBD, 0x42, 0xCC, 0x82, 0xD1, 0xA0, 0x41,
+  0x99, 0xA0, 0x94, 0x82, 0xD0, 0x73, 0xE7, 0xAC, 0x0D, 0x5E, 0x76, 0xB1, 0x4E, 0x52, 0xB5, 0x26, 0x62, 0xDD, 0xED, 0x44, 0xB8, 0x4D, 0xA3, 0x65,
+  0x07, 0xDA, 0x3C, 0xFA, 0x1B, 0xD2, 0x31, 0xF6, 0x4E, 0x59, 0x62, 0x73, 0x88, 0xD9, 0x99, 0xED, 0x7F, 0x5F, 0x87, 0x63, 0x15, 0xCC, 0x18, 0x86,
+  0x2B, 0x71, 0x1B, 0x2A, 0xB9, 0x05, 0x6F, 0x9D, 0x00, 0xA5, 0x9B, 0x16, 0xF1, 0x18, 0xF8, 0xD6, 0x93, 0x78, 0xB0, 0x82, 0xC1, 0xFC, 0xC1, 0x0D,
+  0x65, 0xCD, 0x1D, 0xBF, 0x7E, 0x95, 0xE2, 0x17, 0x36, 0x7A, 0x58, 0x4C, 0x73, 0x03, 0x69, 0xFC, 0x79, 0xB1, 0x0B, 0x72, 0x39, 0xF9, 0xC3, 0x4E,
+  0x9E, 0x07, 0x31, 0x3B, 0x1D, 0x24, 0xC4, 0x2B, 0xB7, 0xF0, 0xBD, 0x27, 0x6F, 0x00, 0x0A, 0xDE, 0x5A, 0x8D, 0x01, 0x37, 0x50, 0xE3, 0xBA, 0x72,
+  0x7B, 0x9E, 0x84, 0xA6, 0x85, 0x62, 0x8B, 0x81, 0x85, 0xA6, 0xC1, 0x7D, 0x3C, 0xD6, 0xB2, 0x6A, 0x28, 0x8F, 0x1C, 0x57, 0x16, 0x5E, 0xBE, 0x94,
+  0x36, 0x81, 0x0F, 0x86, 0xEA, 0x9B, 0xD3, 0xDE, 0x9D, 0x3F, 0x9E, 0x89, 0x49, 0xD6, 0x89, 0x80, 0x41, 0xF2, 0x2D, 0x90, 0x68, 0x71, 0x61, 0x30,
+  0x46, 0x75, 0x4E, 0x32, 0x52, 0xC5, 0x75, 0xCE, 0xDA, 0x5E, 0xE9, 0xB4, 0x25, 0xD9, 0x64, 0x1F, 0x15, 0xA1, 0x48, 0x83, 0x4A, 0xF0, 0xFE, 0x24,
+  0xDA, 0x57, 0x7E, 0x20, 0x1B, 0x43, 0x93, 0xCC, 0x37, 0xED, 0xBA, 0x90, 0x3D, 0x47, 0xBD, 0x32, 0x64, 0x2C, 0x9E, 0x0A, 0x36, 0x2E, 0xBC, 0x93,
+  0x09, 0x6C, 0x94, 0x5E, 0x26, 0xAA, 0xD4, 0x9F, 0xD7, 0xC4, 0x1B, 0xBF, 0x0D, 0x30, 0x80, 0x6D, 0x61, 0x85, 0xF2, 0x28, 0x82, 0xC9, 0x69, 0xAE,
+  0x6E, 0xA1, 0x1B, 0xEA, 0x27, 0x25, 0x53, 0xDD, 0x9C, 0x8C, 0x19, 0xE7, 0xA7, 0x26, 0xF7, 0x0E, 0xA8, 0x12, 0x15, 0x52, 0x2B, 0x51, 0xCF, 0xC3,
+  0xA1, 0x78, 0xC3, 0x9F, 0x3E, 0xE1, 0x41, 0x7C, 0xE3, 0xBB, 0x90, 0x1C, 0x89, 0x4A, 0x65, 0xE8, 0xC6, 0xCC, 0x76, 0xB6, 0x23, 0x20, 0x6B, 0xCE,
+  0x25, 0xA3, 0x18, 0xD4, 0xBE, 0x0A, 0x6D, 0x93, 0xED, 0xF1, 0x7D, 0x48, 0x4F, 0xED, 0x02, 0x63, 0x3D, 0x88, 0xF4, 0x38, 0x02, 0x9B, 0xF5, 0x78,
+  0xD1, 0x11, 0xA6, 0x9B, 0xB3, 0x2C, 0xDC, 0xEA, 0x67, 0xEE, 0x36, 0xF4, 0x04, 0x6C, 0xBE, 0xC3, 0xBC, 0xF4, 0x37, 0x23, 0x7D, 0x10, 0xDF, 0xA4,
+  0x36, 0x83, 0xC2, 0xB6, 0x08, 0xCB, 0x0E, 0x24, 0x40, 0xBA, 0xD3, 0x99, 0xEC, 0xC2, 0xC9, 0xF6, 0x29, 0xAE, 0xEE, 0xAD, 0x93, 0x50, 0xE4, 0x29,
+  0x12, 0xE9, 0x85, 0x7D, 0x01, 0x78, 0x85, 0x3A, 0x0C, 0xE1, 0x4E, 0x99, 0x60, 0x17, 0x97, 0x5B, 0x50, 0x90, 0xCE, 0x75, 0xD5, 0xB5, 0x81, 0xAD,
+  0xF2, 0x34, 0x4D, 0x2C, 0x7D, 0x64, 0xBD, 0xC0, 0xAE, 0x09, 0x9A, 0x09, 0xB9, 0xDC, 0x30, 0x5E, 0xDF, 0x07, 0x18, 0x92, 0x1A, 0x20, 0xBD, 0x0F,
+  0xB9, 0x39, 0x6A, 0x04, 0x88, 0xD8, 0xA9, 0x9D, 0xC4, 0x36, 0xD2, 0xD9, 0x31, 0xD7, 0x57, 0x41, 0x32, 0x80, 0xFA, 0x6D, 0xEB, 0xA7, 0xCC, 0x60,
+  0xB9, 0x19, 0xB5, 0x2F, 0xEA, 0xB9, 0x87, 0xED, 0x38, 0x23, 0x9B, 0x76, 0xEE, 0x17, 0x58, 0xDB, 0x0B, 0x87, 0xC0, 0xAC, 0xC5, 0xE9, 0x64, 0x47,
+  0x84, 0xD8, 0xE2, 0xEA, 0x40, 0x70, 0xBC, 0xBD, 0x6D, 0x74, 0xC8, 0x18, 0x44, 0x82, 0x5A, 0xF0, 0x64, 0xA9, 0x3B, 0xFA, 0xBA, 0x44, 0xE5, 0x3B,
+  0x17, 0x88, 0xAB, 0x89, 0x39, 0x5E, 0x2B, 0xCF, 0xA6, 0x3C, 0x48, 0x39, 0x39, 0x05, 0x7B, 0xF8, 0x69, 0x6D, 0xF1, 0xBC, 0x5B, 0x4C, 0x91, 0xA4,
+  0x23, 0x70, 0xEE, 0x78, 0xEA, 0x38, 0xE3, 0x78, 0x8C, 0x59, 0x94, 0xF3, 0x29, 0x5F, 0x78, 0x4F, 0xF6, 0xE2, 0x43, 0x9B, 0x90, 0xC5, 0x9A, 0x1C,
+  0x57, 0x0D, 0x0D, 0xD5, 0xA2, 0x10, 0x17, 0xB3, 0x2E, 0x24, 0x5A, 0x57, 0xB5, 0x32, 0xEE, 0xD1, 0x5B, 0x0D, 0x10, 0x27, 0x9D, 0x64, 0xCC, 0xEA,
+  0x93, 0x66, 0x46, 0x6C, 0xE4, 0x2C, 0xC4, 0xE9, 0xDB, 0xE5, 0x88, 0x09, 0x54, 0x11, 0x9B, 0x8F, 0x03, 0x30, 0xC2, 0x18, 0xA3, 0xEE, 0xDB, 0x20,
+  0xF6, 0x39, 0x61, 0x56, 0xBF, 0x5E, 0x98, 0x00, 0x67, 0x67, 0x76, 0xAA, 0x17, 0xD6, 0xA8, 0x33, 0x12, 0x6D, 0xB6, 0x0D, 0x71, 0xEC, 0xEB, 0x14,
+  0x59, 0x3B, 0x5D, 0x89, 0x03, 0x38, 0xD5, 0xE0, 0x5C, 0x91, 0xB5, 0xB4, 0xC0, 0xEB, 0x7F, 0xBB, 0xA0, 0x47, 0x48, 0x21, 0x41, 0x30, 0xF3, 0x27,
+  0x61, 0x4E, 0x67, 0xDD, 0x61, 0x0A, 0xE6, 0xCB, 0x22, 0xF1, 0xAF, 0x9E, 0x5D, 0xA3, 0xBF, 0xFA, 0x42, 0xD8, 0x92, 0xA2, 0x35, 0x1A, 0x3E, 0xAA,
+  0x53, 0xEA, 0xED, 0xF5, 0xAB, 0x48, 0x2F, 0x9F, 0x0A, 0x16, 0x5C, 0x19, 0x4C, 0x6E, 0x0D, 0x5A, 0xE7, 0x9F, 0x67, 0xE8, 0xCC, 0xD8, 0xFA, 0x38,
+  0xEF, 0x7A, 0x34, 0xA8, 0x19, 0x55, 0xDD, 0x80, 0x0B, 0xF0, 0x6C, 0x8F, 0x4E, 0x12, 0x24, 0x63, 0x99, 0xD0, 0xA3, 0x50, 0x2E, 0x7F, 0x70, 0x5C,
+  0x77, 0x88, 0xA0, 0xE4, 0xC8, 0x3F, 0x43, 0xD6, 0xE7, 0xDF, 0xA5, 0x71, 0x0C, 0xCA, 0xAF, 0x77, 0x4F, 0x66, 0x30, 0xDE, 0x09, 0x8B, 0x41, 0x51,
+  0x30, 0x79, 0x4D, 0xB4, 0xBF, 0x17, 0x95, 0x94, 0x5D, 0x04, 0x14, 0x89, 0xC3, 0x2D, 0x6E, 0xF1, 0x73, 0x25, 0x72, 0x41, 0x3F, 0xAD, 0x3A, 0x2E,
+  0x6C, 0x65, 0xB8, 0x3A, 0xC2, 0xCA, 0x63, 0xF2, 0x9B, 0x97, 0xAF, 0x7D, 0x71, 0x85, 0x40, 0xEC, 0x92, 0x76, 0x90, 0xDB, 0x04, 0xD1, 0x26, 0x75,
+  0x3E, 0x4B, 0x7B, 0xBE, 0x6D, 0x18, 0xD5, 0xC3, 0xA5, 0x2C, 0x52, 0x63, 0xFE, 0x11, 0x29, 0x86, 0xAB, 0x20, 0x58, 0xBB, 0x5F, 0x18, 0x2D, 0x91,
+  0xAB, 0x50, 0xF3, 0x64, 0xBA, 0xFA, 0xB2, 0x02, 0xBE, 0x27, 0x36, 0x92, 0x5F, 0x72, 0x9C, 0xD4, 0x1B, 0x32, 0x2F, 0x6A, 0xE0, 0x72, 0xD3, 0xDA,
+  0x0F, 0xB6, 0xAD, 0x04, 0xB4, 0x93, 0x86, 0x64, 0xD2, 0x97, 0xCA, 0x25, 0x5D, 0xD9, 0x84, 0x42, 0xE7, 0x82, 0x41, 0x81, 0xC3, 0x4A, 0x63, 0x71,
+  0xA8, 0x91, 0x22, 0x63, 0xBF, 0xA0, 0x52, 0x3D, 0xEA, 0x8B, 0xD8, 0xAC, 0xF7, 0x8C, 0x21, 0xEA, 0x8C, 0xD1, 0x25, 0x9F, 0x6B, 0xAE, 0x0C, 0xB1,
+  0xF3, 0x84, 0x86, 0x34, 0xA3, 0x04, 0xFE, 0xFD, 0x27, 0x04, 0x7F, 0x52, 0xE7, 0xF8, 0x81, 0xC0, 0x21, 0x84, 0x63, 0xD1, 0x5B, 0x8E, 0x87, 0xDE,
+  0x2B, 0xA4, 0xF2, 0xF7, 0x83, 0x8C, 0x65, 0xA4, 0xB7, 0xF8, 0xAC, 0x34, 0x74, 0xF5, 0xC7, 0x9A, 0xB2, 0xEC, 0x8A, 0x28, 0xF5, 0x8A, 0x4A, 0x0A,
+  0x09, 0xD0, 0x3A, 0xD3, 0x23, 0x14, 0xE3, 0x42, 0xE2, 0xF9, 0x78, 0x70, 0x4F, 0xA4, 0xC7, 0x18, 0x90, 0xA3, 0x76, 0x75, 0x93, 0xF2, 0x59, 0x80,
+  0x9E, 0x9B, 0x1A, 0x44, 0xBF, 0xCA, 0xE1, 0x9C, 0xF9, 0xD4, 0xED, 0xDB, 0x23, 0x6F, 0xD8, 0x90, 0xF8, 0x00, 0x24, 0xAA, 0xD6, 0x29, 0x77, 0xDC,
+  0xFF, 0x03, 0x97, 0xEF, 0x32, 0xD9, 0x40, 0xAF, 0x84, 0x99, 0xC0, 0x95, 0x2A, 0x03, 0xAF, 0x72, 0x82, 0x3A, 0x3E, 0x72, 0x02, 0x0E, 0xF5, 0xBC,
+  0xA4, 0x96, 0x89, 0x5B, 0x49, 0x0B, 0x4B, 0x9A, 0x2C, 0x3F, 0xF8, 0xE8, 0xE2, 0x19, 0xE2, 0x4E, 0x1B, 0x96, 0x00, 0x34, 0xBF, 0x14, 0x57, 0x4A,
+  0x4A, 0x99, 0x75, 0xF2, 0x89, 0xA2, 0xFF, 0x3E, 0x52, 0x44, 0x3F, 0xE2, 0xD5, 0x28, 0x20, 0x97, 0x40, 0x7E, 0x84, 0xFE, 0xC2, 0x3F, 0xD5, 0x4E,
+  0x42, 0xDE, 0x1F, 0x84, 0x66, 0x87, 0x16, 0x21, 0xBB, 0xED, 0x14, 0x69, 0x75, 0xD6, 0x28, 0x96, 0xD2, 0x90, 0x22, 0x9E, 0xBD, 0xE1, 0xF9, 0x40,
+  0xC7, 0x84, 0x34, 0x54, 0x6E, 0xED, 0xF0, 0xC4, 0x42, 0xFF, 0x68, 0x3C, 0x98, 0x1E, 0xBE, 0x9C, 0x8C, 0xDC, 0x19, 0xE9, 0x38, 0x1D, 0x50, 0xF5,
+  0xDF, 0xA2, 0xEE, 0x48, 0xA7, 0x0A, 0x12, 0x12, 0x85, 0x19, 0x02, 0x60,
+];
+
+#[test]
+fn test_bzip2() {
+  let text = text(400,);
+
+  assert_eq!(bzip2_decompress(&EMPTY,), Ok(Vec::new()),);
+  assert_eq!(bzip2_decompress(&TEXT,), Ok(text.clone()),);
+  assert_eq!(bzip2_decompress(&BLOCKS,), Ok(b"abcdefghij".repeat(25000,)),);
+  assert_eq!(bzip2_decompress(&RUNS,), Ok(runs()),);
+  //Blocks which use every byte value.
+  assert_eq!(bzip2_decompress(&BYTES,), Ok((0..=255).collect()),);
+  assert_eq!(bzip2_decompress(&RANDOM,), Ok(random(1300,)),);
+
+  //Concatenated streams decode as one.
+  let stream = [&TEXT[..], &EMPTY, &RUNS,].concat();
+  assert_eq!(bzip2_decompress(&stream,), Ok([&text[..], &runs(),].concat()),);
+
+  //Read in small pieces so that repeats are split.
+  let mut decoder = Bzip2Decoder::new(ReadIter::new(RUNS.iter().copied(),),);
+  let mut out = Vec::new();
+  let mut buffer = [0; 7];
+  loop {
+    match decoder.read(&mut buffer,) {
+      Ok(0) => break,
+      Ok(read) => out.extend_from_slice(&buffer[..read],),
+      Err(e) => panic!("Error decoding: {:?}", e,),
+    }
+  }
+  assert!(decoder.is_done(),);
+  assert_eq!(decoder.total_out(), runs().len() as u64,);
+  assert_eq!(out, runs(),);
+}
+
+#[test]
+fn test_bzip2_errors() {
+  let mut bad = TEXT;
+  bad[3] = b'0';
+  assert_eq!(bzip2_decompress(&bad,), Err(Bzip2Error::Header),);
+  let mut bad = TEXT;
+  bad[4] ^= 1;
+  assert_eq!(bzip2_decompress(&bad,), Err(Bzip2Error::Header),);
+  //The block checksum.
+  let mut bad = TEXT;
+  bad[10] ^= 1;
+  assert_eq!(bzip2_decompress(&bad,), Err(Bzip2Error::Checksum),);
+  //The stream checksum.
+  let mut bad = EMPTY;
+  bad[13] ^= 1;
+  assert_eq!(bzip2_decompress(&bad,), Err(Bzip2Error::Checksum),);
+  //The randomised bit.
+  let mut bad = TEXT;
+  bad[14] |= 0x80;
+  assert_eq!(bzip2_decompress(&bad,), Err(Bzip2Error::Randomised),);
+  assert!(matches!(bzip2_decompress(&TEXT[..TEXT.len() - 20],), Err(Bzip2Error::Read(_,)),),);
+}
+
+#[cfg(feature = "std",)]
+#[test]
+fn test_bzip2_stream() {
+  use std::io::{Read, Cursor, ErrorKind,};
+
+  let mut out = Vec::new();
+  Bzip2Decoder::from_reader(Cursor::new(&BLOCKS[..],),).read_to_end(&mut out,).expect("Error decoding",);
+  assert_eq!(out, b"abcdefghij".repeat(25000,),);
+
+  let error = Bzip2Decoder::from_reader(Cursor::new(&TEXT[..20],),).read_to_end(&mut out,).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::UnexpectedEof,);
+  let error = Bzip2Decoder::from_reader(Cursor::new(&[0x42u8, 0x5A, 0x68, 0x30,][..],),).read_to_end(&mut out,).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::InvalidData,);
+}
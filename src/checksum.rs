@@ -0,0 +1,159 @@
+//! Defines the checksums used by compressed formats.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+mod tests;
+
+/// The largest prime below `2^16`.
+const ADLER_MOD: u32 = 65521;
+/// The most bytes which can be summed before the Adler-32 sums must be reduced.
+const ADLER_BLOCK: usize = 5552;
+
+/// An Adler-32 checksum, as used by zlib.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Adler32 {
+  /// The sum of the bytes.
+  a: u32,
+  /// The sum of the values of `a`.
+  b: u32,
+}
+
+impl Adler32 {
+  /// The checksum of no bytes.
+  pub const fn new() -> Self { Self { a: 1, b: 0, } }
+  /// Adds `bytes` to the checksum.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to add.  
+  pub fn update(&mut self, bytes: &[u8],) -> &mut Self {
+    for block in bytes.chunks(ADLER_BLOCK,) {
+      for &byte in block {
+        self.a += byte as u32;
+        self.b += self.a;
+      }
+
+      self.a %= ADLER_MOD;
+      self.b %= ADLER_MOD;
+    }
+
+    self
+  }
+  /// Returns the checksum of the bytes added so far.
+  #[inline]
+  pub const fn value(&self,) -> u32 { (self.b << 16) | self.a }
+}
+
+impl Default for Adler32 {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+/// Builds the table for a CRC-32 which processes bytes low bits first.
+const fn crc32_table(poly: u32,) -> [u32; 256] {
+  let mut table = [0; 256];
+  let mut index = 0;
+  while index < 256 {
+    let mut crc = index as u32;
+    let mut bit = 0;
+    while bit < 8 {
+      crc = if crc & 1 != 0 { (crc >> 1) ^ poly } else { crc >> 1 };
+      bit += 1;
+    }
+
+    table[index] = crc;
+    index += 1;
+  }
+
+  table
+}
+
+/// Builds the table for a CRC-32 which processes bytes high bits first.
+const fn crc32_msb_table(poly: u32,) -> [u32; 256] {
+  let mut table = [0; 256];
+  let mut index = 0;
+  while index < 256 {
+    let mut crc = (index as u32) << 24;
+    let mut bit = 0;
+    while bit < 8 {
+      crc = if crc & 0x8000_0000 != 0 { (crc << 1) ^ poly } else { crc << 1 };
+      bit += 1;
+    }
+
+    table[index] = crc;
+    index += 1;
+  }
+
+  table
+}
+
+/// The table for the CRC-32 of gzip.
+static CRC32_TABLE: [u32; 256] = crc32_table(0xEDB8_8320,);
+/// The table for the CRC-32 of bzip2.
+static CRC32_MSB_TABLE: [u32; 256] = crc32_msb_table(0x04C1_1DB7,);
+
+/// A CRC-32 checksum, as used by gzip and PNG.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Crc32 {
+  /// The inverted remainder.
+  crc: u32,
+}
+
+impl Crc32 {
+  /// The checksum of no bytes.
+  pub const fn new() -> Self { Self { crc: !0, } }
+  /// Adds `bytes` to the checksum.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to add.  
+  pub fn update(&mut self, bytes: &[u8],) -> &mut Self {
+    for &byte in bytes {
+      self.crc = CRC32_TABLE[(self.crc as u8 ^ byte) as usize] ^ (self.crc >> 8);
+    }
+
+    self
+  }
+  /// Returns the checksum of the bytes added so far.
+  #[inline]
+  pub const fn value(&self,) -> u32 { !self.crc }
+}
+
+impl Default for Crc32 {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+/// A CRC-32 checksum which is not reflected, processing bytes high bits first, as used
+/// by bzip2.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Crc32Msb {
+  /// The inverted remainder.
+  crc: u32,
+}
+
+impl Crc32Msb {
+  /// The checksum of no bytes.
+  pub const fn new() -> Self { Self { crc: !0, } }
+  /// Adds `bytes` to the checksum.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to add.  
+  pub fn update(&mut self, bytes: &[u8],) -> &mut Self {
+    for &byte in bytes {
+      self.crc = CRC32_MSB_TABLE[((self.crc >> 24) as u8 ^ byte) as usize] ^ (self.crc << 8);
+    }
+
+    self
+  }
+  /// Returns the checksum of the bytes added so far.
+  #[inline]
+  pub const fn value(&self,) -> u32 { !self.crc }
+}
+
+impl Default for Crc32Msb {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
@@ -0,0 +1,26 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+
+#[test]
+fn test_checksums() {
+  assert_eq!(Adler32::new().value(), 1,);
+  assert_eq!(Adler32::new().update(b"Wikipedia",).value(), 0x11E6_0398,);
+  assert_eq!(Crc32::new().value(), 0,);
+  assert_eq!(Crc32::new().update(b"123456789",).value(), 0xCBF4_3926,);
+  assert_eq!(Crc32Msb::new().value(), 0,);
+  assert_eq!(Crc32Msb::new().update(b"123456789",).value(), 0xFC89_1918,);
+
+  //Updating in parts gives the same checksum.
+  let bytes = (0..20_000u32).map(|i,| (i * 7 + i / 13) as u8,).collect::<alloc::vec::Vec<_>>();
+  let (mut adler, mut crc, mut crc_msb,) = (Adler32::new(), Crc32::new(), Crc32Msb::new(),);
+  for part in bytes.chunks(777,) { adler.update(part,); crc.update(part,); crc_msb.update(part,); }
+  assert_eq!(adler.value(), 0x1E8A_DFE4,);
+  assert_eq!(crc.value(), 0xE9B1_EB3B,);
+  assert_eq!(adler, *Adler32::new().update(&bytes,),);
+  assert_eq!(crc, *Crc32::new().update(&bytes,),);
+  assert_eq!(crc_msb, *Crc32Msb::new().update(&bytes,),);
+}
@@ -0,0 +1,430 @@
+//! Defines the `IntegerCode` trait so that integer codes can be chosen at runtime.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{Bits, BitRead, BitWrite, CodeError, rice_len, golomb_len, fibonacci_len,};
+
+mod tests;
+
+/// A code which writes and reads `u64` values.
+/// 
+/// The reader and writer are passed as `DynBitRead` and `DynBitWrite` trait objects
+/// so that a code can be used as a `dyn IntegerCode<R, W>` where `R` and `W` are the
+/// error types of the reader and writer.
+pub trait IntegerCode<R, W,> {
+  /// Writes `value` to `writer`.
+  /// 
+  /// If `value` is larger than `max_value` nothing is written and `CodeError::Overflow`
+  /// is returned, if the parameter of the code is invalid `CodeError::Invalid` is
+  /// returned.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write too.  
+  /// value --- The value to write.  
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>>;
+  /// Reads a value from `reader`.
+  /// 
+  /// If the parameter of the code is invalid nothing is read and `CodeError::Invalid` is
+  /// returned.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from.  
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>>;
+  /// Returns the number of bits written when encoding `value`.
+  /// 
+  /// The length of a value larger than `max_value`, or of any value when the parameter of
+  /// the code is invalid, is unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value being coded.  
+  fn bit_len(&self, value: u64,) -> u64;
+  /// Returns the largest value which can be encoded.
+  #[inline]
+  fn max_value(&self,) -> u64 { u64::MAX }
+}
+
+/// A dyn compatible `BitRead` with the error type `E`.
+/// 
+/// Implemented for every `BitRead` and `dyn DynBitRead` implements `BitRead` so the
+/// codes of `BitRead` can be used on a trait object.
+pub trait DynBitRead<E,> {
+  /// Forwards to `BitRead::is_aligned`.
+  fn dyn_is_aligned(&self,) -> bool;
+  /// Forwards to `BitRead::read_bits`.
+  fn dyn_read_bits(&mut self, bits: Bits,) -> Result<u8, E>;
+  /// Forwards to `BitRead::read_word`.
+  fn dyn_read_word(&mut self, bits: u8,) -> Result<u64, E>;
+  /// Forwards to `BitRead::read_bytes`.
+  fn dyn_read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, E,)>;
+  /// Forwards to `BitRead::read_unary`.
+  fn dyn_read_unary(&mut self, terminator: bool,) -> Result<u64, E>;
+  /// Forwards to `BitRead::read_truncated_unary`.
+  fn dyn_read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, E>;
+}
+
+impl<R,> DynBitRead<R::Error,> for R
+  where R: BitRead, {
+  #[inline]
+  fn dyn_is_aligned(&self,) -> bool { self.is_aligned() }
+  #[inline]
+  fn dyn_read_bits(&mut self, bits: Bits,) -> Result<u8, R::Error> { self.read_bits(bits,) }
+  #[inline]
+  fn dyn_read_word(&mut self, bits: u8,) -> Result<u64, R::Error> { self.read_word(bits,) }
+  #[inline]
+  fn dyn_read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, R::Error,)> { self.read_bytes(buf,) }
+  #[inline]
+  fn dyn_read_unary(&mut self, terminator: bool,) -> Result<u64, R::Error> { self.read_unary(terminator,) }
+  #[inline]
+  fn dyn_read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, R::Error> {
+    self.read_truncated_unary(max, terminator,)
+  }
+}
+
+impl<E,> BitRead for dyn DynBitRead<E,> + '_ {
+  type Error = E;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.dyn_is_aligned() }
+  #[inline]
+  fn read_bits(&mut self, bits: Bits,) -> Result<u8, Self::Error> { self.dyn_read_bits(bits,) }
+  #[inline]
+  fn read_word(&mut self, bits: u8,) -> Result<u64, Self::Error> { self.dyn_read_word(bits,) }
+  #[inline]
+  fn read_bytes(&mut self, buf: &mut [u8],) -> Result<(), (usize, Self::Error,)> { self.dyn_read_bytes(buf,) }
+  #[inline]
+  fn read_unary(&mut self, terminator: bool,) -> Result<u64, Self::Error> { self.dyn_read_unary(terminator,) }
+  #[inline]
+  fn read_truncated_unary(&mut self, max: u64, terminator: bool,) -> Result<u64, Self::Error> {
+    self.dyn_read_truncated_unary(max, terminator,)
+  }
+}
+
+/// A dyn compatible `BitWrite` with the error type `E`.
+/// 
+/// Implemented for every `BitWrite` and `dyn DynBitWrite` implements `BitWrite` so
+/// the codes of `BitWrite` can be used on a trait object.
+pub trait DynBitWrite<E,> {
+  /// Forwards to `BitWrite::is_aligned`.
+  fn dyn_is_aligned(&self,) -> bool;
+  /// Forwards to `BitWrite::write_bits`.
+  fn dyn_write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, E>;
+  /// Forwards to `BitWrite::write_word`.
+  fn dyn_write_word(&mut self, bits: u8, word: u64,) -> Result<(), E>;
+  /// Forwards to `BitWrite::write_bytes`.
+  fn dyn_write_bytes(&mut self, bytes: &[u8],) -> Result<(), (usize, E,)>;
+  /// Forwards to `BitWrite::write_unary`.
+  fn dyn_write_unary(&mut self, value: u64, terminator: bool,) -> Result<(), E>;
+  /// Forwards to `BitWrite::write_truncated_unary`.
+  fn dyn_write_truncated_unary(&mut self, value: u64, max: u64, terminator: bool,) -> Result<(), E>;
+}
+
+impl<W,> DynBitWrite<W::Error,> for W
+  where W: BitWrite, {
+  #[inline]
+  fn dyn_is_aligned(&self,) -> bool { self.is_aligned() }
+  #[inline]
+  fn dyn_write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, W::Error> { self.write_bits(bits, buf,) }
+  #[inline]
+  fn dyn_write_word(&mut self, bits: u8, word: u64,) -> Result<(), W::Error> { self.write_word(bits, word,).map(|_,| (),) }
+  #[inline]
+  fn dyn_write_bytes(&mut self, bytes: &[u8],) -> Result<(), (usize, W::Error,)> { self.write_bytes(bytes,).map(|_,| (),) }
+  #[inline]
+  fn dyn_write_unary(&mut self, value: u64, terminator: bool,) -> Result<(), W::Error> {
+    self.write_unary(value, terminator,).map(|_,| (),)
+  }
+  #[inline]
+  fn dyn_write_truncated_unary(&mut self, value: u64, max: u64, terminator: bool,) -> Result<(), W::Error> {
+    self.write_truncated_unary(value, max, terminator,).map(|_,| (),)
+  }
+}
+
+impl<E,> BitWrite for dyn DynBitWrite<E,> + '_ {
+  type Error = E;
+
+  #[inline]
+  fn is_aligned(&self,) -> bool { self.dyn_is_aligned() }
+  #[inline]
+  fn write_bits(&mut self, bits: Bits, buf: u8,) -> Result<Bits, Self::Error> { self.dyn_write_bits(bits, buf,) }
+  #[inline]
+  fn write_word(&mut self, bits: u8, word: u64,) -> Result<&mut Self, Self::Error> {
+    self.dyn_write_word(bits, word,)?; Ok(self)
+  }
+  #[inline]
+  fn write_bytes(&mut self, bytes: &[u8],) -> Result<&mut Self, (usize, Self::Error,)> {
+    self.dyn_write_bytes(bytes,)?; Ok(self)
+  }
+  #[inline]
+  fn write_unary(&mut self, value: u64, terminator: bool,) -> Result<&mut Self, Self::Error> {
+    self.dyn_write_unary(value, terminator,)?; Ok(self)
+  }
+  #[inline]
+  fn write_truncated_unary(&mut self, value: u64, max: u64, terminator: bool,) -> Result<&mut Self, Self::Error> {
+    self.dyn_write_truncated_unary(value, max, terminator,)?; Ok(self)
+  }
+}
+
+/// Returns the number of bits needed to store `value`.
+#[inline]
+const fn bits(value: u64,) -> u64 { 64 - value.leading_zeros() as u64 }
+
+/// A unary code, `value` bits followed by the terminator.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Unary {
+  /// The bit which ends the code.
+  pub terminator: bool,
+}
+
+impl<R, W,> IntegerCode<R, W,> for Unary {
+  #[inline]
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    writer.write_unary(value, self.terminator,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    Ok(reader.read_unary(self.terminator,)?)
+  }
+  #[inline]
+  fn bit_len(&self, value: u64,) -> u64 { value.saturating_add(1,) }
+}
+
+/// A fixed width code with the contained number of bits.
+/// 
+/// Values which do not fit in the width cannot be encoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Fixed(pub u8,);
+
+impl Fixed {
+  /// Returns `true` if the width is at most `64` bits.
+  #[inline]
+  pub const fn is_valid(&self,) -> bool { self.0 <= 64 }
+}
+
+impl<R, W,> IntegerCode<R, W,> for Fixed {
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    check(self.is_valid(),)?;
+    if value > IntegerCode::<R, W,>::max_value(self,) { return Err(CodeError::Overflow) }
+
+    writer.write_word(self.0, value,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    check(self.is_valid(),)?;
+
+    Ok(reader.read_word(self.0,)?)
+  }
+  #[inline]
+  fn bit_len(&self, _: u64,) -> u64 { if self.is_valid() { self.0 as u64 } else { u64::MAX } }
+  #[inline]
+  fn max_value(&self,) -> u64 { (!0u64).checked_shr(64u32.saturating_sub(self.0 as u32,),).unwrap_or(0,) }
+}
+
+/// An Elias gamma code.
+/// 
+/// `value + 1` is coded so that zero can be coded, `u64::MAX` cannot be encoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Gamma;
+
+impl<R, W,> IntegerCode<R, W,> for Gamma {
+  #[inline]
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    writer.write_gamma(offset(value,)?,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    reader.read_gamma().map(|v,| v - 1,)
+  }
+  #[inline]
+  fn bit_len(&self, value: u64,) -> u64 { gamma_len(value.saturating_add(1,),) }
+  #[inline]
+  fn max_value(&self,) -> u64 { u64::MAX - 1 }
+}
+
+/// An Elias delta code.
+/// 
+/// `value + 1` is coded so that zero can be coded, `u64::MAX` cannot be encoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Delta;
+
+impl<R, W,> IntegerCode<R, W,> for Delta {
+  #[inline]
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    writer.write_delta(offset(value,)?,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    reader.read_delta().map(|v,| v - 1,)
+  }
+  fn bit_len(&self, value: u64,) -> u64 {
+    let bits = bits(value.saturating_add(1,),);
+
+    gamma_len(bits,) + bits - 1
+  }
+  #[inline]
+  fn max_value(&self,) -> u64 { u64::MAX - 1 }
+}
+
+/// An Elias omega code.
+/// 
+/// `value + 1` is coded so that zero can be coded, `u64::MAX` cannot be encoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Omega;
+
+impl<R, W,> IntegerCode<R, W,> for Omega {
+  #[inline]
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    writer.write_omega(offset(value,)?,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    reader.read_omega().map(|v,| v - 1,)
+  }
+  fn bit_len(&self, value: u64,) -> u64 {
+    let mut value = value.saturating_add(1,);
+    let mut len = 1;
+    while value > 1 {
+      len += bits(value,);
+      value = bits(value,) - 1;
+    }
+
+    len
+  }
+  #[inline]
+  fn max_value(&self,) -> u64 { u64::MAX - 1 }
+}
+
+/// A Fibonacci code.
+/// 
+/// `value + 1` is coded so that zero can be coded, `u64::MAX` cannot be encoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Fibonacci;
+
+impl<R, W,> IntegerCode<R, W,> for Fibonacci {
+  #[inline]
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    writer.write_fibonacci(offset(value,)?,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    reader.read_fibonacci().map(|v,| v - 1,)
+  }
+  #[inline]
+  fn bit_len(&self, value: u64,) -> u64 { fibonacci_len(value.saturating_add(1,),) }
+  #[inline]
+  fn max_value(&self,) -> u64 { u64::MAX - 1 }
+}
+
+/// A Rice code with the contained parameter.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Rice(pub u8,);
+
+impl Rice {
+  /// Returns `true` if the parameter is less than `64`.
+  #[inline]
+  pub const fn is_valid(&self,) -> bool { self.0 < 64 }
+}
+
+impl<R, W,> IntegerCode<R, W,> for Rice {
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    check(self.is_valid(),)?;
+
+    writer.write_rice(self.0, value,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    check(self.is_valid(),)?;
+
+    reader.read_rice(self.0,)
+  }
+  #[inline]
+  fn bit_len(&self, value: u64,) -> u64 { if self.is_valid() { rice_len(self.0, value,) } else { u64::MAX } }
+}
+
+/// A Golomb code with the contained parameter.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Golomb(pub u64,);
+
+impl Golomb {
+  /// Returns `true` if the parameter is greater than `0`.
+  #[inline]
+  pub const fn is_valid(&self,) -> bool { self.0 > 0 }
+}
+
+impl<R, W,> IntegerCode<R, W,> for Golomb {
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    check(self.is_valid(),)?;
+
+    writer.write_golomb(self.0, value,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    check(self.is_valid(),)?;
+
+    reader.read_golomb(self.0,)
+  }
+  #[inline]
+  fn bit_len(&self, value: u64,) -> u64 { if self.is_valid() { golomb_len(self.0, value,) } else { u64::MAX } }
+}
+
+/// An Exp-Golomb code with the contained order.
+/// 
+/// Only values which fit in a `u32` can be encoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct ExpGolomb(pub u8,);
+
+impl ExpGolomb {
+  /// Returns `true` if the order is at most `32`.
+  #[inline]
+  pub const fn is_valid(&self,) -> bool { self.0 <= 32 }
+}
+
+impl<R, W,> IntegerCode<R, W,> for ExpGolomb {
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    check(self.is_valid(),)?;
+    if value > IntegerCode::<R, W,>::max_value(self,) { return Err(CodeError::Overflow) }
+
+    writer.write_exp_golomb(self.0, value as u32,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> {
+    check(self.is_valid(),)?;
+
+    reader.read_exp_golomb(self.0,).map(u64::from,)
+  }
+  fn bit_len(&self, value: u64,) -> u64 {
+    if !self.is_valid() { return u64::MAX }
+
+    bits(value.saturating_add(1 << self.0,),) * 2 - 1 - self.0 as u64
+  }
+  #[inline]
+  fn max_value(&self,) -> u64 { u32::MAX as u64 }
+}
+
+/// An unsigned LEB128 code.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Leb128;
+
+impl<R, W,> IntegerCode<R, W,> for Leb128 {
+  #[inline]
+  fn encode(&self, writer: &mut dyn DynBitWrite<W,>, value: u64,) -> Result<(), CodeError<W,>> {
+    writer.write_uleb128(value,).map(|_,| (),).map_err(CodeError::Write,)
+  }
+  #[inline]
+  fn decode(&self, reader: &mut dyn DynBitRead<R,>,) -> Result<u64, CodeError<R,>> { reader.read_uleb128() }
+  #[inline]
+  fn bit_len(&self, value: u64,) -> u64 { bits(value,).max(1,).div_ceil(7,) * 8 }
+}
+
+/// Offsets `value` so that zero can be coded by codes which start at one.
+/// 
+/// `u64::MAX` cannot be offset and returns `CodeError::Overflow`.
+#[inline]
+fn offset<E,>(value: u64,) -> Result<u64, CodeError<E,>> { value.checked_add(1,).ok_or(CodeError::Overflow,) }
+
+/// Returns `CodeError::Invalid` if the parameter of a code is not `valid`.
+#[inline]
+fn check<E,>(valid: bool,) -> Result<(), CodeError<E,>> { if valid { Ok(()) } else { Err(CodeError::Invalid) } }
+
+/// Returns the number of bits in the Elias gamma code for `value`.
+#[inline]
+const fn gamma_len(value: u64,) -> u64 { bits(value,) * 2 - 1 }
@@ -0,0 +1,108 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::{ReadIter, WriteVec, WriteSlice, Bits, CodeError,};
+use alloc::{boxed::Box, vec::Vec,};
+use core::convert::Infallible;
+
+/// A code chosen at runtime.
+type DynCode = Box<dyn IntegerCode<Option<Bits>, Infallible,>>;
+
+#[test]
+fn test_integer_codes() {
+  let codes: Vec<DynCode> = alloc::vec![
+    Box::new(Unary { terminator: true, },),
+    Box::new(Fixed(64,),),
+    Box::new(Gamma,),
+    Box::new(Delta,),
+    Box::new(Omega,),
+    Box::new(Fibonacci,),
+    Box::new(Rice(3,),),
+    Box::new(Golomb(5,),),
+    Box::new(ExpGolomb(2,),),
+    Box::new(Leb128,),
+  ];
+  let values = [0u64, 1, 2, 3, 7, 8, 100, 1000, 65535,];
+
+  for (index, code,) in codes.iter().enumerate() {
+    let mut writer = WriteVec::new();
+    let mut bits = 0;
+
+    for &value in &values {
+      //Keep the unary code short.
+      let value = if index == 0 { value % 100 } else { value };
+
+      code.encode(&mut writer, value,).expect("Error encoding",);
+      bits += code.bit_len(value,);
+    }
+    assert_eq!(writer.to_write().map_or(0, |b,| 8 - b as u64,), bits % 8, "`bit_len` failed for {}", index,);
+
+    let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+    assert_eq!(bytes.len() as u64, bits.div_ceil(8,), "`bit_len` failed for {}", index,);
+
+    let mut reader = ReadIter::new(bytes.iter().copied(),);
+    for &value in &values {
+      let value = if index == 0 { value % 100 } else { value };
+
+      assert_eq!(code.decode(&mut reader,), Ok(value), "`decode` failed for {}", index,);
+    }
+  }
+}
+
+#[test]
+fn test_bit_len() {
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Gamma, 0,), 1,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Gamma, 3,), 5,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Delta, 0,), 1,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Delta, 15,), 9,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Omega, 0,), 1,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Omega, 15,), 11,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&ExpGolomb(0,), 0,), 1,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&ExpGolomb(0,), 3,), 5,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Leb128, 0,), 8,);
+  assert_eq!(IntegerCode::<(), (),>::bit_len(&Leb128, 128,), 16,);
+}
+
+#[test]
+fn test_max_value() {
+  let codes: [(DynCode, u64,); 5] = [
+    (Box::new(Fixed(8,),), 255,),
+    (Box::new(Fixed(0,),), 0,),
+    (Box::new(Gamma,), u64::MAX - 1,),
+    (Box::new(Fibonacci,), u64::MAX - 1,),
+    (Box::new(ExpGolomb(3,),), u32::MAX as u64,),
+  ];
+
+  for (index, (code, max,),) in codes.iter().enumerate() {
+    let mut writer = WriteVec::new();
+
+    assert_eq!(code.max_value(), *max, "`max_value` failed for {}", index,);
+    assert_eq!(code.encode(&mut writer, max + 1,), Err(CodeError::Overflow), "`encode` failed for {}", index,);
+    assert_eq!(writer.clone().into_vec().ok(), Some(Vec::new()), "`encode` wrote bits for {}", index,);
+    code.encode(&mut writer, *max,).expect("Error encoding",);
+  }
+  assert_eq!(IntegerCode::<(), (),>::max_value(&Rice(2,),), u64::MAX,);
+}
+
+#[test]
+fn test_invalid_parameters() {
+  let codes: [DynCode; 4] = [Box::new(Fixed(65,),), Box::new(Rice(64,),), Box::new(Golomb(0,),), Box::new(ExpGolomb(33,),),];
+
+  for (index, code,) in codes.iter().enumerate() {
+    let mut writer = WriteVec::new();
+    let bytes = [0xFFu8; 16];
+
+    assert_eq!(code.encode(&mut writer, 1,), Err(CodeError::Invalid), "`encode` failed for {}", index,);
+    assert_eq!(writer.clone().into_vec().ok(), Some(Vec::new()), "`encode` wrote bits for {}", index,);
+    assert_eq!(code.decode(&mut ReadIter::new(bytes.iter(),),), Err(CodeError::Invalid), "`decode` failed for {}", index,);
+    assert_eq!(code.bit_len(1,), u64::MAX, "`bit_len` failed for {}", index,);
+  }
+  assert!(Fixed(64,).is_valid() && Rice(63,).is_valid() && Golomb(1,).is_valid() && ExpGolomb(32,).is_valid(),);
+
+  //Failing writers are reported as write errors.
+  let mut writer = WriteSlice::new(&mut [0u8; 0],);
+  assert!(matches!(IntegerCode::<(), Bits,>::encode(&Fixed(8,), &mut writer, 5,), Err(CodeError::Write(_)),),);
+}
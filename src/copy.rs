@@ -0,0 +1,155 @@
+//! Defines functions for copying bits from a reader to a writer.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitWrite, Bits,};
+
+mod tests;
+
+/// The number of bits copied at once when the reader and writer are not both aligned.
+const WORD_BITS: u8 = 56;
+/// The number of bytes copied at once when the reader and writer are both aligned.
+const CHUNK_BYTES: usize = 256;
+
+/// The error returned when copying bits fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum CopyError<R, W,> {
+  /// Reading from the reader failed.
+  Read(R),
+  /// Writing to the writer failed.
+  Write(W),
+}
+
+/// The `CopyError` returned when copying from `R` to `W`.
+pub type CopyErrorOf<R, W,> = CopyError<<R as BitRead>::Error, <W as BitWrite>::Error,>;
+
+/// Copies `bits` bits from `reader` to `writer`.
+/// 
+/// If the reader and writer are both aligned whole bytes are copied in bulk, otherwise
+/// the bits are copied a word at a time and the reader and writer shift them into place.
+/// 
+/// On error the number of bits which were written to `writer` is returned with the
+/// error, bits which were read but not written are lost.
+/// 
+/// # Params
+/// 
+/// reader --- The reader to copy from.  
+/// writer --- The writer to copy too.  
+/// bits --- The number of bits to copy.  
+pub fn copy_bits<R, W,>(reader: &mut R, writer: &mut W, bits: u64,) -> Result<u64, (u64, CopyErrorOf<R, W,>,)>
+  where R: BitRead, W: BitWrite, {
+  let mut copied = 0;
+
+  //Copy whole bytes in bulk.
+  if reader.is_aligned() && writer.is_aligned() {
+    let mut chunk = [0u8; CHUNK_BYTES];
+
+    while bits - copied >= 8 {
+      let len = usize::min(CHUNK_BYTES, ((bits - copied) / 8) as usize,);
+
+      copy_chunk(reader, writer, &mut chunk[..len],).map_err(|(written, e,),| (copied + written, e,),)?;
+      copied += len as u64 * 8;
+    }
+  }
+  //Copy the remaining bits a word at a time.
+  while copied < bits {
+    let len = u64::min(WORD_BITS as u64, bits - copied,) as u8;
+
+    copy_word(reader, writer, len,).map_err(|e,| (copied, e,),)?;
+    copied += len as u64;
+  }
+
+  Ok(copied)
+}
+
+/// Copies bits from `reader` to `writer` until `reader` returns an error.
+/// 
+/// The number of bits copied is returned with the error which stopped the copy, a
+/// `CopyError::Read` marks the end of the reader.  
+/// Readers which remove bits from the input when a read of several bytes fails will
+/// lose those bits.
+/// 
+/// # Params
+/// 
+/// reader --- The reader to copy from.  
+/// writer --- The writer to copy too.  
+pub fn copy_to_end<R, W,>(reader: &mut R, writer: &mut W,) -> (u64, CopyErrorOf<R, W,>,)
+  where R: BitRead, W: BitWrite, {
+  let mut copied = 0;
+
+  //Copy whole bytes in bulk.
+  if reader.is_aligned() && writer.is_aligned() {
+    let mut chunk = [0u8; CHUNK_BYTES];
+
+    loop {
+      match copy_chunk(reader, writer, &mut chunk,) {
+        Ok(()) => copied += CHUNK_BYTES as u64 * 8,
+        //Finish the remaining bytes below.
+        Err((written, CopyError::Read(_),)) => { copied += written; break },
+        Err((written, e,)) => return (copied + written, e,),
+      }
+    }
+  }
+  //Copy a word at a time until there is not a full word available.
+  loop {
+    match copy_word(reader, writer, WORD_BITS,) {
+      Ok(()) => copied += WORD_BITS as u64,
+      Err(CopyError::Read(_)) => break,
+      Err(e) => return (copied, e,),
+    }
+  }
+  //Copy the remaining bytes.
+  loop {
+    match copy_word(reader, writer, 8,) {
+      Ok(()) => copied += 8,
+      Err(CopyError::Read(_)) => break,
+      Err(e) => return (copied, e,),
+    }
+  }
+  //Copy the remaining bits, at most one of these reads will succeed.
+  for &bits in Bits::BITS[..7].iter().rev() {
+    match copy_word(reader, writer, bits as u8,) {
+      Ok(()) => copied += bits as u64,
+      Err(CopyError::Read(_)) => {},
+      Err(e) => return (copied, e,),
+    }
+  }
+
+  //Any further read reports the end of the reader.
+  match copy_word(reader, writer, 1,) {
+    Ok(()) => {
+      let (rest, e,) = copy_to_end(reader, writer,);
+
+      (copied + 1 + rest, e,)
+    },
+    Err(e) => (copied, e,),
+  }
+}
+
+/// Copies `bits` bits from `reader` to `writer` as a single word.
+fn copy_word<R, W,>(reader: &mut R, writer: &mut W, bits: u8,) -> Result<(), CopyErrorOf<R, W,>>
+  where R: BitRead, W: BitWrite, {
+  let word = reader.read_word(bits,).map_err(CopyError::Read,)?;
+
+  writer.write_word(bits, word,).map_err(CopyError::Write,)?;
+
+  Ok(())
+}
+
+/// Copies `chunk.len()` bytes from `reader` to `writer` using `chunk` as a buffer.
+/// 
+/// On error the number of bits written to `writer` is returned.
+fn copy_chunk<R, W,>(reader: &mut R, writer: &mut W, chunk: &mut [u8],) -> Result<(), (u64, CopyErrorOf<R, W,>,)>
+  where R: BitRead, W: BitWrite, {
+  match reader.read_bytes(chunk,) {
+    Ok(()) => writer.write_bytes(chunk,)
+      .map_err(|(written, e,),| (written as u64 * 8, CopyError::Write(e),),)
+      .map(|_,| (),),
+    //Write the bytes which were read before reporting the error.
+    Err((read, e,)) => match writer.write_bytes(&chunk[..read],) {
+      Ok(_) => Err((read as u64 * 8, CopyError::Read(e),)),
+      Err((written, e,)) => Err((written as u64 * 8, CopyError::Write(e),)),
+    },
+  }
+}
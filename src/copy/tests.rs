@@ -0,0 +1,96 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::{ReadIter, WriteVec,};
+use alloc::vec::Vec;
+
+/// Returns `bits` bits of `bytes` starting at bit `start`, packed high bits first.
+fn bit_range(bytes: &[u8], start: usize, bits: usize,) -> Vec<u8> {
+  let mut writer = WriteVec::new();
+
+  for i in start..start + bits {
+    writer.write_bit(bytes[i / 8] & (0x80 >> (i % 8)) != 0,).ok();
+  }
+
+  writer.pad_zeros().clone().into_vec().expect("Error unwrapping writer",)
+}
+
+#[test]
+fn test_copy_bits() {
+  let bytes = (0..200u32).map(|i,| (i * 37 + 11) as u8,).collect::<Vec<_>>();
+
+  for &(skip, pad, bits,) in &[(0, 0, 1000,), (0, 0, 1003,), (3, 0, 1000,), (0, 5, 999,), (3, 5, 1001,), (4, 4, 64,),] {
+    let mut reader = ReadIter::new(bytes.iter(),);
+    let mut writer = WriteVec::new();
+
+    reader.read_word(skip,).expect("Error skipping",);
+    writer.write_word(pad, 0,).expect("Error padding",);
+    assert_eq!(copy_bits(&mut reader, &mut writer, bits,), Ok(bits),);
+
+    let mut expected = WriteVec::new();
+    expected.write_word(pad, 0,).ok();
+    let mut source = ReadIter::new(bit_range(&bytes, skip as usize, bits as usize,),);
+    for _ in 0..bits { expected.write_bit(source.read_bit().expect("Error reading",),).ok(); }
+
+    assert_eq!(
+      writer.pad_zeros().clone().into_vec().ok(),
+      expected.pad_zeros().clone().into_vec().ok(),
+      "Copy failed for {:?}", (skip, pad, bits,),
+    );
+  }
+
+  let mut reader = ReadIter::new(bytes[..2].iter(),);
+  let mut writer = WriteVec::new();
+  reader.read_bit().ok();
+  assert_eq!(copy_bits(&mut reader, &mut writer, 16,), Err((0, CopyError::Read(Some(Bits::B8)),)),);
+  assert_eq!(copy_bits(&mut reader, &mut writer, 15,), Ok(15),);
+}
+
+#[test]
+fn test_copy_to_end() {
+  let bytes = (0..700u32).map(|i,| (i * 37 + 11) as u8,).collect::<Vec<_>>();
+
+  for &(skip, pad,) in &[(0, 0,), (3, 0,), (0, 5,), (3, 3,), (7, 2,),] {
+    let mut reader = ReadIter::new(bytes.iter(),);
+    let mut writer = WriteVec::new();
+
+    reader.read_word(skip,).expect("Error skipping",);
+    writer.write_word(pad, 0,).expect("Error padding",);
+    assert_eq!(copy_to_end(&mut reader, &mut writer,), (bytes.len() as u64 * 8 - skip as u64, CopyError::Read(None),),);
+
+    let mut expected = WriteVec::new();
+    expected.write_word(pad, 0,).ok();
+    expected.write_word(8 - skip, bytes[0] as u64,).ok();
+    expected.write_bytes(&bytes[1..],).ok();
+
+    assert_eq!(writer.pad_zeros().clone().into_vec().ok(), expected.pad_zeros().clone().into_vec().ok(),);
+  }
+}
+
+#[test]
+fn test_copy_io() {
+  #![cfg(feature = "std",)]
+
+  use crate::{ReadIO, WriteIO,};
+
+  let bytes = (0..5000u32).map(|i,| (i * 37 + 11) as u8,).collect::<Vec<_>>();
+  let mut output = Vec::new();
+  let mut reader = ReadIO::with_capacity(64, bytes.as_slice(),);
+  let mut writer = WriteIO::with_capacity(64, &mut output,);
+
+  assert_eq!(copy_bits(&mut reader, &mut writer, 8 * 1000,).ok(), Some(8 * 1000),);
+  reader.read_word(4,).ok();
+  writer.write_word(4, bytes[1000] as u64 >> 4,).ok();
+  match copy_to_end(&mut reader, &mut writer,) {
+    (copied, CopyError::Read((None, e,)),) => {
+      assert_eq!(copied, 8 * 4000 - 4,);
+      assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof,);
+    },
+    (_, e,) => panic!("Unexpected error: {:?}", e,),
+  }
+  assert!(writer.into_writer().is_ok(),);
+  assert_eq!(output, bytes,);
+}
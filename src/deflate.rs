@@ -0,0 +1,624 @@
+//! Encodes DEFLATE streams and the zlib and gzip formats which wrap them.
+//! 
+//! Input is matched against the previous `32KiB` using hash chains and each block is
+//! written as stored, fixed or dynamic Huffman, whichever is smallest. With
+//! `--features std` the encoders also implement `std::io::Write` over a `WriteIO`.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{
+  BitWrite, WriteLsb, WriteVec, HuffmanEncoder, Adler32, Crc32, code_lengths,
+  inflate::{WINDOW_SIZE, LENGTH_BASE, LENGTH_EXTRA, DISTANCE_BASE, DISTANCE_EXTRA, CODE_LENGTH_ORDER, fixed_lengths,},
+};
+use alloc::{vec, vec::Vec, boxed::Box,};
+
+mod tests;
+#[cfg(feature = "std",)]
+mod stream;
+
+/// The most input bytes in a block, the most a stored block can hold.
+const BLOCK_SIZE: usize = 0xFFFF;
+/// The number of entries in the hash table.
+const HASH_SIZE: usize = 1 << 15;
+/// The shortest match.
+const MIN_MATCH: usize = 3;
+/// The longest match.
+const MAX_MATCH: usize = 258;
+/// Matches of the shortest length further back than this are not worth coding.
+const TOO_FAR: usize = 4096;
+/// Marks an empty hash chain.
+const NONE: usize = usize::MAX;
+
+/// The search parameters of a compression level.
+#[derive(Clone, Copy, Debug,)]
+struct Config {
+  /// Search less once a match this long has been found.
+  good: usize,
+  /// Do not look for a longer match once a match this long has been found, for greedy
+  /// levels the longest match whose positions are all hashed.
+  lazy: usize,
+  /// Stop searching once a match this long has been found.
+  nice: usize,
+  /// The most hash chain entries to search.
+  chain: usize,
+  /// Whether to check if the next position has a longer match before taking a match.
+  lazy_eval: bool,
+}
+
+/// The search parameters of each level, the same as zlib.
+const LEVELS: [Config; 10] = [
+  Config { good: 0, lazy: 0, nice: 0, chain: 0, lazy_eval: false, },
+  Config { good: 4, lazy: 4, nice: 8, chain: 4, lazy_eval: false, },
+  Config { good: 4, lazy: 5, nice: 16, chain: 8, lazy_eval: false, },
+  Config { good: 4, lazy: 6, nice: 32, chain: 32, lazy_eval: false, },
+  Config { good: 4, lazy: 4, nice: 16, chain: 16, lazy_eval: true, },
+  Config { good: 8, lazy: 16, nice: 32, chain: 32, lazy_eval: true, },
+  Config { good: 8, lazy: 16, nice: 128, chain: 128, lazy_eval: true, },
+  Config { good: 8, lazy: 32, nice: 128, chain: 256, lazy_eval: true, },
+  Config { good: 32, lazy: 128, nice: 258, chain: 1024, lazy_eval: true, },
+  Config { good: 32, lazy: 258, nice: 258, chain: 4096, lazy_eval: true, },
+];
+
+/// A literal byte or a match.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum Token {
+  /// A byte to output.
+  Literal(u8,),
+  /// A copy of earlier output with its length and distance.
+  Match(u16, u16,),
+}
+
+/// Returns the index of the largest base which is at most `value`.
+#[inline]
+fn symbol_index(bases: &[u16], value: usize,) -> usize {
+  bases.partition_point(|&base,| base as usize <= value,) - 1
+}
+
+/// Run length encodes a sequence of code lengths with the code length symbols.
+/// 
+/// Returns each symbol and the value of its extra bits.
+fn run_lengths(lengths: &[u8],) -> Vec<(u8, u8,)> {
+  let mut symbols = Vec::new();
+  let mut index = 0;
+  while index < lengths.len() {
+    let len = lengths[index];
+    let run = lengths[index..].iter().take_while(|&&other,| other == len,).count();
+    let mut left = run;
+
+    if len == 0 {
+      while left >= 11 {
+        let repeat = left.min(138,);
+
+        symbols.push((18, (repeat - 11) as u8,),);
+        left -= repeat;
+      }
+      if left >= 3 { symbols.push((17, (left - 3) as u8,),); left = 0; }
+    } else {
+      symbols.push((len, 0,),);
+      left -= 1;
+      while left >= 3 {
+        let repeat = left.min(6,);
+
+        symbols.push((16, (repeat - 3) as u8,),);
+        left -= repeat;
+      }
+    }
+    symbols.extend((0..left).map(|_,| (len, 0,),),);
+    index += run;
+  }
+
+  symbols
+}
+
+/// The number of extra bits of each code length symbol.
+#[inline]
+fn run_length_extra(symbol: u8,) -> u8 {
+  match symbol { 16 => 2, 17 => 3, 18 => 7, _ => 0, }
+}
+
+/// Returns the length of the last used code plus one, at least `min`.
+fn used_len(lengths: &[u8], min: usize,) -> usize {
+  lengths.iter().rposition(|&len,| len > 0,).map_or(0, |last,| last + 1,).max(min,)
+}
+
+/// Writes the tokens of a block with the codes.
+fn write_tokens<W,>(writer: &mut WriteLsb<W,>, tokens: &[Token], literals: &HuffmanEncoder, distances: &HuffmanEncoder,) -> Result<(), W::Error>
+  where W: BitWrite, {
+  for &token in tokens {
+    match token {
+      Token::Literal(byte,) => literals.encode(writer, byte as u16,)?,
+      Token::Match(len, dist,) => {
+        let index = symbol_index(&LENGTH_BASE, len as usize,);
+        literals.encode(writer, 257 + index as u16,)?;
+        writer.write_word(LENGTH_EXTRA[index], (len - LENGTH_BASE[index]) as u64,)?;
+
+        let index = symbol_index(&DISTANCE_BASE, dist as usize,);
+        distances.encode(writer, index as u16,)?;
+        writer.write_word(DISTANCE_EXTRA[index], (dist - DISTANCE_BASE[index]) as u64,)?;
+      },
+    }
+  }
+
+  literals.encode(writer, 256,)
+}
+
+/// Writes a block as whichever of the block types is smallest.
+/// 
+/// # Params
+/// 
+/// writer --- The writer to write too.  
+/// tokens --- The tokens of the block, empty if only stored blocks are written.  
+/// raw --- The input bytes of the block.  
+/// last --- Whether this is the last block.  
+/// stored --- Whether only stored blocks should be written.  
+fn write_block<W,>(writer: &mut WriteLsb<W,>, tokens: &[Token], raw: &[u8], last: bool, stored: bool,) -> Result<(), W::Error>
+  where W: BitWrite, {
+  let (mut literal_freqs, mut distance_freqs,) = ([0u64; 286], [0u64; 30],);
+  let mut extra = 0;
+  for &token in tokens {
+    match token {
+      Token::Literal(byte,) => literal_freqs[byte as usize] += 1,
+      Token::Match(len, dist,) => {
+        let index = symbol_index(&LENGTH_BASE, len as usize,);
+        literal_freqs[257 + index] += 1;
+        extra += LENGTH_EXTRA[index] as u64;
+
+        let index = symbol_index(&DISTANCE_BASE, dist as usize,);
+        distance_freqs[index] += 1;
+        extra += DISTANCE_EXTRA[index] as u64;
+      },
+    }
+  }
+  literal_freqs[256] += 1;
+
+  //The size of a stored block including the worst case padding.
+  let stored_cost = 3 + 7 + 32 + 8 * raw.len() as u64;
+  if stored { return write_stored(writer, raw, last,) }
+
+  let (fixed_literals, fixed_distances,) = fixed_lengths();
+  let cost = |literals: &[u8], distances: &[u8],| -> u64 {
+    let literals = literal_freqs.iter().zip(literals,).map(|(&freq, &len,),| freq * len as u64,).sum::<u64>();
+    let distances = distance_freqs.iter().zip(distances,).map(|(&freq, &len,),| freq * len as u64,).sum::<u64>();
+
+    literals + distances + extra
+  };
+  let fixed_cost = 3 + cost(&fixed_literals, &fixed_distances,);
+
+  let literal_lengths = code_lengths(&literal_freqs, 15,);
+  let mut distance_lengths = code_lengths(&distance_freqs, 15,);
+  //A distance code must be sent even if there are no matches.
+  if distance_lengths.iter().all(|&len,| len == 0,) { distance_lengths[..2].fill(1,); }
+
+  let literal_count = used_len(&literal_lengths, 257,);
+  let distance_count = used_len(&distance_lengths, 1,);
+  let mut lengths = literal_lengths[..literal_count].to_vec();
+  lengths.extend_from_slice(&distance_lengths[..distance_count],);
+  let runs = run_lengths(&lengths,);
+
+  let mut run_freqs = [0u64; 19];
+  for &(symbol, _,) in &runs { run_freqs[symbol as usize] += 1; }
+  let run_lengths = code_lengths(&run_freqs, 7,);
+  let run_count = CODE_LENGTH_ORDER.iter().rposition(|&symbol,| run_lengths[symbol] > 0,).map_or(0, |last,| last + 1,).max(4,);
+
+  let dynamic_cost = 3 + 5 + 5 + 4 + 3 * run_count as u64
+    + runs.iter().map(|&(symbol, _,),| (run_lengths[symbol as usize] + run_length_extra(symbol,)) as u64,).sum::<u64>()
+    + cost(&literal_lengths, &distance_lengths,);
+
+  if stored_cost <= fixed_cost.min(dynamic_cost,) {
+    write_stored(writer, raw, last,)
+  } else if fixed_cost <= dynamic_cost {
+    writer.write_word(3, last as u64 | (1 << 1),)?;
+
+    let literals = HuffmanEncoder::new(&fixed_literals,).expect("The fixed code is valid",);
+    let distances = HuffmanEncoder::new(&fixed_distances,).expect("The fixed code is valid",);
+    write_tokens(writer, tokens, &literals, &distances,)
+  } else {
+    writer.write_word(3, last as u64 | (2 << 1),)?
+      .write_word(5, literal_count as u64 - 257,)?
+      .write_word(5, distance_count as u64 - 1,)?
+      .write_word(4, run_count as u64 - 4,)?;
+    for &symbol in &CODE_LENGTH_ORDER[..run_count] {
+      writer.write_word(3, run_lengths[symbol] as u64,)?;
+    }
+
+    let code = HuffmanEncoder::new(&run_lengths,).expect("Package-merge produced an invalid code",);
+    for &(symbol, extra,) in &runs {
+      code.encode(writer, symbol as u16,)?;
+      writer.write_word(run_length_extra(symbol,), extra as u64,)?;
+    }
+
+    let literals = HuffmanEncoder::new(&literal_lengths,).expect("Package-merge produced an invalid code",);
+    let distances = HuffmanEncoder::new(&distance_lengths,).expect("Package-merge produced an invalid code",);
+    write_tokens(writer, tokens, &literals, &distances,)
+  }
+}
+
+/// Writes a stored block.
+fn write_stored<W,>(writer: &mut WriteLsb<W,>, raw: &[u8], last: bool,) -> Result<(), W::Error>
+  where W: BitWrite, {
+  writer.write_word(3, last as u64,)?;
+  if let Some(bits) = writer.to_write() { writer.write_word(bits as u8, 0,)?; }
+  writer.write_word(16, raw.len() as u64,)?.write_word(16, !raw.len() as u64,)?;
+  writer.write_bytes(raw,).map_err(|(_, e,),| e,)?;
+
+  Ok(())
+}
+
+/// Encodes a raw DEFLATE stream.
+/// 
+/// Input is buffered until a block of `64KiB` is available, `finish` writes the rest.
+/// 
+/// After an error the encoder should not be used.
+#[derive(Clone, Debug,)]
+pub struct Deflater<W,>
+  where W: BitWrite, {
+  /// The writer to encode to.
+  writer: WriteLsb<W,>,
+  /// The compression level.
+  level: u8,
+  /// The previous `WINDOW_SIZE` bytes of input followed by the input not yet encoded.
+  data: Vec<u8>,
+  /// The position in `data` of the input not yet encoded.
+  start: usize,
+  /// The number of bytes removed from the front of `data`.
+  offset: usize,
+  /// The most recent position with each hash.
+  head: Box<[usize]>,
+  /// The previous position with the same hash as each position in the window.
+  prev: Box<[usize]>,
+  /// The tokens of the block being encoded.
+  tokens: Vec<Token>,
+}
+
+impl<W,> Deflater<W,>
+  where W: BitWrite, {
+  /// Constructs a new `Deflater` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  /// level --- The compression level from `0`, only stored blocks, to `9`, the smallest
+  /// output.  
+  pub fn new(writer: WriteLsb<W,>, level: u8,) -> Self {
+    assert!(level <= 9, "The compression level must be at most 9",);
+
+    Self {
+      writer,
+      level,
+      data: Vec::new(),
+      start: 0,
+      offset: 0,
+      head: vec![NONE; HASH_SIZE].into_boxed_slice(),
+      prev: vec![NONE; WINDOW_SIZE].into_boxed_slice(),
+      tokens: Vec::new(),
+    }
+  }
+  /// Returns the compression level.
+  #[inline]
+  pub const fn level(&self,) -> u8 { self.level }
+  /// Returns the number of bytes given to the encoder.
+  #[inline]
+  pub fn total_in(&self,) -> u64 { (self.offset + self.data.len()) as u64 }
+  /// Returns a reference to the writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &WriteLsb<W,> { &self.writer }
+  /// Encodes `bytes`, writing any blocks which are full.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to encode.  
+  pub fn write(&mut self, bytes: &[u8],) -> Result<(), W::Error> {
+    self.data.extend_from_slice(bytes,);
+    while self.data.len() - self.start > BLOCK_SIZE { self.write_block(false,)?; }
+
+    Ok(())
+  }
+  /// Writes the remaining input as the last block and pads the output to a byte.
+  /// 
+  /// Returns the writer with all of the output written to its inner writer.
+  pub fn finish(mut self,) -> Result<WriteLsb<W,>, W::Error> {
+    loop {
+      let last = self.data.len() - self.start <= BLOCK_SIZE;
+
+      self.write_block(last,)?;
+      if last { break }
+    }
+
+    self.writer.pad_zeros().flush()?;
+
+    Ok(self.writer)
+  }
+  /// Encodes and writes the next block of input.
+  fn write_block(&mut self, last: bool,) -> Result<(), W::Error> {
+    let end = usize::min(self.start + BLOCK_SIZE, self.data.len(),);
+
+    if self.level > 0 { self.find_matches(end,); }
+    write_block(&mut self.writer, &self.tokens, &self.data[self.start..end], last, self.level == 0,)?;
+    self.tokens.clear();
+    self.start = end;
+
+    //Drop the input which can no longer be matched.
+    if self.start >= 2 * WINDOW_SIZE {
+      let remove = self.start - WINDOW_SIZE;
+
+      self.data.drain(..remove,);
+      self.offset += remove;
+      self.start -= remove;
+    }
+
+    Ok(())
+  }
+  /// Returns the hash of the bytes at `pos`.
+  #[inline]
+  fn hash(&self, pos: usize,) -> usize {
+    let bytes = &self.data[pos..pos + MIN_MATCH];
+
+    ((bytes[0] as usize) << 10 ^ (bytes[1] as usize) << 5 ^ bytes[2] as usize) % HASH_SIZE
+  }
+  /// Adds the position `pos` to the hash chains.
+  #[inline]
+  fn insert(&mut self, pos: usize,) {
+    let hash = self.hash(pos,);
+    let abs = self.offset + pos;
+
+    self.prev[abs % WINDOW_SIZE] = self.head[hash];
+    self.head[hash] = abs;
+  }
+  /// Returns the longest match at `pos` which is longer than `prev_len` and ends by
+  /// `end`, as its length and distance.
+  fn longest_match(&self, pos: usize, end: usize, prev_len: usize,) -> (usize, usize,) {
+    let config = LEVELS[self.level as usize];
+    let max = usize::min(MAX_MATCH, end - pos,);
+    let abs = self.offset + pos;
+    let mut chain = if prev_len >= config.good { config.chain / 4 } else { config.chain };
+    let (mut best_len, mut best_dist,) = (prev_len.max(MIN_MATCH - 1,), 0,);
+    let mut candidate = self.head[self.hash(pos,)];
+
+    while candidate != NONE && chain > 0 {
+      let dist = abs - candidate;
+      if dist > WINDOW_SIZE { break }
+
+      let other = candidate - self.offset;
+      if self.data[other + best_len.min(max - 1,)] == self.data[pos + best_len.min(max - 1,)] {
+        let len = self.data[other..other + max].iter().zip(&self.data[pos..pos + max],)
+          .take_while(|(a, b,),| a == b,).count();
+
+        if len > best_len {
+          best_len = len;
+          best_dist = dist;
+          if len >= config.nice.min(max,) { break }
+        }
+      }
+
+      let next = self.prev[candidate % WINDOW_SIZE];
+      if next == NONE || next >= candidate { break }
+      candidate = next;
+      chain -= 1;
+    }
+
+    if best_dist == 0 || (best_len == MIN_MATCH && best_dist > TOO_FAR) { (0, 0,) }
+    else { (best_len, best_dist,) }
+  }
+  /// Finds the tokens for the input up to `end`.
+  fn find_matches(&mut self, end: usize,) {
+    let config = LEVELS[self.level as usize];
+    //A match found at the previous position which has not been taken yet.
+    let mut pending = None;
+    let mut pos = self.start;
+
+    while pos < end {
+      let (len, dist,) = if pos + MIN_MATCH <= end {
+        let prev_len = pending.map_or(0, |(len, _,),| len,);
+        let found = if config.lazy_eval && prev_len >= config.lazy { (0, 0,) }
+          else { self.longest_match(pos, end, prev_len,) };
+
+        self.insert(pos,);
+        found
+      } else { (0, 0,) };
+
+      if let Some((prev_len, prev_dist,)) = pending {
+        if len > prev_len {
+          //The match at this position is better, emit the previous byte.
+          self.tokens.push(Token::Literal(self.data[pos - 1],),);
+          pending = Some((len, dist,));
+          pos += 1;
+        } else {
+          self.tokens.push(Token::Match(prev_len as u16, prev_dist as u16,),);
+          let match_end = pos - 1 + prev_len;
+          for hashed in pos + 1..usize::min(match_end, end + 1 - MIN_MATCH,) { self.insert(hashed,); }
+
+          pending = None;
+          pos = match_end;
+        }
+      } else if len >= MIN_MATCH {
+        if config.lazy_eval && len < config.lazy && pos + 1 < end {
+          pending = Some((len, dist,));
+          pos += 1;
+        } else {
+          self.tokens.push(Token::Match(len as u16, dist as u16,),);
+          if len <= config.lazy {
+            for hashed in pos + 1..usize::min(pos + len, end + 1 - MIN_MATCH,) { self.insert(hashed,); }
+          }
+
+          pos += len;
+        }
+      } else {
+        self.tokens.push(Token::Literal(self.data[pos],),);
+        pos += 1;
+      }
+    }
+  }
+}
+
+/// Encodes a zlib stream.
+#[derive(Clone, Debug,)]
+pub struct ZlibEncoder<W,>
+  where W: BitWrite, {
+  /// The DEFLATE encoder.
+  deflater: Deflater<W,>,
+  /// The checksum of the input.
+  checksum: Adler32,
+  /// Whether the header has been written.
+  header: bool,
+}
+
+impl<W,> ZlibEncoder<W,>
+  where W: BitWrite, {
+  /// Constructs a new `ZlibEncoder` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to, it should be aligned.  
+  /// level --- The compression level from `0` to `9`.  
+  pub fn new(writer: WriteLsb<W,>, level: u8,) -> Self {
+    Self { deflater: Deflater::new(writer, level,), checksum: Adler32::new(), header: false, }
+  }
+  /// Encodes `bytes`.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to encode.  
+  pub fn write(&mut self, bytes: &[u8],) -> Result<(), W::Error> {
+    self.write_header()?;
+    self.checksum.update(bytes,);
+
+    self.deflater.write(bytes,)
+  }
+  /// Writes the rest of the stream and the trailer.
+  /// 
+  /// Returns the writer with all of the output written to its inner writer.
+  pub fn finish(mut self,) -> Result<WriteLsb<W,>, W::Error> {
+    self.write_header()?;
+
+    let mut writer = self.deflater.finish()?;
+    writer.write_bytes(&self.checksum.value().to_be_bytes(),).map_err(|(_, e,),| e,)?
+      .flush()?;
+
+    Ok(writer)
+  }
+  /// Writes the header if it has not been written.
+  fn write_header(&mut self,) -> Result<(), W::Error> {
+    if self.header { return Ok(()) }
+
+    let method = 0x78u8;
+    let level = match self.deflater.level() { 0 | 1 => 0, 2..=5 => 1, 6 => 2, _ => 3, };
+    let flags = level << 6;
+    let flags = flags + (31 - (u16::from_be_bytes([method, flags,],) % 31) as u8) % 31;
+
+    self.deflater.writer.write_bytes(&[method, flags,],).map_err(|(_, e,),| e,)?;
+    self.header = true;
+
+    Ok(())
+  }
+}
+
+/// Encodes a gzip stream with a single member.
+#[derive(Clone, Debug,)]
+pub struct GzipEncoder<W,>
+  where W: BitWrite, {
+  /// The DEFLATE encoder.
+  deflater: Deflater<W,>,
+  /// The checksum of the input.
+  checksum: Crc32,
+  /// Whether the header has been written.
+  header: bool,
+}
+
+impl<W,> GzipEncoder<W,>
+  where W: BitWrite, {
+  /// Constructs a new `GzipEncoder` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to, it should be aligned.  
+  /// level --- The compression level from `0` to `9`.  
+  pub fn new(writer: WriteLsb<W,>, level: u8,) -> Self {
+    Self { deflater: Deflater::new(writer, level,), checksum: Crc32::new(), header: false, }
+  }
+  /// Encodes `bytes`.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to encode.  
+  pub fn write(&mut self, bytes: &[u8],) -> Result<(), W::Error> {
+    self.write_header()?;
+    self.checksum.update(bytes,);
+
+    self.deflater.write(bytes,)
+  }
+  /// Writes the rest of the stream and the trailer.
+  /// 
+  /// Returns the writer with all of the output written to its inner writer.
+  pub fn finish(mut self,) -> Result<WriteLsb<W,>, W::Error> {
+    self.write_header()?;
+
+    let len = self.deflater.total_in() as u32;
+    let mut writer = self.deflater.finish()?;
+    writer.write_bytes(&self.checksum.value().to_le_bytes(),).map_err(|(_, e,),| e,)?
+      .write_bytes(&len.to_le_bytes(),).map_err(|(_, e,),| e,)?
+      .flush()?;
+
+    Ok(writer)
+  }
+  /// Writes the header if it has not been written.
+  fn write_header(&mut self,) -> Result<(), W::Error> {
+    if self.header { return Ok(()) }
+
+    let extra_flags = match self.deflater.level() { 9 => 2, 1 => 4, _ => 0, };
+    //No flags, no modification time and an unknown operating system.
+    let header = [0x1F, 0x8B, 8, 0, 0, 0, 0, 0, extra_flags, 255,];
+
+    self.deflater.writer.write_bytes(&header,).map_err(|(_, e,),| e,)?;
+    self.header = true;
+
+    Ok(())
+  }
+}
+
+/// Unwraps the bytes written by an encoder to a `WriteVec`.
+fn into_bytes(writer: Result<WriteLsb<WriteVec,>, core::convert::Infallible,>,) -> Vec<u8> {
+  let writer = match writer { Ok(writer) => writer, Err(e) => match e {}, };
+  let (writer, _,) = writer.into_inner().expect("The encoder pads its output",);
+
+  writer.into_vec().expect("The encoder pads its output",)
+}
+
+/// Encodes `bytes` as a raw DEFLATE stream.
+/// 
+/// # Params
+/// 
+/// bytes --- The bytes to encode.  
+/// level --- The compression level from `0` to `9`.  
+pub fn deflate(bytes: &[u8], level: u8,) -> Vec<u8> {
+  let mut deflater = Deflater::new(WriteLsb::new(WriteVec::new(),), level,);
+  deflater.write(bytes,).ok();
+
+  into_bytes(deflater.finish(),)
+}
+
+/// Encodes `bytes` as a zlib stream.
+/// 
+/// # Params
+/// 
+/// bytes --- The bytes to encode.  
+/// level --- The compression level from `0` to `9`.  
+pub fn zlib_compress(bytes: &[u8], level: u8,) -> Vec<u8> {
+  let mut encoder = ZlibEncoder::new(WriteLsb::new(WriteVec::new(),), level,);
+  encoder.write(bytes,).ok();
+
+  into_bytes(encoder.finish(),)
+}
+
+/// Encodes `bytes` as a gzip stream.
+/// 
+/// # Params
+/// 
+/// bytes --- The bytes to encode.  
+/// level --- The compression level from `0` to `9`.  
+pub fn gzip_compress(bytes: &[u8], level: u8,) -> Vec<u8> {
+  let mut encoder = GzipEncoder::new(WriteLsb::new(WriteVec::new(),), level,);
+  encoder.write(bytes,).ok();
+
+  into_bytes(encoder.finish(),)
+}
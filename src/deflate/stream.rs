@@ -0,0 +1,92 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use crate::{Bits, WriteIO, IntoWriterError,};
+use std::io::{self, Write,};
+
+/// The error of a `WriteIO`.
+type IOError = (Option<Bits>, io::Error,);
+
+/// Unwraps the IO writer from a finished encoder.
+fn into_writer<W,>(writer: Result<WriteLsb<WriteIO<W,>,>, IOError,>,) -> io::Result<W>
+  where W: Write, {
+  let (writer, _,) = writer.map_err(|(_, e,),| e,)?
+    .into_inner().map_err(|_,| io::Error::other("The encoder output was unaligned",),)?;
+
+  writer.into_writer().map_err(|e,| match e {
+    IntoWriterError::Io(_, e,) => e,
+    IntoWriterError::Unaligned(_,) => io::Error::other("The encoder output was unaligned",),
+  },)
+}
+
+impl<W,> Deflater<WriteIO<W,>,>
+  where W: Write, {
+  /// Constructs a new `Deflater` which encodes to an IO writer.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  /// level --- The compression level from `0` to `9`.  
+  pub fn from_writer(writer: W, level: u8,) -> Self { Self::new(WriteLsb::new(WriteIO::new(writer,),), level,) }
+  /// Finishes the stream, flushes all of the output and unwraps the IO writer.
+  pub fn into_writer(self,) -> io::Result<W> { into_writer(self.finish(),) }
+}
+
+impl<W,> Write for Deflater<W,>
+  where W: BitWrite<Error = IOError,>, {
+  #[inline]
+  fn write(&mut self, buf: &[u8],) -> io::Result<usize> {
+    Deflater::write(self, buf,).map(|_,| buf.len(),).map_err(|(_, e,),| e,)
+  }
+  /// Writes the whole bytes of the encoded blocks, input is only encoded once a block is
+  /// full or the stream is finished.
+  #[inline]
+  fn flush(&mut self,) -> io::Result<()> { self.writer.flush().map(|_,| (),).map_err(|(_, e,),| e,) }
+}
+
+impl<W,> ZlibEncoder<WriteIO<W,>,>
+  where W: Write, {
+  /// Constructs a new `ZlibEncoder` which encodes to an IO writer.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  /// level --- The compression level from `0` to `9`.  
+  pub fn from_writer(writer: W, level: u8,) -> Self { Self::new(WriteLsb::new(WriteIO::new(writer,),), level,) }
+  /// Finishes the stream, flushes all of the output and unwraps the IO writer.
+  pub fn into_writer(self,) -> io::Result<W> { into_writer(self.finish(),) }
+}
+
+impl<W,> Write for ZlibEncoder<W,>
+  where W: BitWrite<Error = IOError,>, {
+  #[inline]
+  fn write(&mut self, buf: &[u8],) -> io::Result<usize> {
+    ZlibEncoder::write(self, buf,).map(|_,| buf.len(),).map_err(|(_, e,),| e,)
+  }
+  #[inline]
+  fn flush(&mut self,) -> io::Result<()> { self.deflater.flush() }
+}
+
+impl<W,> GzipEncoder<WriteIO<W,>,>
+  where W: Write, {
+  /// Constructs a new `GzipEncoder` which encodes to an IO writer.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  /// level --- The compression level from `0` to `9`.  
+  pub fn from_writer(writer: W, level: u8,) -> Self { Self::new(WriteLsb::new(WriteIO::new(writer,),), level,) }
+  /// Finishes the stream, flushes all of the output and unwraps the IO writer.
+  pub fn into_writer(self,) -> io::Result<W> { into_writer(self.finish(),) }
+}
+
+impl<W,> Write for GzipEncoder<W,>
+  where W: BitWrite<Error = IOError,>, {
+  #[inline]
+  fn write(&mut self, buf: &[u8],) -> io::Result<usize> {
+    GzipEncoder::write(self, buf,).map(|_,| buf.len(),).map_err(|(_, e,),| e,)
+  }
+  #[inline]
+  fn flush(&mut self,) -> io::Result<()> { self.deflater.flush() }
+}
@@ -0,0 +1,130 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::{inflate::{inflate, zlib_decompress, gzip_decompress,}, test_data::{text, random,},};
+
+//The vectors below were checked with Python's `zlib.decompress`.
+/// `b"stored bytes"` at level `0`.
+const STORED: [u8; 17] = [
+  0x01, 0x0C, 0x00, 0xF3, 0xFF, 0x73, 0x74, 0x6F, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73,
+];
+/// `b"abcabcabcabcabcabc"` at level `6`, a fixed block.
+const FIXED: [u8; 6] = [0x4B, 0x4C, 0x4A, 0x46, 0x43, 0x00,];
+/// `text(60)` at level `9`, a dynamic block.
+const DYNAMIC: [u8; 97] = [
+  0x7D, 0x4F, 0x41, 0x0E, 0x80, 0x30, 0x08, 0xBB, 0xFB, 0x8A, 0x7D, 0x8D, 0xC5, 0x45, 0x4D, 0x36, 0x5D, 0xE2, 0x4E, 0xBC,
+  0xDE, 0x48, 0x83, 0x82, 0x21, 0x5E, 0xA0, 0x85, 0x42, 0x61, 0xA1, 0xD6, 0x28, 0x51, 0xED, 0x2B, 0xA5, 0xB9, 0xD4, 0x41,
+  0x93, 0xC5, 0xB9, 0x0C, 0x85, 0xA5, 0x9F, 0x5B, 0x3D, 0xF6, 0xA4, 0x99, 0xEF, 0x96, 0x12, 0x48, 0xB0, 0x0B, 0xD8, 0x0F,
+  0x45, 0xCC, 0xAA, 0xC5, 0x47, 0x36, 0xA2, 0x2A, 0x1C, 0x87, 0x20, 0x86, 0xAE, 0xFC, 0x55, 0xC5, 0x05, 0x6F, 0x9E, 0x5F,
+  0x17, 0x7E, 0x5C, 0x7F, 0x9E, 0xF2, 0x2C, 0x10, 0xD8, 0x98, 0x6D, 0x5B, 0x33, 0x4E, 0xB1, 0xEF, 0x5E,
+];
+/// `b"hello hello hello hello"` as a zlib stream at level `6`.
+const ZLIB: [u8; 15] = [0x78, 0x9C, 0xCB, 0x48, 0xCD, 0xC9, 0xC9, 0x57, 0xC0, 0x20, 0x01, 0x68, 0x03, 0x08, 0xB1,];
+/// `b"hello hello hello hello"` as a gzip stream at level `1`.
+const GZIP: [u8; 27] = [
+  0x1F, 0x8B, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFF, 0xCB, 0x48, 0xCD, 0xC9, 0xC9, 0x57, 0xC0, 0x20, 0x01, 0xE3,
+  0x51, 0x3D, 0x8D, 0x17, 0x00, 0x00, 0x00,
+];
+
+#[test]
+fn test_run_lengths() {
+  let lengths = [0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 0, 0,];
+  assert_eq!(run_lengths(&lengths,), [(17, 1,), (3, 0,), (16, 3,), (3, 0,), (4, 0,), (4, 0,), (0, 0,), (0, 0,),],);
+
+  let lengths = [0; 140];
+  assert_eq!(run_lengths(&lengths,), [(18, 127,), (0, 0,), (0, 0,),],);
+}
+
+#[test]
+fn test_deflate() {
+  let text = text(40_000,);
+  let noise = random(70_000,);
+  let mut mixed = text[..40_000].to_vec();
+  mixed.extend_from_slice(&noise,);
+  mixed.extend_from_slice(&text[..40_000],);
+
+  for level in 0..=9 {
+    for input in [&[][..], b"a", b"abcabcabcabcabc", &text, &mixed,] {
+      assert_eq!(inflate(&deflate(input, level,),).as_deref(), Ok(input), "Level {}", level,);
+    }
+  }
+
+  //Long runs use overlapping matches.
+  let run = [7; 1000];
+  let compressed = deflate(&run, 6,);
+  assert!(compressed.len() < 20,);
+  assert_eq!(inflate(&compressed,).as_deref(), Ok(&run[..]),);
+
+  //Incompressible input is stored.
+  let compressed = deflate(&noise, 9,);
+  assert!(compressed.len() <= noise.len() + 5 * 2,);
+  //Higher levels compress better.
+  let (fast, best,) = (deflate(&text, 1,), deflate(&text, 9,),);
+  assert!(best.len() < fast.len(),);
+  assert!(best.len() * 4 < text.len(),);
+
+  //Writing in pieces produces the same stream.
+  let mut deflater = Deflater::new(WriteLsb::new(WriteVec::new(),), 6,);
+  for chunk in mixed.chunks(1000,) { deflater.write(chunk,).ok(); }
+  assert_eq!(deflater.total_in(), mixed.len() as u64,);
+  assert_eq!(into_bytes(deflater.finish(),), deflate(&mixed, 6,),);
+}
+
+#[test]
+fn test_zlib_gzip() {
+  let text = text(5000,);
+
+  for level in [0, 1, 6, 9,] {
+    let zlib = zlib_compress(&text, level,);
+    assert_eq!(u16::from_be_bytes([zlib[0], zlib[1],],) % 31, 0,);
+    assert_eq!(zlib_decompress(&zlib,), Ok(text.clone()),);
+    assert_eq!(gzip_decompress(&gzip_compress(&text, level,),), Ok(text.clone()),);
+  }
+
+  assert_eq!(zlib_compress(&[], 6,), [0x78, 0x9C, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01,],);
+  assert_eq!(zlib_decompress(&zlib_compress(&[], 6,),), Ok(Vec::new()),);
+  assert_eq!(gzip_decompress(&gzip_compress(&[], 6,),), Ok(Vec::new()),);
+}
+
+#[cfg(feature = "std",)]
+#[test]
+fn test_deflate_stream() {
+  use crate::inflate::{GzipDecoder, ZlibDecoder,};
+  use std::io::{Read, Write, Cursor,};
+
+  let text = text(5000,);
+
+  let mut encoder = GzipEncoder::from_writer(Vec::new(), 6,);
+  encoder.write_all(&text,).expect("Error encoding",);
+  let compressed = encoder.into_writer().expect("Error encoding",);
+  assert_eq!(compressed, gzip_compress(&text, 6,),);
+  let mut out = Vec::new();
+  GzipDecoder::from_reader(Cursor::new(&compressed,),).read_to_end(&mut out,).expect("Error decoding",);
+  assert_eq!(out, text,);
+
+  let mut encoder = ZlibEncoder::from_writer(Vec::new(), 9,);
+  for chunk in text.chunks(333,) { encoder.write_all(chunk,).expect("Error encoding",); }
+  encoder.flush().expect("Error encoding",);
+  let compressed = encoder.into_writer().expect("Error encoding",);
+  let mut out = Vec::new();
+  ZlibDecoder::from_reader(Cursor::new(&compressed,),).read_to_end(&mut out,).expect("Error decoding",);
+  assert_eq!(out, text,);
+
+  let mut encoder = Deflater::from_writer(Vec::new(), 1,);
+  encoder.write_all(&text,).expect("Error encoding",);
+  assert_eq!(encoder.into_writer().expect("Error encoding",), deflate(&text, 1,),);
+}
+
+#[test]
+fn test_reference_vectors() {
+  let hello = b"hello hello hello hello";
+
+  assert_eq!(deflate(b"stored bytes", 0,), STORED,);
+  assert_eq!(deflate(b"abcabcabcabcabcabc", 6,), FIXED,);
+  assert_eq!(deflate(&text(60,), 9,), DYNAMIC,);
+  assert_eq!(zlib_compress(hello, 6,), ZLIB,);
+  assert_eq!(gzip_compress(hello, 1,), GZIP,);
+}
@@ -0,0 +1,56 @@
+//! Defines helpers for Fibonacci codes.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+mod tests;
+
+/// The Fibonacci numbers from `1, 2, 3, 5, ...` which fit in a `u64`.
+pub(crate) const FIBONACCI: [u64; 92] = {
+  let mut fibonacci = [1u64; 92];
+  let mut i = 1;
+  fibonacci[1] = 2;
+  while i + 1 < fibonacci.len() {
+    fibonacci[i + 1] = fibonacci[i] + fibonacci[i - 1];
+    i += 1;
+  }
+
+  fibonacci
+};
+
+/// Returns the bits of the Fibonacci code for `value` along with the number of bits.
+/// 
+/// The first bit of the code is the highest set bit of the returned value.
+/// 
+/// # Params
+/// 
+/// value --- The value being coded, greater than `0`.  
+pub(crate) fn fibonacci_code(value: u64,) -> (u128, u8,) {
+  assert!(value != 0, "`0` cannot be written as a Fibonacci code",);
+
+  //The largest Fibonacci number in the code.
+  let top = FIBONACCI.iter().rposition(|&f,| f <= value,).unwrap_or(0,);
+  let bits = top as u8 + 2;
+  //Start with the terminator.
+  let mut code = 1u128;
+  let mut value = value;
+  for (index, &f,) in FIBONACCI[..=top].iter().enumerate().rev() {
+    if f <= value {
+      code |= 1 << (bits - 1 - index as u8);
+      value -= f;
+    }
+  }
+
+  (code, bits,)
+}
+
+/// Returns the number of bits in the Fibonacci code for `value`.
+/// 
+/// # Params
+/// 
+/// value --- The value being coded, greater than `0`.  
+pub fn fibonacci_len(value: u64,) -> u64 {
+  assert!(value != 0, "`0` cannot be written as a Fibonacci code",);
+
+  FIBONACCI.iter().rposition(|&f,| f <= value,).unwrap_or(0,) as u64 + 2
+}
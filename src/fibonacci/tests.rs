@@ -0,0 +1,19 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+
+#[test]
+fn test_fibonacci() {
+  assert_eq!(FIBONACCI[..6], [1, 2, 3, 5, 8, 13,],);
+  assert_eq!(FIBONACCI[91], 12200160415121876738,);
+  assert!(FIBONACCI[91].checked_add(FIBONACCI[90],).is_none(),);
+
+  assert_eq!(fibonacci_code(1,), (0b11, 2,),);
+  assert_eq!(fibonacci_code(4,), (0b1011, 4,),);
+  assert_eq!(fibonacci_code(11,), (0b001011, 6,),);
+  assert_eq!(fibonacci_len(11,), 6,);
+  assert_eq!(fibonacci_len(u64::MAX,), 93,);
+}
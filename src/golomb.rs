@@ -0,0 +1,78 @@
+//! Defines helpers for Golomb and Rice codes.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+mod tests;
+
+/// Maps a signed value onto an unsigned value so that values close to zero are small.
+/// 
+/// `0, -1, 1, -2, 2, ...` are mapped to `0, 1, 2, 3, 4, ...`.
+#[inline]
+pub const fn zigzag_encode(value: i64,) -> u64 { ((value << 1) ^ (value >> 63)) as u64 }
+
+/// The inverse of `zigzag_encode`.
+#[inline]
+pub const fn zigzag_decode(value: u64,) -> i64 { (value >> 1) as i64 ^ -((value & 1) as i64) }
+
+/// Returns the number of bits in the Rice code for `value`, saturating at `u64::MAX`.
+/// 
+/// # Params
+/// 
+/// k --- The Rice parameter, less than `64`.  
+/// value --- The value being coded.  
+#[inline]
+pub const fn rice_len(k: u8, value: u64,) -> u64 { (value >> k).saturating_add(1 + k as u64,) }
+
+/// Returns the number of bits in the Golomb code for `value`, saturating at `u64::MAX`.
+/// 
+/// # Params
+/// 
+/// m --- The Golomb parameter, greater than `0`.  
+/// value --- The value being coded.  
+pub fn golomb_len(m: u64, value: u64,) -> u64 {
+  let (bits, cutoff,) = truncated_binary(m,);
+  let remainder = value % m;
+
+  (value / m).saturating_add(1 + bits as u64 - (remainder < cutoff) as u64,)
+}
+
+/// Returns the Rice parameter which codes `values` in the fewest bits along with the
+/// number of bits needed.
+/// 
+/// The exact cost of every useful parameter is calculated, ties are broken in favour of
+/// the smaller parameter.
+/// 
+/// # Params
+/// 
+/// values --- The values to be coded.  
+pub fn optimal_rice_parameter(values: &[u64],) -> (u8, u64,) {
+  //Larger parameters than the largest value only add bits.
+  let max_k = values.iter().map(|v,| 64 - v.leading_zeros() as u8,).max().unwrap_or(0,).min(63,);
+  let mut best = (0, u64::MAX,);
+
+  for k in 0..=max_k {
+    let bits = values.iter().fold(0u64, |bits, &v,| bits.saturating_add(rice_len(k, v,),),);
+
+    if bits < best.1 { best = (k, bits,) }
+  }
+
+  best
+}
+
+/// Returns the number of bits in a long truncated binary remainder for `m` and the
+/// number of remainders which use one bit less.
+/// 
+/// # Params
+/// 
+/// m --- The Golomb parameter, greater than `0`.  
+pub(crate) fn truncated_binary(m: u64,) -> (u8, u64,) {
+  assert!(m != 0, "The Golomb parameter must be greater than 0",);
+
+  if m == 1 { return (0, 0,) }
+
+  let bits = 64 - (m - 1).leading_zeros() as u8;
+  let cutoff = (1u128 << bits) - m as u128;
+
+  (bits, cutoff as u64,)
+}
@@ -0,0 +1,52 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+
+#[test]
+fn test_zigzag() {
+  let pairs = [(0, 0,), (-1, 1,), (1, 2,), (-2, 3,), (2, 4,), (i64::MAX, u64::MAX - 1,), (i64::MIN, u64::MAX,),];
+
+  for &(signed, unsigned,) in &pairs {
+    assert_eq!(zigzag_encode(signed,), unsigned, "`zigzag_encode` failed on {}", signed,);
+    assert_eq!(zigzag_decode(unsigned,), signed, "`zigzag_decode` failed on {}", unsigned,);
+  }
+}
+
+#[test]
+fn test_lengths() {
+  assert_eq!(rice_len(0, 5,), 6,);
+  assert_eq!(rice_len(2, 5,), 4,);
+  assert_eq!(golomb_len(1, 5,), 6,);
+  assert_eq!(golomb_len(4, 5,), rice_len(2, 5,),);
+  //m = 5 codes remainders 0..3 in 2 bits and 3..5 in 3 bits.
+  assert_eq!(golomb_len(5, 2,), 3,);
+  assert_eq!(golomb_len(5, 3,), 4,);
+  assert_eq!(golomb_len(5, 9,), 5,);
+  assert_eq!(truncated_binary(5,), (3, 3,),);
+  assert_eq!(truncated_binary(u64::MAX,), (64, 1,),);
+  //Lengths which do not fit in a `u64` saturate.
+  assert_eq!(rice_len(0, u64::MAX,), u64::MAX,);
+  assert_eq!(rice_len(63, u64::MAX,), 65,);
+  assert_eq!(golomb_len(1, u64::MAX,), u64::MAX,);
+  assert_eq!(golomb_len(u64::MAX, u64::MAX,), 65,);
+}
+
+#[test]
+fn test_optimal_rice_parameter() {
+  assert_eq!(optimal_rice_parameter(&[],), (0, 0,),);
+  assert_eq!(optimal_rice_parameter(&[0, 0, 1,],), (0, 4,),);
+  assert_eq!(optimal_rice_parameter(&[u64::MAX, 0,],), (62, 129,),);
+
+  let values = [3, 9, 12, 7, 30, 2, 18, 5,];
+  let (k, bits,) = optimal_rice_parameter(&values,);
+  for other in 0..8 {
+    let cost = values.iter().map(|&v,| rice_len(other, v,),).sum::<u64>();
+
+    assert!(bits <= cost, "k = {} beaten by {}", k, other,);
+    if other == k { assert_eq!(bits, cost,) }
+  }
+  assert_eq!(k, 3,);
+}
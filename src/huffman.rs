@@ -0,0 +1,231 @@
+//! Defines canonical Huffman codes.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitPeek, BitOrder, CodeError,};
+use alloc::{vec, vec::Vec,};
+
+mod tests;
+mod encoder;
+mod adaptive;
+
+pub use self::{encoder::*, adaptive::*,};
+
+/// The longest code length supported.
+/// 
+/// This bounds the decoding tables to about `2^20` entries, enough for the codes of DEFLATE,
+/// JPEG and bzip2.
+pub const MAX_CODE_LEN: u8 = 20;
+/// The number of bits indexing the first level of a decoding table.
+const ROOT_BITS: u8 = 9;
+
+/// The error returned when a set of code lengths do not form a valid code.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum HuffmanError {
+  /// There are more codes than the lengths allow.
+  OverSubscribed,
+  /// Some sequences of bits are not the prefix of any code.
+  Incomplete,
+  /// A length is longer than `MAX_CODE_LEN`.
+  TooLong,
+}
+
+/// Checks that `lengths` describe a complete prefix code.
+/// 
+/// # Params
+/// 
+/// lengths --- The length of the code for each symbol, zero if the symbol is unused.  
+pub(crate) fn check_lengths(lengths: &[u8],) -> Result<(), HuffmanError> {
+  if lengths.iter().any(|&len,| len > MAX_CODE_LEN,) { return Err(HuffmanError::TooLong) }
+
+  let mut counts = [0i64; MAX_CODE_LEN as usize + 1];
+  for &len in lengths { counts[len as usize] += 1; }
+
+  //The number of codes still available at each length.
+  let mut left = 1i64;
+  for &count in &counts[1..] {
+    left = (left << 1) - count;
+    if left < 0 { return Err(HuffmanError::OverSubscribed) }
+  }
+
+  if left > 0 { Err(HuffmanError::Incomplete) } else { Ok(()) }
+}
+
+/// Returns the canonical code for each symbol.
+/// 
+/// Codes are assigned in order of length and then symbol, unused symbols get zero.
+/// 
+/// # Params
+/// 
+/// lengths --- The length of the code for each symbol, zero if the symbol is unused.  
+pub(crate) fn canonical_codes(lengths: &[u8],) -> Vec<u32> {
+  let mut counts = [0u32; MAX_CODE_LEN as usize + 1];
+  for &len in lengths { counts[len as usize] += 1; }
+  counts[0] = 0;
+
+  //The first code of each length.
+  let mut next = [0u32; MAX_CODE_LEN as usize + 1];
+  let mut code = 0u64;
+  for len in 1..next.len() {
+    code = (code + counts[len - 1] as u64) << 1;
+    next[len] = code as u32;
+  }
+
+  lengths.iter().map(|&len,| {
+    if len == 0 { return 0 }
+
+    let code = next[len as usize];
+    next[len as usize] = code.wrapping_add(1,);
+
+    code
+  },).collect()
+}
+
+/// An entry in a decoding table.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum Entry {
+  /// No code starts with these bits.
+  Empty,
+  /// A decoded symbol and the length of its code.
+  Symbol { symbol: u16, len: u8, },
+  /// The codes are longer than the first level, index a second level table.
+  Table { offset: u32, bits: u8, },
+}
+
+/// Decodes symbols of a canonical Huffman code.
+/// 
+/// Symbols are decoded by table lookup on peeked bits, the first `9` bits index a table
+/// which either holds the symbol or points to a second table for longer codes.
+/// 
+/// The decoder works for both bit orders, the order is taken from the reader.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct HuffmanDecoder {
+  /// The decoding tables, the first level followed by the second level tables.
+  table: Vec<Entry>,
+  /// The number of bits indexing the first level table.
+  root: u8,
+  /// The length of the longest code.
+  max_len: u8,
+}
+
+impl HuffmanDecoder {
+  /// Builds the decoder for the canonical code with the code lengths.
+  /// 
+  /// Codes are assigned in order of length and then symbol, as in DEFLATE.
+  /// 
+  /// # Params
+  /// 
+  /// lengths --- The length of the code for each symbol, zero if the symbol is unused.  
+  pub fn new(lengths: &[u8],) -> Result<Self, HuffmanError> {
+    check_lengths(lengths,)?;
+
+    Ok(Self::build(lengths,))
+  }
+  /// Builds the decoder for the canonical code with the code lengths allowing codes
+  /// which are incomplete.
+  /// 
+  /// Decoding bits which are not the prefix of any code returns `CodeError::Invalid`.
+  /// 
+  /// # Params
+  /// 
+  /// lengths --- The length of the code for each symbol, zero if the symbol is unused.  
+  pub fn new_incomplete(lengths: &[u8],) -> Result<Self, HuffmanError> {
+    match check_lengths(lengths,) {
+      Ok(()) | Err(HuffmanError::Incomplete) => Ok(Self::build(lengths,)),
+      Err(e) => Err(e),
+    }
+  }
+  /// Builds the decoding tables for lengths which have been checked.
+  fn build(lengths: &[u8],) -> Self {
+    assert!(lengths.len() <= 1 << 16, "Huffman codes are limited to `65536` symbols",);
+
+    let codes = canonical_codes(lengths,);
+    let max_len = lengths.iter().copied().max().unwrap_or(0,);
+    let root = max_len.min(ROOT_BITS,);
+    let mut table = vec![Entry::Empty; 1 << root];
+
+    //The bits of the second level table needed for each first level index.
+    let mut sub_bits = vec![0u8; 1 << root];
+    for (&len, &code,) in lengths.iter().zip(&codes,) {
+      if len > root {
+        let prefix = (code >> (len - root)) as usize;
+
+        sub_bits[prefix] = sub_bits[prefix].max(len - root,);
+      }
+    }
+    for (prefix, &bits,) in sub_bits.iter().enumerate() {
+      if bits > 0 {
+        table[prefix] = Entry::Table { offset: table.len() as u32, bits, };
+        table.resize(table.len() + (1 << bits), Entry::Empty,);
+      }
+    }
+
+    for (symbol, (&len, &code,),) in lengths.iter().zip(&codes,).enumerate() {
+      let entry = Entry::Symbol { symbol: symbol as u16, len, };
+
+      if len == 0 { continue }
+      else if len <= root {
+        let start = (code as usize) << (root - len);
+
+        table[start..start + (1 << (root - len))].fill(entry,);
+      } else if let Entry::Table { offset, bits, } = table[(code >> (len - root)) as usize] {
+        let extra = len - root;
+        let low = code as usize & ((1 << extra) - 1);
+        let start = offset as usize + (low << (bits - extra));
+
+        table[start..start + (1 << (bits - extra))].fill(entry,);
+      }
+    }
+
+    Self { table, root, max_len, }
+  }
+  /// Reads code lengths written by `HuffmanEncoder::write_lengths` and builds the
+  /// decoder for them.
+  /// 
+  /// `CodeError::Invalid` is returned if the lengths do not form a valid code.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from.  
+  pub fn read_lengths<R,>(reader: &mut R,) -> Result<Self, CodeError<R::Error,>>
+    where R: BitRead, {
+    let count = reader.read_uleb128()?;
+    if count > 1 << 16 { return Err(CodeError::Overflow) }
+
+    let mut lengths = Vec::with_capacity(count as usize,);
+    for _ in 0..count { lengths.push(reader.read_word(6,)? as u8,); }
+
+    Self::new(&lengths,).map_err(|_,| CodeError::Invalid,)
+  }
+  /// Returns the length of the longest code.
+  #[inline]
+  pub const fn max_len(&self,) -> u8 { self.max_len }
+  /// Reads a symbol from `reader`.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from.  
+  pub fn decode<R,>(&self, reader: &mut R,) -> Result<u16, CodeError<R::Error,>>
+    where R: BitPeek, {
+    let max_len = self.max_len;
+    let (peek, _,) = reader.peek_word(max_len,);
+    //Put the first bit of the code in the high bit.
+    let peek = match R::ORDER {
+      BitOrder::Msb => peek,
+      BitOrder::Lsb => peek.reverse_bits().checked_shr(64 - max_len as u32,).unwrap_or(0,),
+    };
+
+    let mut entry = self.table[(peek >> (max_len - self.root)) as usize];
+    if let Entry::Table { offset, bits, } = entry {
+      let index = (peek >> (max_len - self.root - bits)) as usize & ((1 << bits) - 1);
+
+      entry = self.table[offset as usize + index];
+    }
+
+    match entry {
+      Entry::Symbol { symbol, len, } => { reader.read_word(len,)?; Ok(symbol) },
+      _ => Err(CodeError::Invalid),
+    }
+  }
+}
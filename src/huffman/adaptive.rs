@@ -0,0 +1,213 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use crate::BitWrite;
+
+/// The contents of a node in an adaptive Huffman tree.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum Kind {
+  /// The node standing for every symbol not seen yet.
+  Nyt,
+  /// A symbol which has been seen.
+  Leaf(u16,),
+  /// An internal node and its `0` and `1` children.
+  Internal(u32, u32,),
+}
+
+/// A node in an adaptive Huffman tree.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+struct Node {
+  /// The number of times the symbols below this node have been seen.
+  weight: u64,
+  /// The parent of the position this node is stored at.
+  parent: u32,
+  /// The contents of the node.
+  kind: Kind,
+}
+
+/// An adaptive Huffman tree updated with the FGK algorithm.
+/// 
+/// Nodes are stored in order of decreasing weight with the root first, which is the
+/// sibling property that the update maintains.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+struct AdaptiveTree {
+  /// The nodes of the tree.
+  nodes: Vec<Node>,
+  /// The position of the leaf for each symbol.
+  leaves: Vec<Option<u32>>,
+  /// The position of the NYT node.
+  nyt: u32,
+  /// The number of bits used to send a new symbol.
+  symbol_bits: u8,
+}
+
+impl AdaptiveTree {
+  /// Creates an empty tree over `alphabet` symbols.
+  fn new(alphabet: usize,) -> Self {
+    assert!(0 < alphabet && alphabet <= 1 << 16, "The alphabet must have between 1 and `65536` symbols",);
+
+    let symbol_bits = (usize::BITS - (alphabet - 1).leading_zeros()) as u8;
+    let root = Node { weight: 0, parent: 0, kind: Kind::Nyt, };
+
+    Self { nodes: vec![root], leaves: vec![None; alphabet], nyt: 0, symbol_bits, }
+  }
+  /// Fixes the links to the contents of the node at `index`.
+  fn relink(&mut self, index: u32,) {
+    match self.nodes[index as usize].kind {
+      Kind::Nyt => self.nyt = index,
+      Kind::Leaf(symbol,) => self.leaves[symbol as usize] = Some(index),
+      Kind::Internal(zero, one,) => {
+        self.nodes[zero as usize].parent = index;
+        self.nodes[one as usize].parent = index;
+      },
+    }
+  }
+  /// Counts another occurrence of `symbol` and updates the tree.
+  fn update(&mut self, symbol: u16,) {
+    let mut index = match self.leaves[symbol as usize] {
+      Some(index) => index,
+      //Split the NYT node into a new NYT node and a leaf for the symbol.
+      None => {
+        let parent = self.nyt;
+        let leaf = self.nodes.len() as u32;
+
+        self.nodes[parent as usize].kind = Kind::Internal(leaf + 1, leaf,);
+        self.nodes.push(Node { weight: 0, parent, kind: Kind::Leaf(symbol,), },);
+        self.nodes.push(Node { weight: 0, parent, kind: Kind::Nyt, },);
+        self.relink(leaf,);
+        self.relink(leaf + 1,);
+
+        leaf
+      },
+    };
+
+    loop {
+      let weight = self.nodes[index as usize].weight;
+      //Find the first node with the same weight.
+      let mut leader = index;
+      while leader > 0 && self.nodes[leader as usize - 1].weight == weight { leader -= 1; }
+
+      //Move the node to the front of its block so incrementing it keeps the order.
+      if leader != index && leader != self.nodes[index as usize].parent {
+        let (node, other,) = (self.nodes[index as usize], self.nodes[leader as usize],);
+
+        self.nodes[index as usize].weight = other.weight;
+        self.nodes[index as usize].kind = other.kind;
+        self.nodes[leader as usize].weight = node.weight;
+        self.nodes[leader as usize].kind = node.kind;
+        self.relink(index,);
+        self.relink(leader,);
+
+        index = leader;
+      }
+
+      self.nodes[index as usize].weight += 1;
+      if index == 0 { break }
+      index = self.nodes[index as usize].parent;
+    }
+  }
+}
+
+/// Encodes symbols with an adaptive Huffman code.
+/// 
+/// No code table is sent, the code is updated after every symbol using the FGK
+/// algorithm. The first occurrence of a symbol is sent as the code of the NYT node
+/// followed by the symbol in the fewest bits which can hold every symbol.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct AdaptiveHuffmanEncoder {
+  /// The current code.
+  tree: AdaptiveTree,
+  /// The path from a node to the root.
+  path: Vec<bool>,
+}
+
+impl AdaptiveHuffmanEncoder {
+  /// Creates an encoder over `alphabet` symbols.
+  /// 
+  /// # Params
+  /// 
+  /// alphabet --- The number of symbols, between `1` and `65536`.  
+  pub fn new(alphabet: usize,) -> Self { Self { tree: AdaptiveTree::new(alphabet,), path: Vec::new(), } }
+  /// Returns the number of symbols in the alphabet.
+  #[inline]
+  pub fn alphabet(&self,) -> usize { self.tree.leaves.len() }
+  /// Writes the code for `symbol` and updates the code.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write too.  
+  /// symbol --- The symbol to write, it must be in the alphabet.  
+  pub fn encode<W,>(&mut self, writer: &mut W, symbol: u16,) -> Result<(), W::Error>
+    where W: BitWrite, {
+    assert!((symbol as usize) < self.alphabet(), "{} is not in the alphabet", symbol,);
+
+    let tree = &self.tree;
+    let seen = tree.leaves[symbol as usize];
+    let mut index = seen.unwrap_or(tree.nyt,);
+
+    //Collect the path from the root, it is found in reverse.
+    self.path.clear();
+    while index != 0 {
+      let parent = tree.nodes[index as usize].parent;
+
+      self.path.push(matches!(tree.nodes[parent as usize].kind, Kind::Internal(_, one,) if one == index),);
+      index = parent;
+    }
+    for &bit in self.path.iter().rev() { writer.write_bit(bit,)?; }
+    if seen.is_none() { writer.write_word(tree.symbol_bits, symbol as u64,)?; }
+
+    self.tree.update(symbol,);
+
+    Ok(())
+  }
+}
+
+/// Decodes symbols encoded by an `AdaptiveHuffmanEncoder` over the same alphabet.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct AdaptiveHuffmanDecoder {
+  /// The current code.
+  tree: AdaptiveTree,
+}
+
+impl AdaptiveHuffmanDecoder {
+  /// Creates a decoder over `alphabet` symbols.
+  /// 
+  /// # Params
+  /// 
+  /// alphabet --- The number of symbols, between `1` and `65536`.  
+  pub fn new(alphabet: usize,) -> Self { Self { tree: AdaptiveTree::new(alphabet,), } }
+  /// Returns the number of symbols in the alphabet.
+  #[inline]
+  pub fn alphabet(&self,) -> usize { self.tree.leaves.len() }
+  /// Reads a symbol and updates the code.
+  /// 
+  /// `CodeError::Invalid` is returned if a new symbol is not in the alphabet or has
+  /// already been seen.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to read from.  
+  pub fn decode<R,>(&mut self, reader: &mut R,) -> Result<u16, CodeError<R::Error,>>
+    where R: BitRead, {
+    let mut index = 0;
+    let symbol = loop {
+      match self.tree.nodes[index as usize].kind {
+        Kind::Internal(zero, one,) => index = if reader.read_bit()? { one } else { zero },
+        Kind::Leaf(symbol,) => break symbol,
+        Kind::Nyt => {
+          let symbol = reader.read_word(self.tree.symbol_bits,)?;
+
+          match self.tree.leaves.get(symbol as usize,) {
+            Some(None) => break symbol as u16,
+            _ => return Err(CodeError::Invalid),
+          }
+        },
+      }
+    };
+
+    self.tree.update(symbol,);
+
+    Ok(symbol)
+  }
+}
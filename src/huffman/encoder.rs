@@ -0,0 +1,173 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use crate::{BitWrite, OrderedWrite,};
+
+/// A node built by package-merge.
+#[derive(Clone, Copy,)]
+enum Node {
+  /// A symbol.
+  Leaf(usize,),
+  /// A package of two nodes.
+  Package(u32, u32,),
+}
+
+/// Returns optimal code lengths for symbols with the frequencies with no code longer
+/// than `max_len`, using package-merge.
+/// 
+/// Symbols with a frequency of zero get no code. If only one symbol is used another
+/// symbol is also given a code so that the code is complete.
+/// 
+/// # Params
+/// 
+/// freqs --- The frequency of each symbol.  
+/// max_len --- The longest code allowed.  
+pub fn code_lengths(freqs: &[u64], max_len: u8,) -> Vec<u8> {
+  assert!(0 < max_len && max_len <= MAX_CODE_LEN, "`max_len` must be between 1 and {}", MAX_CODE_LEN,);
+
+  let mut lengths = vec![0u8; freqs.len()];
+  let mut leaves = (0..freqs.len()).filter(|&symbol,| freqs[symbol] > 0,).collect::<Vec<_>>();
+  let count = leaves.len();
+
+  match count {
+    0 => return lengths,
+    1 => {
+      assert!(freqs.len() > 1, "A complete code needs at least two symbols",);
+
+      lengths[leaves[0]] = 1;
+      lengths[if leaves[0] == 0 { 1 } else { 0 }] = 1;
+
+      return lengths
+    },
+    _ => assert!(count as u64 <= 1 << max_len, "{} symbols do not fit in codes of {} bits", count, max_len,),
+  }
+
+  leaves.sort_by_key(|&symbol,| (freqs[symbol], symbol,),);
+
+  let mut nodes = leaves.iter().map(|&symbol,| Node::Leaf(symbol,),).collect::<Vec<_>>();
+  let mut weights = leaves.iter().map(|&symbol,| freqs[symbol] as u128,).collect::<Vec<_>>();
+  //The nodes at the current level, sorted by weight.
+  let mut list = (0..count as u32).collect::<Vec<_>>();
+  //Codes are never longer than `count - 1` bits, fewer levels give the same result.
+  for _ in 1..u64::min(max_len as u64, count as u64 - 1,) {
+    let mut packages = Vec::with_capacity(list.len() / 2,);
+    for pair in list.chunks_exact(2,) {
+      packages.push(nodes.len() as u32,);
+      weights.push(weights[pair[0] as usize] + weights[pair[1] as usize],);
+      nodes.push(Node::Package(pair[0], pair[1],),);
+    }
+
+    //Merge the leaves with the packages, leaves first when the weights are equal.
+    let mut merged = Vec::with_capacity(count + packages.len(),);
+    let (mut leaf, mut package,) = (0, 0,);
+    while leaf < count || package < packages.len() {
+      if package == packages.len()
+        || (leaf < count && weights[leaf] <= weights[packages[package] as usize]) {
+        merged.push(leaf as u32,);
+        leaf += 1;
+      } else {
+        merged.push(packages[package],);
+        package += 1;
+      }
+    }
+
+    list = merged;
+  }
+
+  //Each time a symbol appears in the chosen nodes its code is one bit longer.
+  let mut stack = list[..2 * count - 2].to_vec();
+  while let Some(node) = stack.pop() {
+    match nodes[node as usize] {
+      Node::Leaf(symbol,) => lengths[symbol] += 1,
+      Node::Package(left, right,) => { stack.push(left,); stack.push(right,); },
+    }
+  }
+
+  lengths
+}
+
+/// Encodes symbols with a canonical Huffman code.
+/// 
+/// Codes are assigned in order of length and then symbol so the code can be decoded by
+/// a `HuffmanDecoder` built from the same lengths.
+#[derive(PartialEq, Eq, Clone, Debug,)]
+pub struct HuffmanEncoder {
+  /// The length of the code for each symbol.
+  lengths: Vec<u8>,
+  /// The code for each symbol, first bit highest.
+  codes: Vec<u32>,
+}
+
+impl HuffmanEncoder {
+  /// Builds the encoder for the canonical code with the code lengths.
+  /// 
+  /// # Params
+  /// 
+  /// lengths --- The length of the code for each symbol, zero if the symbol is unused.  
+  pub fn new(lengths: &[u8],) -> Result<Self, HuffmanError> {
+    assert!(lengths.len() <= 1 << 16, "Huffman codes are limited to `65536` symbols",);
+
+    check_lengths(lengths,)?;
+
+    Ok(Self { lengths: lengths.to_vec(), codes: canonical_codes(lengths,), })
+  }
+  /// Builds the encoder for an optimal code for the frequencies with no code longer
+  /// than `max_len`.
+  /// 
+  /// # Params
+  /// 
+  /// freqs --- The frequency of each symbol, at least one must not be zero.  
+  /// max_len --- The longest code allowed.  
+  pub fn from_frequencies(freqs: &[u64], max_len: u8,) -> Self {
+    assert!(freqs.iter().any(|&freq,| freq > 0,), "At least one symbol must be used",);
+
+    Self::new(&code_lengths(freqs, max_len,),).expect("Package-merge produced an invalid code",)
+  }
+  /// Returns the length of the code for each symbol.
+  #[inline]
+  pub fn lengths(&self,) -> &[u8] { &self.lengths }
+  /// Returns the number of bits needed to encode the symbols with the frequencies.
+  /// 
+  /// # Params
+  /// 
+  /// freqs --- The frequency of each symbol.  
+  pub fn bit_len(&self, freqs: &[u64],) -> u64 {
+    freqs.iter().zip(&self.lengths,).map(|(&freq, &len,),| freq * len as u64,).sum()
+  }
+  /// Writes the code for `symbol`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write too.  
+  /// symbol --- The symbol to write, it must have a code.  
+  pub fn encode<W,>(&self, writer: &mut W, symbol: u16,) -> Result<(), W::Error>
+    where W: OrderedWrite, {
+    let len = self.lengths[symbol as usize];
+    assert!(len > 0, "Symbol {} has no code", symbol,);
+
+    let code = self.codes[symbol as usize] as u64;
+    //Write the first bit of the code first.
+    let code = match W::ORDER {
+      BitOrder::Msb => code,
+      BitOrder::Lsb => code.reverse_bits() >> (64 - len as u32),
+    };
+
+    writer.write_word(len, code,).map(|_,| (),)
+  }
+  /// Writes the code lengths so that they can be read by `HuffmanDecoder::read_lengths`.
+  /// 
+  /// The number of symbols is written as unsigned LEB128 followed by each length in `6`
+  /// bits.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to write too.  
+  pub fn write_lengths<W,>(&self, writer: &mut W,) -> Result<(), W::Error>
+    where W: BitWrite, {
+    writer.write_uleb128(self.lengths.len() as u64,)?;
+    for &len in &self.lengths { writer.write_word(6, len as u64,)?; }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,195 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::{BitWrite, ReadIter, ReadLsb, WriteLsb, WriteVec, test_data::Rng,};
+
+/// Code lengths which need second level tables.
+const LONG: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 15,];
+
+/// Returns a fixed sequence of symbols below `count`.
+fn symbols(count: usize,) -> Vec<u16> { Rng::new(12345,).take(500,).map(|value,| (value as usize % count) as u16,).collect() }
+
+#[test]
+fn test_canonical_codes() {
+  assert_eq!(canonical_codes(&[2, 1, 3, 3,],), [0b10, 0b0, 0b110, 0b111,],);
+  assert_eq!(canonical_codes(&[3, 3, 3, 3, 3, 2, 4, 4,],), [2, 3, 4, 5, 6, 0, 14, 15,],);
+}
+
+#[test]
+fn test_check_lengths() {
+  assert_eq!(check_lengths(&[2, 1, 3, 3,],), Ok(()),);
+  assert_eq!(check_lengths(&[1, 1, 1,],), Err(HuffmanError::OverSubscribed),);
+  assert_eq!(check_lengths(&[1, 2, 0,],), Err(HuffmanError::Incomplete),);
+  assert_eq!(check_lengths(&[0, 0,],), Err(HuffmanError::Incomplete),);
+  assert_eq!(check_lengths(&[1, 33,],), Err(HuffmanError::TooLong),);
+  //The longest codes allowed.
+  let mut lengths = (1..=MAX_CODE_LEN).collect::<Vec<_>>();
+  lengths.push(MAX_CODE_LEN,);
+  assert_eq!(check_lengths(&lengths,), Ok(()),);
+  *lengths.last_mut().unwrap() += 1;
+  lengths.push(MAX_CODE_LEN + 1,);
+  assert_eq!(check_lengths(&lengths,), Err(HuffmanError::TooLong),);
+  assert_eq!(HuffmanDecoder::new(&[1,],), Err(HuffmanError::Incomplete),);
+  assert_eq!(HuffmanDecoder::new_incomplete(&[1, 1, 1,],), Err(HuffmanError::OverSubscribed),);
+
+  //Bits which are not a code are rejected.
+  let decoder = HuffmanDecoder::new_incomplete(&[0, 1,],).expect("Error building decoder",);
+  assert_eq!(decoder.decode(&mut ReadIter::new([0x7Fu8,].iter().copied(),),), Ok(1),);
+  assert_eq!(decoder.decode(&mut ReadLsb::new(ReadIter::new([0x01u8,].iter().copied(),),),), Err(CodeError::Invalid),);
+  let decoder = HuffmanDecoder::new_incomplete(&[0, 0,],).expect("Error building decoder",);
+  assert_eq!(decoder.decode(&mut ReadIter::new([0x7Fu8,].iter().copied(),),), Err(CodeError::Invalid),);
+  assert_eq!(decoder.decode(&mut ReadLsb::new(ReadIter::new([0x01u8,].iter().copied(),),),), Err(CodeError::Invalid),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_HuffmanDecoder_msb() {
+  for lengths in [&[2, 1, 3, 3,][..], &LONG,] {
+    let decoder = HuffmanDecoder::new(lengths,).expect("Error building decoder",);
+    let codes = canonical_codes(lengths,);
+    let symbols = symbols(lengths.len(),);
+
+    let mut writer = WriteVec::new();
+    for &symbol in &symbols {
+      writer.write_word(lengths[symbol as usize], codes[symbol as usize] as u64,).expect("Error writing",);
+    }
+    let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+
+    let mut reader = ReadIter::new(bytes.iter().copied(),);
+    for &symbol in &symbols {
+      assert_eq!(decoder.decode(&mut reader,), Ok(symbol),);
+    }
+
+    let mut reader = ReadIter::new(bytes[..0].iter().copied(),);
+    assert!(matches!(decoder.decode(&mut reader,), Err(CodeError::Read(_,)),),);
+  }
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_HuffmanDecoder_lsb() {
+  for lengths in [&[2, 1, 3, 3,][..], &LONG,] {
+    let decoder = HuffmanDecoder::new(lengths,).expect("Error building decoder",);
+    let codes = canonical_codes(lengths,);
+    let symbols = symbols(lengths.len(),);
+
+    //Pack the codes low bits first, starting with the first bit of each code.
+    let mut bytes = Vec::new();
+    let mut bit = 0;
+    for &symbol in &symbols {
+      let len = lengths[symbol as usize];
+      let code = codes[symbol as usize];
+
+      for index in (0..len).rev() {
+        if bit % 8 == 0 { bytes.push(0,); }
+        *bytes.last_mut().unwrap() |= (((code >> index) & 1) as u8) << (bit % 8);
+        bit += 1;
+      }
+    }
+
+    let mut reader = ReadLsb::new(ReadIter::new(bytes.iter().copied(),),);
+    for &symbol in &symbols {
+      assert_eq!(decoder.decode(&mut reader,), Ok(symbol),);
+    }
+  }
+}
+
+#[test]
+fn test_code_lengths() {
+  //Unlimited the code is 1, 2, 3, ... bits long.
+  let freqs = [1, 1, 2, 3, 5, 8, 13, 21, 34, 55,];
+
+  assert_eq!(code_lengths(&freqs, 15,), [9, 9, 8, 7, 6, 5, 4, 3, 2, 1,],);
+  let lengths = code_lengths(&freqs, 5,);
+  assert!(lengths.iter().all(|&len,| len <= 5,),);
+  assert_eq!(check_lengths(&lengths,), Ok(()),);
+  assert_eq!(lengths, [5, 5, 5, 5, 4, 4, 3, 3, 2, 2,],);
+
+  assert_eq!(code_lengths(&[0, 3, 0,], 4,), [1, 1, 0,],);
+  assert_eq!(code_lengths(&[0, 0, 0,], 4,), [0, 0, 0,],);
+  assert_eq!(code_lengths(&[1, 1, 1, 1,], 2,), [2, 2, 2, 2,],);
+  assert_eq!(code_lengths(&[1, 0, 1, 1,], 8,), [2, 0, 2, 1,],);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_HuffmanEncoder() {
+  let symbols = symbols(300,);
+  let mut freqs = [0u64; 300];
+  for &symbol in &symbols { freqs[symbol as usize] += 1; }
+
+  let encoder = HuffmanEncoder::from_frequencies(&freqs, 9,);
+  assert!(encoder.lengths().iter().all(|&len,| len <= 9,),);
+
+  //High bits first.
+  let mut writer = WriteVec::new();
+  encoder.write_lengths(&mut writer,).expect("Error writing",);
+  for &symbol in &symbols { encoder.encode(&mut writer, symbol,).expect("Error writing",); }
+  let bits = encoder.bit_len(&freqs,);
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+
+  let mut reader = ReadIter::new(bytes.iter().copied(),);
+  let decoder = HuffmanDecoder::read_lengths(&mut reader,).expect("Error reading lengths",);
+  assert_eq!(decoder, HuffmanDecoder::new(encoder.lengths(),).unwrap(),);
+  for &symbol in &symbols {
+    assert_eq!(decoder.decode(&mut reader,), Ok(symbol),);
+  }
+
+  //Low bits first.
+  let mut writer = WriteLsb::new(WriteVec::new(),);
+  for &symbol in &symbols { encoder.encode(&mut writer, symbol,).expect("Error writing",); }
+  writer.pad_zeros().flush().expect("Error flushing",);
+  let (writer, _,) = writer.into_inner().expect("Error unwrapping",);
+  let bytes = writer.into_vec().expect("Error unwrapping",);
+  assert_eq!(bytes.len() as u64, bits.div_ceil(8,),);
+
+  let mut reader = ReadLsb::new(ReadIter::new(bytes.iter().copied(),),);
+  for &symbol in &symbols {
+    assert_eq!(decoder.decode(&mut reader,), Ok(symbol),);
+  }
+
+  assert_eq!(HuffmanEncoder::new(&[1, 1, 1,],), Err(HuffmanError::OverSubscribed),);
+}
+
+#[allow(non_snake_case,)]
+#[test]
+fn test_AdaptiveHuffman() {
+  for &alphabet in &[1, 2, 3, 256, 300,] {
+    //Skew the symbols so the code adapts.
+    let symbols = symbols(alphabet,).into_iter()
+      .map(|symbol,| (symbol % 16).trailing_zeros().min(4,).min(alphabet as u32 - 1,) as u16,)
+      .collect::<Vec<_>>();
+    let mut encoder = AdaptiveHuffmanEncoder::new(alphabet,);
+    let mut writer = WriteVec::new();
+
+    for &symbol in &symbols { encoder.encode(&mut writer, symbol,).expect("Error writing",); }
+    let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+    if alphabet > 2 { assert!(bytes.len() * 8 < symbols.len() * 5 / 2,); }
+
+    let mut decoder = AdaptiveHuffmanDecoder::new(alphabet,);
+    let mut reader = ReadIter::new(bytes.iter().copied(),);
+    for &symbol in &symbols {
+      assert_eq!(decoder.decode(&mut reader,), Ok(symbol),);
+    }
+  }
+
+  let symbols = symbols(300,);
+  let mut encoder = AdaptiveHuffmanEncoder::new(300,);
+  let mut writer = WriteVec::new();
+  for &symbol in &symbols { encoder.encode(&mut writer, symbol,).expect("Error writing",); }
+  let bytes = writer.pad_zeros().clone().into_vec().expect("Error unwrapping",);
+
+  let mut decoder = AdaptiveHuffmanDecoder::new(300,);
+  let mut reader = ReadIter::new(bytes.iter().copied(),);
+  for &symbol in &symbols {
+    assert_eq!(decoder.decode(&mut reader,), Ok(symbol),);
+  }
+
+  //A new symbol outside of the alphabet.
+  let mut decoder = AdaptiveHuffmanDecoder::new(3,);
+  let mut reader = ReadIter::new([0b1100_0000u8,].iter().copied(),);
+  assert_eq!(decoder.decode(&mut reader,), Err(CodeError::Invalid),);
+}
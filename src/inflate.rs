@@ -0,0 +1,614 @@
+//! Decodes DEFLATE streams and the zlib and gzip formats which wrap them.
+//! 
+//! DEFLATE is read low bits first so every decoder reads from a `ReadLsb`, with
+//! `--features std` the decoders also implement `std::io::Read` over a `ReadIO`.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitPeek, ReadLsb, ReadIter, Bits, CodeError, HuffmanDecoder, HuffmanError, Adler32, Crc32, huffman::check_lengths,};
+use alloc::{vec, vec::Vec, boxed::Box,};
+use core::{fmt, iter::Copied, slice::Iter,};
+
+mod tests;
+#[cfg(feature = "std",)]
+mod stream;
+
+/// The number of bytes a distance can reach back.
+pub(crate) const WINDOW_SIZE: usize = 1 << 15;
+/// The shortest length of each length symbol.
+pub(crate) const LENGTH_BASE: [u16; 29] = [
+  3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+/// The number of extra bits of each length symbol.
+pub(crate) const LENGTH_EXTRA: [u8; 29] = [
+  0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+/// The shortest distance of each distance symbol.
+pub(crate) const DISTANCE_BASE: [u16; 30] = [
+  1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097,
+  6145, 8193, 12289, 16385, 24577,
+];
+/// The number of extra bits of each distance symbol.
+pub(crate) const DISTANCE_EXTRA: [u8; 30] = [
+  0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13,
+];
+/// The order the lengths of the code length code are sent in.
+pub(crate) const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,];
+
+/// Returns the code lengths of the fixed literal/length and distance codes.
+pub(crate) fn fixed_lengths() -> ([u8; 288], [u8; 32],) {
+  let mut literals = [8; 288];
+  literals[144..256].fill(9,);
+  literals[256..280].fill(7,);
+
+  (literals, [5; 32],)
+}
+
+/// The error returned when decoding a compressed stream fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum InflateError<E,> {
+  /// Reading from the input failed.
+  Read(E),
+  /// A block used the reserved block type.
+  BlockType,
+  /// The length of a stored block did not match its complement.
+  StoredLength,
+  /// The code lengths of a dynamic block do not form a valid code.
+  CodeLengths,
+  /// The compressed data contained an invalid code.
+  Code,
+  /// A distance reached back before the start of the output.
+  Distance,
+  /// The zlib or gzip header is invalid or uses an unsupported feature.
+  Header,
+  /// The checksum or length of the output did not match the trailer.
+  Checksum,
+}
+
+impl<E,> InflateError<E,> {
+  /// Converts an error decoding a symbol of the compressed data.
+  fn from_code(from: CodeError<E,>,) -> Self {
+    match from {
+      CodeError::Read(e,) => InflateError::Read(e,),
+      _ => InflateError::Code,
+    }
+  }
+}
+
+impl<E,> From<E> for InflateError<E,> {
+  #[inline]
+  fn from(from: E,) -> Self { InflateError::Read(from,) }
+}
+
+impl<E,> fmt::Display for InflateError<E,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.write_str(match self {
+      InflateError::Read(_,) => "reading the compressed input failed",
+      InflateError::BlockType => "invalid block type",
+      InflateError::StoredLength => "invalid stored block length",
+      InflateError::CodeLengths => "invalid code lengths",
+      InflateError::Code => "invalid code in compressed data",
+      InflateError::Distance => "distance too far back",
+      InflateError::Header => "invalid or unsupported header",
+      InflateError::Checksum => "checksum mismatch",
+    },)
+  }
+}
+
+/// The error type of the decoders reading from `R`.
+pub type InflateErrorOf<R,> = InflateError<<R as BitRead>::Error,>;
+
+/// Builds the decoder for a literal/length or distance code.
+/// 
+/// As in zlib an incomplete code is only accepted if it has at most one symbol.
+fn deflate_code<E,>(lengths: &[u8],) -> Result<HuffmanDecoder, InflateError<E,>> {
+  match check_lengths(lengths,) {
+    Err(HuffmanError::Incomplete) if lengths.iter().filter(|&&len,| len > 0,).count() > 1 => Err(InflateError::CodeLengths),
+    _ => HuffmanDecoder::new_incomplete(lengths,).map_err(|_,| InflateError::CodeLengths,),
+  }
+}
+
+/// The part of the stream being decoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum State {
+  /// The next bits are a block header.
+  Header,
+  /// Within a stored block with the bytes remaining.
+  Stored(u16,),
+  /// Within a Huffman coded block.
+  Block,
+  /// The last block has ended.
+  Done,
+}
+
+/// Decodes a raw DEFLATE stream.
+/// 
+/// Output is produced on demand so the decompressed data never needs to be held in
+/// memory, only the last `32KiB` of output is kept.
+/// 
+/// After an error the decoder should not be used.
+#[derive(Clone, Debug,)]
+pub struct Inflater<R,>
+  where R: BitRead, {
+  /// The reader to decode from.
+  reader: ReadLsb<R,>,
+  /// The part of the stream being decoded.
+  state: State,
+  /// Whether the current block is the last.
+  last: bool,
+  /// The literal/length code of the current block.
+  literals: HuffmanDecoder,
+  /// The distance code of the current block.
+  distances: HuffmanDecoder,
+  /// The last `WINDOW_SIZE` bytes of output.
+  window: Box<[u8]>,
+  /// The number of bytes output.
+  total: u64,
+  /// The bytes of a match left to copy.
+  copy_len: usize,
+  /// The distance of the match being copied.
+  copy_dist: usize,
+}
+
+impl<R,> Inflater<R,>
+  where R: BitRead, {
+  /// Constructs a new `Inflater` which decodes from `reader`.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn new(reader: ReadLsb<R,>,) -> Self {
+    let empty = HuffmanDecoder::new_incomplete(&[],).expect("The empty code is valid",);
+
+    Self {
+      reader,
+      state: State::Header,
+      last: false,
+      literals: empty.clone(),
+      distances: empty,
+      window: vec![0; WINDOW_SIZE].into_boxed_slice(),
+      total: 0,
+      copy_len: 0,
+      copy_dist: 0,
+    }
+  }
+  /// Returns `true` if the last block has been decoded.
+  #[inline]
+  pub fn is_done(&self,) -> bool { self.state == State::Done && self.copy_len == 0 }
+  /// Returns the number of bytes decoded so far.
+  #[inline]
+  pub const fn total_out(&self,) -> u64 { self.total }
+  /// Returns a reference to the reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &ReadLsb<R,> { &self.reader }
+  /// Unwraps the reader.
+  /// 
+  /// Once the stream is done the reader is positioned just after the last block.
+  #[inline]
+  pub fn into_inner(self,) -> ReadLsb<R,> { self.reader }
+  /// Decodes bytes into `out`.
+  /// 
+  /// Returns the number of bytes decoded which is only `0` once the stream is done or
+  /// `out` is empty.
+  /// 
+  /// # Params
+  /// 
+  /// out --- The buffer to decode into.  
+  pub fn read(&mut self, out: &mut [u8],) -> Result<usize, InflateErrorOf<R,>> {
+    let mut written = 0;
+    while written < out.len() {
+      //Finish copying a match.
+      if self.copy_len > 0 {
+        let len = usize::min(self.copy_len, out.len() - written,);
+        for byte in &mut out[written..written + len] {
+          *byte = self.window[(self.total as usize).wrapping_sub(self.copy_dist,) % WINDOW_SIZE];
+          self.push(*byte,);
+        }
+
+        written += len;
+        self.copy_len -= len;
+        continue;
+      }
+
+      match self.state {
+        State::Done => break,
+        State::Header => self.read_header()?,
+        State::Stored(remaining,) => {
+          let len = usize::min(remaining as usize, out.len() - written,);
+          let bytes = &mut out[written..written + len];
+
+          self.reader.read_bytes(bytes,).map_err(|(_, e,),| InflateError::Read(e,),)?;
+          for &byte in bytes.iter() {
+            self.window[self.total as usize % WINDOW_SIZE] = byte;
+            self.total += 1;
+          }
+
+          written += len;
+          self.state = State::Stored(remaining - len as u16,);
+          if remaining as usize == len { self.end_block() }
+        },
+        State::Block => match self.literals.decode(&mut self.reader,).map_err(InflateError::from_code,)? {
+          symbol @ 0..=255 => {
+            out[written] = symbol as u8;
+            self.push(symbol as u8,);
+            written += 1;
+          },
+          256 => self.end_block(),
+          symbol @ 257..=285 => {
+            let index = symbol as usize - 257;
+            let len = LENGTH_BASE[index] as usize + self.reader.read_word(LENGTH_EXTRA[index],)? as usize;
+            let index = self.distances.decode(&mut self.reader,).map_err(InflateError::from_code,)? as usize;
+            if index >= DISTANCE_BASE.len() { return Err(InflateError::Code) }
+
+            let dist = DISTANCE_BASE[index] as usize + self.reader.read_word(DISTANCE_EXTRA[index],)? as usize;
+            if dist as u64 > self.total { return Err(InflateError::Distance) }
+
+            self.copy_len = len;
+            self.copy_dist = dist;
+          },
+          _ => return Err(InflateError::Code),
+        },
+      }
+    }
+
+    Ok(written)
+  }
+  /// Adds a byte to the window.
+  #[inline]
+  fn push(&mut self, byte: u8,) {
+    self.window[self.total as usize % WINDOW_SIZE] = byte;
+    self.total += 1;
+  }
+  /// Moves past the end of a block.
+  fn end_block(&mut self,) {
+    self.state = if self.last { State::Done } else { State::Header };
+  }
+  /// Prepares to decode another stream.
+  fn reset(&mut self,) {
+    self.state = State::Header;
+    self.last = false;
+    self.total = 0;
+    self.copy_len = 0;
+  }
+  /// Reads a block header and any code it defines.
+  fn read_header(&mut self,) -> Result<(), InflateErrorOf<R,>> {
+    let header = self.reader.read_word(3,)?;
+
+    self.last = header & 1 != 0;
+    match header >> 1 {
+      0 => {
+        self.reader.clear_buf();
+
+        let len = self.reader.read_word(16,)? as u16;
+        if len != !self.reader.read_word(16,)? as u16 { return Err(InflateError::StoredLength) }
+
+        self.state = State::Stored(len,);
+        if len == 0 { self.end_block() }
+      },
+      1 => {
+        let (literals, distances,) = fixed_lengths();
+
+        self.literals = deflate_code(&literals,)?;
+        self.distances = deflate_code(&distances,)?;
+        self.state = State::Block;
+      },
+      2 => {
+        self.read_dynamic()?;
+        self.state = State::Block;
+      },
+      _ => return Err(InflateError::BlockType),
+    }
+
+    Ok(())
+  }
+  /// Reads the codes of a dynamic block.
+  fn read_dynamic(&mut self,) -> Result<(), InflateErrorOf<R,>> {
+    let literals = self.reader.read_word(5,)? as usize + 257;
+    let distances = self.reader.read_word(5,)? as usize + 1;
+    let code_lengths = self.reader.read_word(4,)? as usize + 4;
+    if literals > 286 || distances > 30 { return Err(InflateError::CodeLengths) }
+
+    let mut lengths = [0u8; 19];
+    for &symbol in &CODE_LENGTH_ORDER[..code_lengths] {
+      lengths[symbol] = self.reader.read_word(3,)? as u8;
+    }
+    let code = HuffmanDecoder::new(&lengths,).map_err(|_,| InflateError::CodeLengths,)?;
+
+    //The literal/length and distance lengths are sent as one sequence.
+    let mut lengths = [0u8; 286 + 30];
+    let count = literals + distances;
+    let mut index = 0;
+    while index < count {
+      let (len, repeat,) = match code.decode(&mut self.reader,) {
+        Ok(symbol @ 0..=15) => (symbol as u8, 1,),
+        Ok(16) if index > 0 => (lengths[index - 1], 3 + self.reader.read_word(2,)? as usize,),
+        Ok(17) => (0, 3 + self.reader.read_word(3,)? as usize,),
+        Ok(18) => (0, 11 + self.reader.read_word(7,)? as usize,),
+        Err(CodeError::Read(e,)) => return Err(InflateError::Read(e,)),
+        _ => return Err(InflateError::CodeLengths),
+      };
+      if index + repeat > count { return Err(InflateError::CodeLengths) }
+
+      lengths[index..index + repeat].fill(len,);
+      index += repeat;
+    }
+    //There must be a code for the end of the block.
+    if lengths[256] == 0 { return Err(InflateError::CodeLengths) }
+
+    self.literals = deflate_code(&lengths[..literals],)?;
+    self.distances = deflate_code(&lengths[literals..count],)?;
+
+    Ok(())
+  }
+}
+
+/// The part of a wrapped stream being decoded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+enum Wrapper {
+  /// The next bytes are a header.
+  Header,
+  /// Within the compressed data.
+  Body,
+  /// The stream has ended.
+  Done,
+}
+
+/// Reads `N` whole bytes.
+fn read_array<R, const N: usize,>(reader: &mut ReadLsb<R,>,) -> Result<[u8; N], InflateErrorOf<R,>>
+  where R: BitRead, {
+  let mut bytes = [0; N];
+  reader.read_bytes(&mut bytes,).map_err(|(_, e,),| InflateError::Read(e,),)?;
+
+  Ok(bytes)
+}
+
+/// Decodes a zlib stream, checking its Adler-32 checksum.
+/// 
+/// Streams which need a preset dictionary are not supported.
+#[derive(Clone, Debug,)]
+pub struct ZlibDecoder<R,>
+  where R: BitRead, {
+  /// The DEFLATE decoder.
+  inflater: Inflater<R,>,
+  /// The checksum of the output.
+  checksum: Adler32,
+  /// The part of the stream being decoded.
+  state: Wrapper,
+}
+
+impl<R,> ZlibDecoder<R,>
+  where R: BitRead, {
+  /// Constructs a new `ZlibDecoder` which decodes from `reader`.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from, it should be aligned.  
+  pub fn new(reader: ReadLsb<R,>,) -> Self {
+    Self { inflater: Inflater::new(reader,), checksum: Adler32::new(), state: Wrapper::Header, }
+  }
+  /// Returns `true` if the stream and its trailer have been read.
+  #[inline]
+  pub fn is_done(&self,) -> bool { self.state == Wrapper::Done }
+  /// Unwraps the reader.
+  /// 
+  /// Once the stream is done the reader is positioned just after the trailer.
+  #[inline]
+  pub fn into_inner(self,) -> ReadLsb<R,> { self.inflater.into_inner() }
+  /// Decodes bytes into `out`.
+  /// 
+  /// Returns the number of bytes decoded which is only `0` once the stream is done or
+  /// `out` is empty.
+  /// 
+  /// # Params
+  /// 
+  /// out --- The buffer to decode into.  
+  pub fn read(&mut self, out: &mut [u8],) -> Result<usize, InflateErrorOf<R,>> {
+    if out.is_empty() { return Ok(0) }
+
+    loop {
+      match self.state {
+        Wrapper::Header => {
+          let [method, flags,] = read_array(&mut self.inflater.reader,)?;
+
+          if method & 0x0F != 8 || method >> 4 > 7 || flags & 0x20 != 0
+            || u16::from_be_bytes([method, flags,],) % 31 != 0 {
+            return Err(InflateError::Header)
+          }
+
+          self.state = Wrapper::Body;
+        },
+        Wrapper::Body => {
+          let read = self.inflater.read(out,)?;
+          if read > 0 {
+            self.checksum.update(&out[..read],);
+
+            return Ok(read)
+          }
+
+          self.inflater.reader.clear_buf();
+          if u32::from_be_bytes(read_array(&mut self.inflater.reader,)?,) != self.checksum.value() {
+            return Err(InflateError::Checksum)
+          }
+
+          self.state = Wrapper::Done;
+        },
+        Wrapper::Done => return Ok(0),
+      }
+    }
+  }
+}
+
+/// The gzip header flag for a text file.
+const FTEXT: u8 = 0x01;
+/// The gzip header flag for a header checksum.
+const FHCRC: u8 = 0x02;
+/// The gzip header flag for extra fields.
+const FEXTRA: u8 = 0x04;
+/// The gzip header flag for a file name.
+const FNAME: u8 = 0x08;
+/// The gzip header flag for a comment.
+const FCOMMENT: u8 = 0x10;
+
+/// Decodes a gzip stream, checking the CRC-32 and length of every member.
+/// 
+/// Concatenated members are decoded as one stream.
+#[derive(Clone, Debug,)]
+pub struct GzipDecoder<R,>
+  where R: BitRead, {
+  /// The DEFLATE decoder.
+  inflater: Inflater<R,>,
+  /// The checksum of the output of the current member.
+  checksum: Crc32,
+  /// The part of the stream being decoded.
+  state: Wrapper,
+}
+
+impl<R,> GzipDecoder<R,>
+  where R: BitRead, {
+  /// Constructs a new `GzipDecoder` which decodes from `reader`.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from, it should be aligned.  
+  pub fn new(reader: ReadLsb<R,>,) -> Self {
+    Self { inflater: Inflater::new(reader,), checksum: Crc32::new(), state: Wrapper::Header, }
+  }
+  /// Returns `true` if every member has been read.
+  #[inline]
+  pub fn is_done(&self,) -> bool { self.state == Wrapper::Done }
+  /// Unwraps the reader.
+  #[inline]
+  pub fn into_inner(self,) -> ReadLsb<R,> { self.inflater.into_inner() }
+  /// Decodes bytes into `out`.
+  /// 
+  /// Returns the number of bytes decoded which is only `0` once the stream is done or
+  /// `out` is empty.
+  /// 
+  /// # Params
+  /// 
+  /// out --- The buffer to decode into.  
+  pub fn read(&mut self, out: &mut [u8],) -> Result<usize, InflateErrorOf<R,>> {
+    if out.is_empty() { return Ok(0) }
+
+    loop {
+      match self.state {
+        Wrapper::Header => {
+          self.read_header()?;
+          self.state = Wrapper::Body;
+        },
+        Wrapper::Body => {
+          let read = self.inflater.read(out,)?;
+          if read > 0 {
+            self.checksum.update(&out[..read],);
+
+            return Ok(read)
+          }
+
+          let reader = &mut self.inflater.reader;
+          reader.clear_buf();
+          let checksum = u32::from_le_bytes(read_array(reader,)?,);
+          let len = u32::from_le_bytes(read_array(reader,)?,);
+          if checksum != self.checksum.value() || len != self.inflater.total as u32 {
+            return Err(InflateError::Checksum)
+          }
+
+          //Another member may follow.
+          if reader.peek_word(8,).1 == 0 {
+            self.state = Wrapper::Done;
+          } else {
+            self.inflater.reset();
+            self.checksum = Crc32::new();
+            self.state = Wrapper::Header;
+          }
+        },
+        Wrapper::Done => return Ok(0),
+      }
+    }
+  }
+  /// Reads and checks a member header.
+  fn read_header(&mut self,) -> Result<(), InflateErrorOf<R,>> {
+    let reader = &mut self.inflater.reader;
+    let mut checksum = Crc32::new();
+    let mut read_byte = |reader: &mut ReadLsb<R,>,| -> Result<u8, InflateErrorOf<R,>> {
+      let byte = reader.read_byte()?;
+      checksum.update(&[byte,],);
+
+      Ok(byte)
+    };
+
+    let mut header = [0; 10];
+    for byte in &mut header { *byte = read_byte(reader,)?; }
+    let flags = header[3];
+    if header[..3] != [0x1F, 0x8B, 8,] || flags & !(FTEXT | FHCRC | FEXTRA | FNAME | FCOMMENT) != 0 {
+      return Err(InflateError::Header)
+    }
+
+    if flags & FEXTRA != 0 {
+      let len = u16::from_le_bytes([read_byte(reader,)?, read_byte(reader,)?,],);
+      for _ in 0..len { read_byte(reader,)?; }
+    }
+    if flags & FNAME != 0 { while read_byte(reader,)? != 0 {} }
+    if flags & FCOMMENT != 0 { while read_byte(reader,)? != 0 {} }
+    if flags & FHCRC != 0 {
+      let expected = checksum.value() as u16;
+
+      if u16::from_le_bytes(read_array(reader,)?,) != expected { return Err(InflateError::Header) }
+    }
+
+    Ok(())
+  }
+}
+
+/// A reader over a slice of bytes.
+type SliceReader<'a,> = ReadIter<Copied<Iter<'a, u8,>,>,>;
+
+/// Reads all of the output of a decoder.
+fn read_to_end<E,>(mut read: impl FnMut(&mut [u8],) -> Result<usize, InflateError<E,>>,) -> Result<Vec<u8>, InflateError<E,>> {
+  let mut out = Vec::new();
+  let mut len = 0;
+  loop {
+    if out.len() - len < WINDOW_SIZE { out.resize(len + WINDOW_SIZE, 0,); }
+
+    match read(&mut out[len..],)? {
+      0 => break,
+      read => len += read,
+    }
+  }
+  out.truncate(len,);
+
+  Ok(out)
+}
+
+/// Decodes a raw DEFLATE stream held in memory.
+/// 
+/// # Params
+/// 
+/// bytes --- The compressed bytes.  
+pub fn inflate(bytes: &[u8],) -> Result<Vec<u8>, InflateError<Option<Bits>,>> {
+  let mut inflater = Inflater::new(ReadLsb::new(SliceReader::new(bytes.iter().copied(),),),);
+
+  read_to_end(|out,| inflater.read(out,),)
+}
+
+/// Decodes a zlib stream held in memory.
+/// 
+/// # Params
+/// 
+/// bytes --- The compressed bytes.  
+pub fn zlib_decompress(bytes: &[u8],) -> Result<Vec<u8>, InflateError<Option<Bits>,>> {
+  let mut decoder = ZlibDecoder::new(ReadLsb::new(SliceReader::new(bytes.iter().copied(),),),);
+
+  read_to_end(|out,| decoder.read(out,),)
+}
+
+/// Decodes a gzip stream held in memory.
+/// 
+/// # Params
+/// 
+/// bytes --- The compressed bytes.  
+pub fn gzip_decompress(bytes: &[u8],) -> Result<Vec<u8>, InflateError<Option<Bits>,>> {
+  let mut decoder = GzipDecoder::new(ReadLsb::new(SliceReader::new(bytes.iter().copied(),),),);
+
+  read_to_end(|out,| decoder.read(out,),)
+}
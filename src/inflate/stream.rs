@@ -0,0 +1,65 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use super::*;
+use crate::ReadIO;
+use std::io::{self, Read, ErrorKind,};
+
+/// The error of a `ReadIO`.
+type IOError = (Option<Bits>, io::Error,);
+
+/// Converts an error decoding from a `ReadIO` into an IO error.
+fn into_io(error: InflateError<IOError,>,) -> io::Error {
+  match error {
+    InflateError::Read((_, e,),) => e,
+    e => io::Error::new(ErrorKind::InvalidData, e.to_string(),),
+  }
+}
+
+impl<R,> Inflater<ReadIO<R,>,>
+  where R: Read, {
+  /// Constructs a new `Inflater` which decodes from an IO reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn from_reader(reader: R,) -> Self { Self::new(ReadLsb::new(ReadIO::new(reader,),),) }
+}
+
+impl<R,> Read for Inflater<R,>
+  where R: BitRead<Error = IOError,>, {
+  #[inline]
+  fn read(&mut self, buf: &mut [u8],) -> io::Result<usize> { Inflater::read(self, buf,).map_err(into_io,) }
+}
+
+impl<R,> ZlibDecoder<ReadIO<R,>,>
+  where R: Read, {
+  /// Constructs a new `ZlibDecoder` which decodes from an IO reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn from_reader(reader: R,) -> Self { Self::new(ReadLsb::new(ReadIO::new(reader,),),) }
+}
+
+impl<R,> Read for ZlibDecoder<R,>
+  where R: BitRead<Error = IOError,>, {
+  #[inline]
+  fn read(&mut self, buf: &mut [u8],) -> io::Result<usize> { ZlibDecoder::read(self, buf,).map_err(into_io,) }
+}
+
+impl<R,> GzipDecoder<ReadIO<R,>,>
+  where R: Read, {
+  /// Constructs a new `GzipDecoder` which decodes from an IO reader.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  pub fn from_reader(reader: R,) -> Self { Self::new(ReadLsb::new(ReadIO::new(reader,),),) }
+}
+
+impl<R,> Read for GzipDecoder<R,>
+  where R: BitRead<Error = IOError,>, {
+  #[inline]
+  fn read(&mut self, buf: &mut [u8],) -> io::Result<usize> { GzipDecoder::read(self, buf,).map_err(into_io,) }
+}
@@ -0,0 +1,145 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::test_data::text;
+
+/// The compressed text is `text(400)`.
+const STORED: [u8; 17] = [
+  0x01, 0x0C, 0x00, 0xF3, 0xFF, 0x73, 0x74, 0x6F, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73,
+];
+const FIXED: [u8; 7] = [
+  0x4B, 0x4C, 0x4A, 0x4E, 0x44, 0x42, 0x00,
+];
+const BLOCKS: [u8; 365] = [
+  0x7C, 0x93, 0x4B, 0x0E, 0xC3, 0x20, 0x0C, 0x44, 0xF7, 0x3D, 0x05, 0x57, 0x03, 0x35, 0x4A, 0x2B, 0x91, 0x36, 0x52, 0xB3, 0xE2, 0xF4, 0x55, 0xB1,
+  0x28, 0xCF, 0x30, 0xC9, 0xC6, 0xF1, 0x7F, 0xC6, 0x36, 0x59, 0xE3, 0xB6, 0xC5, 0x10, 0xF3, 0xFE, 0x88, 0xE1, 0xBE, 0xE4, 0x23, 0xDE, 0xA8, 0xA7,
+  0xE5, 0x68, 0xEA, 0xB2, 0x7F, 0x9E, 0xF9, 0xFD, 0x0A, 0xED, 0x5B, 0x7E, 0xA1, 0x66, 0x58, 0xCA, 0x5A, 0x7B, 0x99, 0xEE, 0x8B, 0x94, 0xC5, 0xEC,
+  0x8A, 0x53, 0x3B, 0x9A, 0xB7, 0xDA, 0x46, 0xC4, 0xA4, 0x44, 0x2D, 0x63, 0x96, 0x76, 0x78, 0xF0, 0xD4, 0x51, 0xCA, 0x1F, 0xF5, 0x62, 0x28, 0x6F,
+  0x89, 0x04, 0xCA, 0xC4, 0x70, 0xFB, 0x1A, 0x15, 0x8E, 0xCB, 0x15, 0xA3, 0xF0, 0x74, 0x6A, 0xD7, 0x16, 0xDC, 0xD9, 0xD9, 0xF4, 0xDE, 0x07, 0x69,
+  0x53, 0x82, 0x9F, 0xAC, 0x0C, 0x35, 0x38, 0x01, 0x1D, 0x24, 0x6D, 0x1E, 0x71, 0x07, 0xA0, 0x8E, 0x6B, 0x48, 0x23, 0x15, 0x17, 0x98, 0x88, 0x12,
+  0xBA, 0x1F, 0x0A, 0x07, 0xD5, 0x2B, 0x63, 0x99, 0x7C, 0xB2, 0x3E, 0x34, 0xDF, 0xE6, 0x62, 0xE7, 0x67, 0x27, 0x14, 0x13, 0xAA, 0x42, 0x1F, 0xE9,
+  0xF4, 0x05, 0xBD, 0x59, 0xE2, 0xF9, 0x75, 0x21, 0xFE, 0x0D, 0x0E, 0xC4, 0x77, 0xF0, 0x05, 0x00, 0x00, 0xFF, 0xFF, 0x8D, 0x96, 0xB9, 0x01, 0xC0,
+  0x30, 0x0C, 0x02, 0x77, 0xD5, 0x18, 0x9E, 0x3E, 0xA5, 0x11, 0x9C, 0x14, 0x37, 0x69, 0xE2, 0x4F, 0x08, 0xCE, 0xBE, 0xFF, 0x22, 0xD9, 0x11, 0x95,
+  0x3C, 0x95, 0x76, 0x3D, 0x7D, 0x30, 0xB5, 0x73, 0x38, 0xCB, 0xAD, 0x9C, 0xCC, 0xD4, 0x36, 0xEF, 0x2B, 0xF7, 0xDC, 0x69, 0xD7, 0x65, 0x35, 0x5D,
+  0x33, 0x05, 0xCC, 0x31, 0x35, 0x08, 0xB9, 0xA0, 0x20, 0x6D, 0xDA, 0xE7, 0xBB, 0x69, 0x1F, 0x92, 0x17, 0x69, 0xD3, 0x83, 0x6A, 0xA5, 0xE2, 0x83,
+  0xB2, 0xB5, 0xCB, 0x02, 0x12, 0x4C, 0x6C, 0xD2, 0x7A, 0x7C, 0x48, 0xDC, 0x49, 0xCB, 0x63, 0xC6, 0x82, 0xAC, 0x38, 0xD7, 0x6B, 0x67, 0xAD, 0xDB,
+  0x83, 0xF2, 0x19, 0x9E, 0x62, 0xD0, 0x8A, 0x02, 0xC8, 0x21, 0x2A, 0x34, 0xC0, 0x47, 0xFB, 0x0F, 0x5C, 0xF4, 0x4A, 0xBB, 0x0E, 0x74, 0x57, 0x44,
+  0xA4, 0x60, 0x9E, 0x74, 0x6C, 0xBD, 0x9C, 0x83, 0x9B, 0x87, 0x89, 0x18, 0x59, 0x64, 0xF1, 0xF2, 0xEB, 0x2E, 0x83, 0xA8, 0x70, 0x37, 0xE4, 0x90,
+  0xC4, 0x5C, 0x43, 0x59, 0x0D, 0x36, 0x1D, 0xF0, 0xF3, 0x13, 0x3B, 0x7A, 0x83, 0x90, 0x73, 0x0A, 0x9F, 0x1C, 0x4E, 0xB0, 0x7C, 0xAF, 0x48, 0x09,
+  0x6F, 0xA2, 0xF4, 0x01, 0x1F,
+];
+const ZLIB: [u8; 357] = [
+  0x78, 0xDA, 0x8D, 0x56, 0x49, 0x0E, 0xC2, 0x30, 0x0C, 0xBC, 0xF3, 0x8A, 0x7E, 0x2D, 0x16, 0x55, 0x41, 0x2A, 0x50, 0x09, 0x4E, 0x79, 0x3D, 0xA2,
+  0x51, 0xC9, 0xD8, 0x33, 0x0E, 0x5C, 0xD2, 0x2C, 0x8E, 0xD7, 0x19, 0x37, 0x4B, 0xB9, 0xDD, 0xCA, 0x54, 0xD6, 0xED, 0x52, 0xA6, 0xF3, 0xBC, 0xBE,
+  0xCA, 0x09, 0xE7, 0x36, 0xBF, 0x8E, 0xE9, 0xBC, 0x3D, 0xAF, 0xEB, 0xE3, 0x3E, 0x1D, 0xDF, 0xFA, 0x39, 0x3A, 0x16, 0x4D, 0x64, 0xD9, 0x75, 0xB5,
+  0xB9, 0xBF, 0xA4, 0x56, 0x28, 0xBD, 0xDB, 0xD9, 0x35, 0xB6, 0xDD, 0x7D, 0xDD, 0x1C, 0x69, 0xA3, 0xB4, 0x5A, 0xA3, 0x94, 0xDE, 0xF0, 0xC6, 0xAD,
+  0x5B, 0xA9, 0x5F, 0xAB, 0x83, 0xA0, 0xFC, 0x4A, 0x08, 0xE0, 0x68, 0x78, 0x7C, 0x7C, 0x9B, 0x2B, 0x18, 0x2E, 0xA6, 0x18, 0x2E, 0xA6, 0x51, 0x3B,
+  0xB5, 0xE0, 0x3B, 0x6A, 0x6E, 0xF3, 0xAE, 0x07, 0xC4, 0x48, 0xC0, 0x47, 0x56, 0xC3, 0x1D, 0x28, 0x01, 0x6E, 0xA0, 0xD3, 0x6D, 0x47, 0xD4, 0x01,
+  0xAC, 0xC6, 0x34, 0x58, 0x74, 0xC5, 0x1D, 0x90, 0xA3, 0x68, 0xBA, 0x17, 0x0A, 0x0A, 0xAA, 0x53, 0x86, 0xD7, 0x24, 0x64, 0xFD, 0x11, 0xD7, 0x66,
+  0x90, 0xF3, 0xAC, 0x84, 0x22, 0x42, 0x75, 0xD1, 0x9F, 0x74, 0xF7, 0x85, 0x7B, 0x3C, 0x02, 0xFC, 0xFA, 0x20, 0xB8, 0x81, 0x01, 0x45, 0x1C, 0x24,
+  0xCC, 0x26, 0xAA, 0xB0, 0x57, 0x58, 0x75, 0xC6, 0x41, 0x56, 0xCE, 0xC4, 0x97, 0x1E, 0xB9, 0x02, 0x93, 0x33, 0xEE, 0x35, 0x7B, 0xDE, 0x61, 0xD5,
+  0x41, 0x1B, 0xEA, 0xE4, 0x04, 0xB2, 0x8C, 0x25, 0x89, 0x1C, 0xB4, 0x02, 0x86, 0xA9, 0xBF, 0x1F, 0x41, 0xFB, 0x07, 0xF3, 0x88, 0x6D, 0xE8, 0x28,
+  0x46, 0x0A, 0x38, 0xB0, 0xA0, 0xDB, 0x02, 0x41, 0xA8, 0x27, 0xBA, 0xD4, 0x46, 0xFA, 0xA8, 0xE4, 0x66, 0xB9, 0xAC, 0x01, 0x58, 0x82, 0x2B, 0xB1,
+  0xAF, 0xDB, 0xB8, 0xD7, 0x46, 0x78, 0x28, 0x7E, 0x12, 0xA6, 0x74, 0xA3, 0x85, 0x0C, 0xC8, 0x3E, 0xA4, 0x02, 0xA5, 0xC6, 0xA7, 0xEC, 0x27, 0x7D,
+  0x31, 0x46, 0xEA, 0xF3, 0xA0, 0xFE, 0x15, 0x44, 0x29, 0x71, 0x0F, 0x2A, 0x36, 0xFC, 0x39, 0x53, 0xDF, 0xAC, 0xBA, 0x23, 0x12, 0x17, 0x75, 0xF2,
+  0x78, 0x8C, 0x28, 0x13, 0x54, 0xD1, 0xD5, 0x00, 0x27, 0x55, 0xCF, 0x0D, 0xAD, 0xCC, 0x12, 0x98, 0x26, 0xED, 0xE7, 0x07, 0xED, 0xD4, 0x1B, 0x44,
+  0x21, 0xC7, 0xE4, 0x93, 0x23, 0x76, 0x30, 0x7E, 0xAF, 0x40, 0x08, 0xFF, 0x25, 0xC5, 0x0B, 0xBC, 0x01, 0x59, 0xBE, 0x6D, 0x63,
+];
+const GZIP: [u8; 480] = [
+  0x1F, 0x8B, 0x08, 0x1E, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x02, 0x00, 0x78, 0x79, 0x6E, 0x61, 0x6D, 0x65, 0x2E, 0x74, 0x78, 0x74, 0x00, 0x63,
+  0x6F, 0x6D, 0x6D, 0x65, 0x6E, 0x74, 0x00, 0x9B, 0x2C, 0x7D, 0x51, 0x49, 0x0E, 0x80, 0x20, 0x0C, 0xBC, 0xFB, 0x0A, 0xBE, 0xD6, 0x46, 0xA2, 0x26,
+  0xA0, 0x26, 0x72, 0xE2, 0xF5, 0x46, 0x1A, 0x64, 0x0A, 0xD5, 0x0B, 0xDD, 0xA6, 0x9D, 0x29, 0x5D, 0x28, 0x46, 0x72, 0x14, 0xCE, 0x95, 0xDC, 0xEC,
+  0x43, 0xA2, 0x09, 0x7D, 0xF6, 0xA9, 0xBA, 0xFE, 0xBC, 0xB6, 0x70, 0xEC, 0xAE, 0xDA, 0xFC, 0x94, 0x6A, 0x20, 0x90, 0xA5, 0xCC, 0x12, 0x5F, 0x37,
+  0x59, 0x11, 0xA2, 0x0B, 0x4F, 0x99, 0x28, 0xD9, 0x12, 0x8B, 0x10, 0x79, 0x4D, 0xD6, 0xDC, 0xA3, 0xEC, 0x84, 0x26, 0xE7, 0xC6, 0x92, 0x5F, 0xD6,
+  0x9F, 0xA5, 0x74, 0x64, 0x00, 0xF0, 0x65, 0x2C, 0x57, 0x2B, 0x52, 0x70, 0x5D, 0xFC, 0x62, 0x68, 0xFC, 0xDC, 0x5A, 0x8D, 0x05, 0xED, 0x38, 0x59,
+  0xFC, 0x36, 0x07, 0x60, 0x03, 0x40, 0x6F, 0x96, 0xBB, 0x1E, 0x38, 0x01, 0x26, 0x50, 0xB4, 0x64, 0x8C, 0x3B, 0x00, 0x6B, 0xFF, 0x0D, 0xDC, 0x4B,
+  0x51, 0x85, 0x41, 0x28, 0x52, 0xB7, 0x43, 0xC1, 0x41, 0xD9, 0xDF, 0x43, 0xC3, 0x91, 0x82, 0xBC, 0x02, 0x00, 0x00, 0x1F, 0x8B, 0x08, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0xFF, 0x85, 0x95, 0x4B, 0x0E, 0xC2, 0x30, 0x0C, 0x44, 0xF7, 0x9C, 0xA2, 0x57, 0x4B, 0x45, 0x05, 0x48, 0x2D, 0x54, 0xA2,
+  0xAB, 0x9C, 0x1E, 0x11, 0x2B, 0xE4, 0x39, 0xE3, 0xD0, 0x8D, 0xAB, 0x36, 0xFE, 0x8C, 0xC7, 0xE3, 0xF4, 0x48, 0xD3, 0xBC, 0x1C, 0x69, 0x4A, 0xEB,
+  0x7E, 0xAF, 0xF6, 0x96, 0xB6, 0xAD, 0xDA, 0x65, 0x7F, 0x3F, 0xD6, 0xD7, 0xF3, 0xF7, 0xCC, 0x5F, 0x67, 0x7F, 0xC4, 0xA0, 0xEB, 0xB2, 0x1E, 0xE9,
+  0x52, 0x4F, 0x4A, 0xE6, 0xFA, 0xC2, 0xAC, 0xE6, 0x66, 0x81, 0x41, 0x88, 0xCF, 0xCF, 0x40, 0x7F, 0x52, 0x9C, 0x5D, 0x11, 0xC0, 0x53, 0x6B, 0x95,
+  0x8A, 0x4B, 0x33, 0x06, 0xC2, 0xC5, 0xB3, 0x21, 0xE0, 0xC1, 0x99, 0x65, 0x2A, 0x67, 0x6C, 0x25, 0xB7, 0x0F, 0x8A, 0xCA, 0xBE, 0x5B, 0x2A, 0x66,
+  0xE1, 0x17, 0xD7, 0xD8, 0x3F, 0x2C, 0xAD, 0x73, 0x26, 0x09, 0x79, 0xF7, 0x99, 0xEB, 0x93, 0xB0, 0xCD, 0x22, 0x1B, 0x73, 0x2A, 0x81, 0xEA, 0x33,
+  0x0F, 0x88, 0xCC, 0xC4, 0xE1, 0xE7, 0xCC, 0xAE, 0x23, 0x06, 0x40, 0x39, 0x78, 0x00, 0x46, 0x57, 0xA8, 0xD1, 0x31, 0x82, 0xCB, 0x4E, 0xA1, 0x83,
+  0xB9, 0xCB, 0xDD, 0x88, 0x97, 0xB9, 0xC2, 0x4B, 0xF5, 0xE7, 0xC1, 0xAB, 0xBC, 0x95, 0xCB, 0xDC, 0x09, 0x2B, 0xD8, 0x15, 0x28, 0x29, 0x7B, 0x70,
+  0xAE, 0xF9, 0x60, 0x75, 0x02, 0x01, 0x88, 0x86, 0xE3, 0xFD, 0x16, 0x21, 0xBB, 0x12, 0xFF, 0x54, 0xC4, 0xE6, 0x86, 0xF5, 0x45, 0xC8, 0x71, 0xA7,
+  0x9E, 0x07, 0x5F, 0x0D, 0xB0, 0xC8, 0x6D, 0x10, 0x87, 0x89, 0x71, 0x16, 0xB2, 0xC2, 0x18, 0x85, 0xCC, 0x5B, 0x15, 0x1A, 0x74, 0xE8, 0x3B, 0x55,
+  0xDB, 0xAB, 0x2C, 0x58, 0x95, 0x78, 0x1A, 0x00, 0x19, 0xDD, 0xB9, 0xDD, 0x55, 0xD6, 0x97, 0x39, 0xB9, 0x7E, 0x4E, 0xD6, 0x8E, 0x6C, 0x47, 0xA3,
+  0x1C, 0xC9, 0x0E, 0x00, 0xE4, 0xC6, 0xD4, 0xDB, 0xBF, 0x07, 0x7E, 0xFA, 0x9F, 0xA9, 0x6F, 0x1F, 0xB2, 0x41, 0x5D, 0x3E, 0xB9, 0x06, 0x00, 0x00,
+];
+
+#[test]
+fn test_inflate() {
+  let text = text(400,);
+
+  assert_eq!(inflate(&STORED,).as_deref(), Ok(&b"stored bytes"[..]),);
+  assert_eq!(inflate(&FIXED,).as_deref(), Ok(&b"abcabcabcabcabc"[..]),);
+  assert_eq!(inflate(&BLOCKS,), Ok(text.clone()),);
+  assert_eq!(zlib_decompress(&ZLIB,), Ok(text.clone()),);
+  assert_eq!(gzip_decompress(&GZIP,), Ok(text.clone()),);
+
+  //Read in small pieces so that matches are split.
+  let mut inflater = Inflater::new(ReadLsb::new(ReadIter::new(BLOCKS.iter().copied(),),),);
+  let mut out = Vec::new();
+  let mut buffer = [0; 7];
+  loop {
+    match inflater.read(&mut buffer,) {
+      Ok(0) => break,
+      Ok(read) => out.extend_from_slice(&buffer[..read],),
+      Err(e) => panic!("Error inflating: {:?}", e,),
+    }
+  }
+  assert!(inflater.is_done(),);
+  assert_eq!(inflater.total_out(), text.len() as u64,);
+  assert_eq!(out, text,);
+}
+
+#[test]
+fn test_inflate_errors() {
+  assert_eq!(inflate(&[0x07,],), Err(InflateError::BlockType),);
+  assert_eq!(inflate(&[0x01, 0x05, 0x00, 0x00, 0x00,],), Err(InflateError::StoredLength),);
+  //A match before any output.
+  assert_eq!(inflate(&[0x03, 0x02, 0x00,],), Err(InflateError::Distance),);
+  assert_eq!(inflate(&BLOCKS[..BLOCKS.len() - 4],), Err(InflateError::Read(None,)),);
+
+  let mut zlib = ZLIB;
+  zlib[0] = 0x79;
+  assert_eq!(zlib_decompress(&zlib,), Err(InflateError::Header),);
+  let mut zlib = ZLIB;
+  zlib[ZLIB.len() - 1] ^= 1;
+  assert_eq!(zlib_decompress(&zlib,), Err(InflateError::Checksum),);
+
+  let mut gzip = GZIP;
+  gzip[1] = 0x8C;
+  assert_eq!(gzip_decompress(&gzip,), Err(InflateError::Header),);
+  let mut gzip = GZIP;
+  gzip[GZIP.len() - 1] ^= 1;
+  assert_eq!(gzip_decompress(&gzip,), Err(InflateError::Checksum),);
+}
+
+#[cfg(feature = "std",)]
+#[test]
+fn test_inflate_stream() {
+  use std::io::{Read, Cursor, ErrorKind,};
+
+  let text = text(400,);
+  let mut out = Vec::new();
+  GzipDecoder::from_reader(Cursor::new(&GZIP[..],),).read_to_end(&mut out,).expect("Error decoding",);
+  assert_eq!(out, text,);
+
+  let mut out = Vec::new();
+  ZlibDecoder::from_reader(Cursor::new(&ZLIB[..],),).read_to_end(&mut out,).expect("Error decoding",);
+  assert_eq!(out, text,);
+
+  let mut out = Vec::new();
+  Inflater::from_reader(Cursor::new(&BLOCKS[..],),).read_to_end(&mut out,).expect("Error decoding",);
+  assert_eq!(out, text,);
+
+  let error = Inflater::from_reader(Cursor::new(&BLOCKS[..20],),).read_to_end(&mut out,).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::UnexpectedEof,);
+  let error = Inflater::from_reader(Cursor::new(&[0x07u8,][..],),).read_to_end(&mut out,).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::InvalidData,);
+}
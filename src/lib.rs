@@ -2,11 +2,14 @@
 //! 
 //! To access [BitReader](./struct.BitReader.html) or `BitWriter` types use `--features std`
 //! 
+//! The crate builds on stable Rust, `--features nightly` adds conversions from the never
+//! type.
+//! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2019-12-30
+//! Last Moddified --- 2026-10-18
 
 #![cfg_attr(not(feature = "std",), no_std,)]
-#![feature(const_fn, const_transmute, never_type, try_trait, nll,)]
+#![cfg_attr(feature = "nightly", feature(never_type,),)]
 #![deny(missing_docs,)]
 
 extern crate core;
@@ -16,10 +19,23 @@ extern crate alloc;
 extern crate std;
 
 pub mod bits;
+pub mod codes;
+pub mod inflate;
+pub mod deflate;
+pub mod bzip2;
 mod bit_read;
 mod bit_write;
+mod copy;
+mod golomb;
+mod fibonacci;
+mod huffman;
+mod checksum;
+mod arithmetic;
+mod ans;
+mod lzw;
+mod test_data;
 
-pub use self::{bits::Bits, bit_read::*, bit_write::*,};
+pub use self::{bits::Bits, codes::{IntegerCode, DynBitRead, DynBitWrite,}, bit_read::*, bit_write::*, copy::*, golomb::*, fibonacci::*, huffman::*, checksum::*, arithmetic::*, ans::*, lzw::*,};
 
 /// The error returned when trying to unwrap an unaligned reader/writer.
 #[derive(Debug, Hash,)]
@@ -33,3 +49,33 @@ impl<R,> UnalignedError<R,> {
   #[inline]
   pub fn into_inner(self,) -> R { self.0 }
 }
+
+/// The order bits are taken from each byte.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum BitOrder {
+  /// High bits first, the order of every reader and writer except `ReadLsb`.
+  Msb,
+  /// Low bits first, as used by DEFLATE.
+  Lsb,
+}
+
+/// The error returned when reading or writing a variable length code fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum CodeError<E,> {
+  /// Reading from the input failed.
+  Read(E),
+  /// Writing to the output failed.
+  Write(E),
+  /// The value of the code does not fit in the type being read, or the value being
+  /// written cannot be represented by the code.
+  Overflow,
+  /// The code used more bits than are needed for its value.
+  Overlong,
+  /// The bits read are not a valid code, or the parameter of the code is invalid.
+  Invalid,
+}
+
+impl<E,> From<E> for CodeError<E,> {
+  #[inline]
+  fn from(from: E,) -> Self { CodeError::Read(from,) }
+}
@@ -0,0 +1,427 @@
+//! Defines an LZW codec with variable width codes, as used by GIF, TIFF and `compress`.
+//! 
+//! Codes are written with `write_word` and read with `read_word` so the bit order comes
+//! from the reader/writer: `WriteLsb`/`ReadLsb` pack codes low bits first as GIF does and
+//! the high bit first readers/writers pack them as TIFF does.
+//! 
+//! `LzwConfig::compress` reads and writes the codes of a Unix `compress` (`.Z`) file
+//! through `WriteLsb`/`ReadLsb`, the three byte header is left to the caller.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+use crate::{BitRead, BitWrite, CodeError,};
+use alloc::{vec, vec::Vec,};
+
+mod tests;
+
+/// The widest code supported.
+pub const MAX_LZW_WIDTH: u8 = 16;
+/// Marks an empty slot in the encoder hash table.
+const EMPTY: u32 = u32::MAX;
+
+/// The parameters of an LZW code.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct LzwConfig {
+  /// The number of bits in a literal.
+  literal_bits: u8,
+  /// The width of the codes after a clear code.
+  min_width: u8,
+  /// The widest codes.
+  max_width: u8,
+  /// Whether the code after the clear code ends the data.
+  eoi: bool,
+  /// Whether the code width grows one code early.
+  early_change: bool,
+  /// Whether the stream starts with a clear code.
+  initial_clear: bool,
+  /// Whether the codes are padded to a group of eight when the width changes.
+  code_groups: bool,
+}
+
+impl LzwConfig {
+  /// Constructs a new `LzwConfig` with an end of information code and no early change.
+  /// 
+  /// The clear code follows the literals and the stream starts with a clear code.
+  /// 
+  /// # Params
+  /// 
+  /// literal_bits --- The number of bits in a literal, from `1` to `8`.  
+  /// min_width --- The width of the codes after a clear code, more than `literal_bits`.  
+  /// max_width --- The widest codes, from `min_width` to `16`.  
+  pub const fn new(literal_bits: u8, min_width: u8, max_width: u8,) -> Self {
+    assert!(literal_bits >= 1 && literal_bits <= 8, "Literals must have 1 to 8 bits",);
+    assert!(min_width > literal_bits, "The minimum width must be more than the literal bits",);
+    assert!(max_width >= min_width && max_width <= MAX_LZW_WIDTH, "The maximum width must be from the minimum width to 16",);
+
+    Self { literal_bits, min_width, max_width, eoi: true, early_change: false, initial_clear: true, code_groups: false, }
+  }
+  /// The configuration of GIF image data.
+  /// 
+  /// # Params
+  /// 
+  /// min_code_size --- The minimum code size from the image header, from `2` to `8`.  
+  pub const fn gif(min_code_size: u8,) -> Self { Self::new(min_code_size, min_code_size + 1, 12,) }
+  /// The configuration of TIFF strips, which use early change.
+  pub const fn tiff() -> Self { Self::new(8, 9, 12,).with_early_change(true,) }
+  /// The configuration of a Unix `compress` file in block mode.
+  /// 
+  /// The codes follow the header `1F 9D` and a flags byte of `0x80 | max_bits`.  
+  /// `compress -b 9` grows its codes to 10 bits once the table is full so a `max_bits`
+  /// of `9` is not supported.
+  /// 
+  /// # Params
+  /// 
+  /// max_bits --- The widest codes from the flags byte, from `10` to `16`.  
+  pub const fn compress(max_bits: u8,) -> Self {
+    assert!(max_bits >= 10, "`compress` streams with 9 bit codes are not supported",);
+
+    Self::new(8, 9, max_bits,).with_eoi(false,).with_initial_clear(false,).with_code_groups(true,)
+  }
+  /// Sets whether the code after the clear code is an end of information code.
+  /// 
+  /// # Params
+  /// 
+  /// eoi --- Whether there is an end of information code.  
+  pub const fn with_eoi(mut self, eoi: bool,) -> Self { self.eoi = eoi; self }
+  /// Sets whether the code width grows one code before it is needed, as TIFF does.
+  /// 
+  /// # Params
+  /// 
+  /// early_change --- Whether the width grows early.  
+  pub const fn with_early_change(mut self, early_change: bool,) -> Self { self.early_change = early_change; self }
+  /// Sets whether the encoder starts the stream with a clear code.
+  /// 
+  /// # Params
+  /// 
+  /// initial_clear --- Whether the stream starts with a clear code.  
+  pub const fn with_initial_clear(mut self, initial_clear: bool,) -> Self { self.initial_clear = initial_clear; self }
+  /// Sets whether codes are written in groups of eight, as `compress` does.
+  /// 
+  /// When the code width changes, after a clear code or when the width grows, the rest
+  /// of the current group of eight codes is padded with zeros.
+  /// 
+  /// # Params
+  /// 
+  /// code_groups --- Whether the codes are padded to groups of eight.  
+  pub const fn with_code_groups(mut self, code_groups: bool,) -> Self { self.code_groups = code_groups; self }
+  /// Returns the clear code.
+  #[inline]
+  pub const fn clear_code(&self,) -> u32 { 1 << self.literal_bits }
+  /// Returns the end of information code if there is one.
+  #[inline]
+  pub const fn eoi_code(&self,) -> Option<u32> {
+    if self.eoi { Some(self.clear_code() + 1) } else { None }
+  }
+  /// Returns the first code assigned to a string.
+  #[inline]
+  const fn first_code(&self,) -> u32 { self.clear_code() + 1 + self.eoi as u32 }
+  /// Returns the number of bits of padding after `codes` codes of `width` bits when the
+  /// width changes.
+  #[inline]
+  const fn padding(&self, codes: u8, width: u8,) -> u8 {
+    if self.code_groups { (8 - codes) % 8 * width } else { 0 }
+  }
+  /// Returns the width of the next code given the width of the last code and the
+  /// number of codes the decoder has assigned.
+  #[inline]
+  const fn grow(&self, width: u8, next_code: u32,) -> u8 {
+    if width < self.max_width && next_code + self.early_change as u32 >= 1 << width { width + 1 }
+    else { width }
+  }
+}
+
+/// Encodes bytes with LZW.
+/// 
+/// The stream starts with a clear code unless the configuration says otherwise and the
+/// encoder clears the table whenever it fills. Output is not padded, GIF and TIFF pad
+/// the last byte with zeros.
+#[derive(Clone, Debug,)]
+pub struct LzwEncoder<W,>
+  where W: BitWrite, {
+  /// The writer to encode to.
+  writer: W,
+  /// The configuration of the code.
+  config: LzwConfig,
+  /// The codes of the strings, keyed by prefix code and byte, with open addressing.
+  table: Vec<(u32, u32,)>,
+  /// The code of the string matched so far.
+  prefix: Option<u32>,
+  /// The next code to assign.
+  next_code: u32,
+  /// The width of the next code.
+  width: u8,
+  /// The number of codes written in the current group of eight.
+  codes: u8,
+  /// The bits of padding to write before the next code.
+  padding: u8,
+  /// Whether the first clear code has been written.
+  started: bool,
+}
+
+impl<W,> LzwEncoder<W,>
+  where W: BitWrite, {
+  /// Constructs a new `LzwEncoder` which encodes to `writer`.
+  /// 
+  /// # Params
+  /// 
+  /// writer --- The writer to encode to.  
+  /// config --- The configuration of the code.  
+  pub fn new(writer: W, config: LzwConfig,) -> Self {
+    Self {
+      writer,
+      config,
+      table: vec![(EMPTY, 0,); 2 << config.max_width],
+      prefix: None,
+      next_code: config.first_code(),
+      width: config.min_width,
+      codes: 0,
+      padding: 0,
+      started: false,
+    }
+  }
+  /// Returns a reference to the writer.
+  #[inline]
+  pub const fn get_ref(&self,) -> &W { &self.writer }
+  /// Encodes `bytes`.
+  /// 
+  /// The last string matched is held until more bytes are written or the encoder is
+  /// finished.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The bytes to encode, each less than `1 << literal_bits`.  
+  pub fn write(&mut self, bytes: &[u8],) -> Result<(), W::Error> {
+    if !self.started {
+      if self.config.initial_clear { self.write_code(self.config.clear_code(),)?; }
+      self.started = true;
+    }
+
+    for &byte in bytes {
+      assert!((byte as u32) < self.config.clear_code(), "The byte does not fit in a literal",);
+
+      let prefix = match self.prefix {
+        Some(prefix) => prefix,
+        None => { self.prefix = Some(byte as u32,); continue },
+      };
+      let key = (prefix << 8) | byte as u32;
+      let slot = self.find(key,);
+      if self.table[slot].0 == key {
+        self.prefix = Some(self.table[slot].1,);
+        continue
+      }
+
+      self.write_code(prefix,)?;
+      //Keep a code free so that early change never needs a wider code.
+      if self.next_code < (1 << self.config.max_width) - self.config.early_change as u32 {
+        self.table[slot] = (key, self.next_code,);
+        self.next_code += 1;
+        self.set_width(self.config.grow(self.width, self.next_code - 1,),);
+      } else {
+        self.write_code(self.config.clear_code(),)?;
+        self.table.fill((EMPTY, 0,),);
+        self.next_code = self.config.first_code();
+        self.padding = self.config.padding(self.codes, self.width,);
+        self.codes = 0;
+        self.width = self.config.min_width;
+      }
+      self.prefix = Some(byte as u32,);
+    }
+
+    Ok(())
+  }
+  /// Writes the last string and the end of information code and returns the writer.
+  pub fn finish(mut self,) -> Result<W, W::Error> {
+    self.write(&[],)?;
+
+    if let Some(prefix) = self.prefix {
+      self.write_code(prefix,)?;
+      //The decoder assigns a code after reading the string.
+      if self.next_code < (1 << self.config.max_width) - self.config.early_change as u32 {
+        self.next_code += 1;
+        self.set_width(self.config.grow(self.width, self.next_code - 1,),);
+      }
+    }
+    if let Some(eoi) = self.config.eoi_code() { self.write_code(eoi,)?; }
+
+    Ok(self.writer)
+  }
+  /// Writes any padding owed and then `code`.
+  fn write_code(&mut self, code: u32,) -> Result<(), W::Error> {
+    while self.padding > 0 {
+      let bits = self.padding.min(64,);
+
+      self.writer.write_word(bits, 0,)?;
+      self.padding -= bits;
+    }
+
+    self.writer.write_word(self.width, code as u64,)?;
+    self.codes = (self.codes + 1) % 8;
+
+    Ok(())
+  }
+  /// Changes the width of the codes, starting a new group if it grows.
+  #[inline]
+  fn set_width(&mut self, width: u8,) {
+    if width != self.width {
+      self.padding = self.config.padding(self.codes, self.width,);
+      self.codes = 0;
+      self.width = width;
+    }
+  }
+  /// Returns the slot of `key` in the table or the empty slot where it would go.
+  #[inline]
+  fn find(&self, key: u32,) -> usize {
+    let mask = self.table.len() - 1;
+    let mut slot = (key.wrapping_mul(0x9E37_79B1,) >> 8) as usize & mask;
+    while self.table[slot].0 != EMPTY && self.table[slot].0 != key { slot = (slot + 1) & mask; }
+
+    slot
+  }
+}
+
+/// A string in the decoder table.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+struct Entry {
+  /// The code of the string without its last byte.
+  prefix: u32,
+  /// The first byte of the string.
+  first: u8,
+  /// The last byte of the string.
+  last: u8,
+  /// The length of the string.
+  len: u32,
+}
+
+/// Decodes LZW codes.
+#[derive(Clone, Debug,)]
+pub struct LzwDecoder<R,>
+  where R: BitRead, {
+  /// The reader to decode from.
+  reader: R,
+  /// The configuration of the code.
+  config: LzwConfig,
+  /// The string of each code.
+  table: Vec<Entry>,
+  /// The previous code since the last clear code.
+  prev: Option<u32>,
+  /// The width of the next code.
+  width: u8,
+  /// The number of codes read in the current group of eight.
+  codes: u8,
+  /// The bits of padding to skip before the next code.
+  padding: u8,
+  /// Whether the end of information code has been read.
+  done: bool,
+}
+
+impl<R,> LzwDecoder<R,>
+  where R: BitRead, {
+  /// Constructs a new `LzwDecoder` which decodes from `reader`.
+  /// 
+  /// # Params
+  /// 
+  /// reader --- The reader to decode from.  
+  /// config --- The configuration of the code.  
+  pub fn new(reader: R, config: LzwConfig,) -> Self {
+    let table = (0..config.first_code()).map(|code,| Entry { prefix: 0, first: code as u8, last: code as u8, len: 1, },).collect();
+
+    Self { reader, config, table, prev: None, width: config.min_width, codes: 0, padding: 0, done: false, }
+  }
+  /// Returns `true` if the end of information code has been read.
+  #[inline]
+  pub const fn is_done(&self,) -> bool { self.done }
+  /// Returns a reference to the reader.
+  #[inline]
+  pub const fn get_ref(&self,) -> &R { &self.reader }
+  /// Unwraps the reader.
+  #[inline]
+  pub fn into_inner(self,) -> R { self.reader }
+  /// Decodes codes until the end of information code.
+  /// 
+  /// Without an end of information code use `decode_code` until the input runs out.
+  /// 
+  /// # Params
+  /// 
+  /// out --- The buffer to append the bytes to.  
+  pub fn decode(&mut self, out: &mut Vec<u8>,) -> Result<(), CodeError<R::Error>> {
+    while self.decode_code(out,)? {}
+
+    Ok(())
+  }
+  /// Decodes a single code.
+  /// 
+  /// Returns `false` once the end of information code has been read and `Invalid` for
+  /// a code which has not been assigned.
+  /// 
+  /// # Params
+  /// 
+  /// out --- The buffer to append the bytes of the code to.  
+  pub fn decode_code(&mut self, out: &mut Vec<u8>,) -> Result<bool, CodeError<R::Error>> {
+    if self.done { return Ok(false) }
+
+    while self.padding > 0 {
+      let bits = self.padding.min(64,);
+
+      self.reader.read_word(bits,)?;
+      self.padding -= bits;
+    }
+
+    let code = self.reader.read_word(self.width,)? as u32;
+    self.codes = (self.codes + 1) % 8;
+    if code == self.config.clear_code() {
+      self.table.truncate(self.config.first_code() as usize,);
+      self.prev = None;
+      self.padding = self.config.padding(self.codes, self.width,);
+      self.codes = 0;
+      self.width = self.config.min_width;
+
+      return Ok(true)
+    }
+    if Some(code,) == self.config.eoi_code() {
+      self.done = true;
+
+      return Ok(false)
+    }
+
+    let next_code = self.table.len() as u32;
+    match self.prev {
+      None => if code >= self.config.clear_code() { return Err(CodeError::Invalid) },
+      Some(prev) => {
+        if code > next_code || (code == next_code && next_code >= 1 << self.config.max_width) {
+          return Err(CodeError::Invalid)
+        }
+
+        //A code may be the one about to be assigned, which starts with its prefix.
+        let first = if code < next_code { self.table[code as usize].first } else { self.table[prev as usize].first };
+        if next_code < 1 << self.config.max_width {
+          let entry = self.table[prev as usize];
+
+          self.table.push(Entry { prefix: prev, first: entry.first, last: first, len: entry.len + 1, },);
+        }
+      },
+    }
+
+    //Write the string from its last byte.
+    let entry = self.table[code as usize];
+    let start = out.len();
+    out.resize(start + entry.len as usize, 0,);
+    let mut cursor = code;
+    for byte in out[start..].iter_mut().rev() {
+      let entry = self.table[cursor as usize];
+
+      *byte = entry.last;
+      cursor = entry.prefix;
+    }
+
+    self.prev = Some(code,);
+    let width = self.config.grow(self.width, self.table.len() as u32,);
+    if width != self.width {
+      self.padding = self.config.padding(self.codes, self.width,);
+      self.codes = 0;
+      self.width = width;
+    }
+
+    Ok(true)
+  }
+}
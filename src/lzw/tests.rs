@@ -0,0 +1,167 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use super::*;
+use crate::{Bits, WriteVec, WriteLsb, ReadIter, ReadLsb, test_data::Rng,};
+
+/// The image data of the 10 by 10 sample GIF with a minimum code size of `2`.
+const GIF: [u8; 22] = [
+  0x8C, 0x2D, 0x99, 0x87, 0x2A, 0x1C, 0xDC, 0x33, 0xA0, 0x02, 0x75, 0xEC, 0x95, 0xFA, 0xA8, 0xDE, 0x60, 0x8C, 0x04, 0x91,
+  0x4C, 0x01,
+];
+
+/// Returns the pixels of the sample GIF.
+fn pixels() -> Vec<u8> {
+  const ROWS: [[u8; 10]; 4] = [
+    [1, 1, 1, 1, 1, 2, 2, 2, 2, 2,],
+    [1, 1, 1, 0, 0, 0, 0, 2, 2, 2,],
+    [2, 2, 2, 0, 0, 0, 0, 1, 1, 1,],
+    [2, 2, 2, 2, 2, 1, 1, 1, 1, 1,],
+  ];
+
+  [0, 0, 0, 1, 1, 2, 2, 3, 3, 3,].iter().flat_map(|&row,| ROWS[row],).collect()
+}
+
+/// Returns `count` bytes of text with repeats.
+fn text(count: usize,) -> Vec<u8> {
+  let mut text = Vec::with_capacity(count,);
+  for value in Rng::new(2463534242,) {
+    if text.len() >= count { break }
+
+    text.extend_from_slice(&b"the quick brown fox jumps over the lazy dog "[..(value % 40) as usize],);
+    text.push(value as u8,);
+  }
+  text.truncate(count,);
+
+  text
+}
+
+/// Encodes `bytes` with the high bit first.
+fn encode_msb(bytes: &[u8], config: LzwConfig,) -> Vec<u8> {
+  let mut encoder = LzwEncoder::new(WriteVec::new(), config,);
+  encoder.write(bytes,).ok();
+
+  match encoder.finish() {
+    Ok(mut writer) => writer.pad_zeros().clone().into_vec().expect("The writer is padded",),
+    Err(e) => match e {},
+  }
+}
+
+/// Encodes `bytes` with the low bit first.
+fn encode_lsb(bytes: &[u8], config: LzwConfig,) -> Vec<u8> {
+  let mut encoder = LzwEncoder::new(WriteLsb::new(WriteVec::new(),), config,);
+  for chunk in bytes.chunks(100,) { encoder.write(chunk,).ok(); }
+
+  match encoder.finish() {
+    Ok(mut writer) => {
+      writer.pad_zeros().flush().ok();
+      let (writer, _,) = writer.into_inner().expect("The writer is padded",);
+
+      writer.into_vec().expect("The writer is padded",)
+    },
+    Err(e) => match e {},
+  }
+}
+
+#[test]
+fn test_lzw_gif() {
+  let pixels = pixels();
+  let mut decoder = LzwDecoder::new(ReadLsb::new(ReadIter::new(GIF.iter().copied(),),), LzwConfig::gif(2,),);
+  let mut out = Vec::new();
+  assert_eq!(decoder.decode(&mut out,), Ok(()),);
+  assert!(decoder.is_done(),);
+  assert_eq!(out, pixels,);
+
+  assert_eq!(encode_lsb(&pixels, LzwConfig::gif(2,),), GIF,);
+}
+
+#[test]
+fn test_lzw() {
+  let text = text(300_000,);
+  let configs = [
+    LzwConfig::gif(8,),
+    LzwConfig::tiff(),
+    LzwConfig::new(8, 9, 16,).with_eoi(false,),
+    LzwConfig::compress(10,),
+    LzwConfig::compress(16,),
+    LzwConfig::new(8, 9, 9,).with_early_change(true,),
+    LzwConfig::new(1, 2, 12,),
+  ];
+
+  for config in configs {
+    let input = if config.literal_bits == 1 { text.iter().map(|&byte,| byte & 1,).collect() } else { text.clone() };
+
+    let compressed = encode_msb(&input, config,);
+    assert!(compressed.len() < input.len() / 2, "{:?}", config,);
+    let mut decoder = LzwDecoder::new(ReadIter::new(compressed.iter().copied(),), config,);
+    let mut out = Vec::new();
+    if config.eoi {
+      assert_eq!(decoder.decode(&mut out,), Ok(()),);
+    } else {
+      while out.len() < input.len() { assert_eq!(decoder.decode_code(&mut out,), Ok(true),); }
+    }
+    assert_eq!(out, input, "{:?}", config,);
+
+    let compressed = encode_lsb(&input, config,);
+    let mut decoder = LzwDecoder::new(ReadLsb::new(ReadIter::new(compressed.iter().copied(),),), config,);
+    let mut out = Vec::new();
+    while out.len() < input.len() { assert_eq!(decoder.decode_code(&mut out,), Ok(true),); }
+    assert_eq!(out, input, "{:?}", config,);
+  }
+
+  //An empty stream is a clear code and an end of information code.
+  assert_eq!(encode_msb(&[], LzwConfig::tiff(),), [0x80, 0x40, 0x40,],);
+}
+
+#[test]
+fn test_lzw_errors() {
+  //A code which has not been assigned.
+  let mut writer = WriteVec::new();
+  writer.write_word(9, 256,).ok();
+  writer.write_word(9, 65,).ok();
+  writer.write_word(9, 300,).ok();
+  let bytes = writer.pad_zeros().clone().into_vec().expect("The writer is padded",);
+  let mut decoder = LzwDecoder::new(ReadIter::new(bytes.iter().copied(),), LzwConfig::tiff(),);
+  let mut out = Vec::new();
+  assert_eq!(decoder.decode(&mut out,), Err(CodeError::Invalid),);
+  assert_eq!(out, b"A",);
+
+  //A string code straight after a clear code.
+  let mut decoder = LzwDecoder::new(ReadIter::new([0x80, 0x40, 0x80,].iter().copied(),), LzwConfig::tiff(),);
+  assert_eq!(decoder.decode(&mut Vec::new(),), Err(CodeError::Invalid),);
+  //Truncated input.
+  let mut decoder = LzwDecoder::new(ReadIter::new([0x80,].iter().copied(),), LzwConfig::tiff(),);
+  assert_eq!(decoder.decode(&mut Vec::new(),), Err(CodeError::Read(Some(Bits::B8,),)),);
+}
+
+#[test]
+fn test_lzw_compress() {
+  //`TOBEORNOTTOBEORTOBEORNOT#` as a `.Z` file with 16 bit codes, checked with `gzip -d`.
+  const Z: [u8; 23] = [
+    0x1F, 0x9D, 0x90, 0x54, 0x9E, 0x08, 0x29, 0xF2, 0x44, 0x8A, 0x93, 0x27, 0x54, 0x02, 0x0E, 0x2C, 0xA8, 0x90, 0xA0, 0x41,
+    0x84, 0x23, 0x00,
+  ];
+  let input = b"TOBEORNOTTOBEORTOBEORNOT#";
+
+  assert_eq!(encode_lsb(input, LzwConfig::compress(16,),), Z[3..],);
+  let mut decoder = LzwDecoder::new(ReadLsb::new(ReadIter::new(Z[3..].iter().copied(),),), LzwConfig::compress(16,),);
+  let mut out = Vec::new();
+  while out.len() < input.len() { assert_eq!(decoder.decode_code(&mut out,), Ok(true),); }
+  assert_eq!(out, input,);
+
+  //The rest of the group of eight codes after a clear code is skipped.
+  let mut writer = WriteLsb::new(WriteVec::new(),);
+  writer.write_word(9, b'a' as u64,).ok();
+  writer.write_word(9, 256,).ok();
+  writer.write_word(54, !0,).ok();
+  writer.write_word(9, b'b' as u64,).ok();
+  writer.pad_zeros().flush().ok();
+  let (writer, _,) = writer.into_inner().expect("The writer is padded",);
+  let bytes = writer.into_vec().expect("The writer is padded",);
+  let mut decoder = LzwDecoder::new(ReadLsb::new(ReadIter::new(bytes.iter().copied(),),), LzwConfig::compress(12,),);
+  let mut out = Vec::new();
+  for _ in 0..3 { assert_eq!(decoder.decode_code(&mut out,), Ok(true),); }
+  assert_eq!(out, b"ab",);
+}
@@ -0,0 +1,41 @@
+//! Defines the data shared by the tests of several modules.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-18
+
+#![cfg(test,)]
+
+use alloc::vec::Vec;
+
+/// A linear congruential generator so that test data is the same on every run.
+/// 
+/// Each value is the high 16 bits of the next state.
+#[derive(Clone, Debug,)]
+pub(crate) struct Rng(u32,);
+
+impl Rng {
+  /// Constructs a new `Rng` starting from `seed`.
+  #[inline]
+  pub(crate) const fn new(seed: u32,) -> Self { Self(seed,) }
+}
+
+impl Iterator for Rng {
+  type Item = u32;
+
+  #[inline]
+  fn next(&mut self,) -> Option<u32> {
+    self.0 = self.0.wrapping_mul(1103515245,).wrapping_add(12345,);
+
+    Some(self.0 >> 16)
+  }
+}
+
+/// Returns `count` words chosen by a fixed sequence.
+pub(crate) fn text(count: usize,) -> Vec<u8> {
+  const WORDS: [&[u8]; 6] = [b"alpha ", b"beta ", b"gamma ", b"delta\n", b"epsilon ", b"zeta ",];
+
+  Rng::new(12345,).take(count,).flat_map(|value,| WORDS[value as usize % 6],).copied().collect()
+}
+
+/// Returns `count` bytes chosen by a fixed sequence, which do not compress.
+pub(crate) fn random(count: usize,) -> Vec<u8> { Rng::new(12345,).take(count,).map(|value,| value as u8,).collect() }